@@ -34,6 +34,7 @@ pub use runtime::BlockingWait;
 pub use runtime::Dropper;
 pub use runtime::Runtime;
 pub use runtime::TrySpawn;
+pub use runtime_tracker::MemoryTracker;
 pub use runtime_tracker::RuntimeTracker;
 pub use runtime_tracker::ThreadTracker;
 pub use shutdown_signal::signal_stream;
@@ -25,6 +25,7 @@ use tokio::runtime::Handle;
 use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
 
+use crate::runtime_tracker::MemoryTracker;
 use crate::runtime_tracker::RuntimeTracker;
 
 /// Methods to spawn tasks.
@@ -202,6 +203,17 @@ impl Runtime {
         Self::create(tracker, runtime_builder.worker_threads(workers))
     }
 
+    /// Create a runtime whose memory tracker is a child of the given one instead of the one of
+    /// the current thread, e.g. the per-user memory tracker.
+    pub fn with_worker_threads_and_parent(
+        workers: usize,
+        parent_memory_tracker: Option<Arc<MemoryTracker>>,
+    ) -> Result<Self> {
+        let tracker = RuntimeTracker::create_with_parent(parent_memory_tracker);
+        let mut runtime_builder = Self::tracker_builder(tracker.clone());
+        Self::create(tracker, runtime_builder.worker_threads(workers))
+    }
+
     pub fn block_on<F: Future>(&self, future: F) -> F::Output {
         self.handle.block_on(future)
     }
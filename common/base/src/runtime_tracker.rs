@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::sync::atomic::AtomicIsize;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
+use common_exception::Result;
+
 #[thread_local]
 static mut TRACKER: *const ThreadTracker = std::ptr::null();
 
@@ -49,7 +53,11 @@ impl ThreadTracker {
 }
 
 pub struct MemoryTracker {
-    memory_usage: AtomicUsize,
+    // Signed, the memory may be freed by another tracker than the one it was allocated by.
+    memory_usage: AtomicIsize,
+    peak_memory_usage: AtomicIsize,
+    // The max memory usage(bytes), 0 is no limited.
+    limit: AtomicUsize,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -57,13 +65,19 @@ impl MemoryTracker {
     pub fn create(parent_memory_tracker: Option<Arc<MemoryTracker>>) -> Arc<MemoryTracker> {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
-            memory_usage: AtomicUsize::new(0),
+            memory_usage: AtomicIsize::new(0),
+            peak_memory_usage: AtomicIsize::new(0),
+            limit: AtomicUsize::new(0),
         })
     }
 
     #[inline]
     pub fn alloc_memory(&self, size: usize) {
-        self.memory_usage.fetch_add(size, Ordering::Relaxed);
+        let usage = self
+            .memory_usage
+            .fetch_add(size as isize, Ordering::Relaxed)
+            + size as isize;
+        self.update_peak_memory_usage(usage);
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.alloc_memory(size);
@@ -72,7 +86,8 @@ impl MemoryTracker {
 
     #[inline]
     pub fn dealloc_memory(&self, size: usize) {
-        self.memory_usage.fetch_sub(size, Ordering::Relaxed);
+        self.memory_usage
+            .fetch_sub(size as isize, Ordering::Relaxed);
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.dealloc_memory(size);
@@ -81,14 +96,22 @@ impl MemoryTracker {
 
     #[inline]
     pub fn realloc_memory(&self, old_size: usize, new_size: usize) {
-        self.memory_usage.fetch_sub(old_size, Ordering::Relaxed);
-        self.memory_usage.fetch_add(new_size, Ordering::Relaxed);
+        let diff = new_size as isize - old_size as isize;
+        let usage = self.memory_usage.fetch_add(diff, Ordering::Relaxed) + diff;
+        self.update_peak_memory_usage(usage);
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.realloc_memory(old_size, new_size);
         }
     }
 
+    #[inline]
+    fn update_peak_memory_usage(&self, usage: isize) {
+        if usage > self.peak_memory_usage.load(Ordering::Relaxed) {
+            self.peak_memory_usage.fetch_max(usage, Ordering::Relaxed);
+        }
+    }
+
     pub fn current() -> Option<Arc<MemoryTracker>> {
         let thread_trckcer = ThreadTracker::current();
         match thread_trckcer.is_null() {
@@ -98,7 +121,48 @@ impl MemoryTracker {
     }
 
     pub fn get_memory_usage(&self) -> usize {
-        self.memory_usage.load(Ordering::Relaxed)
+        self.memory_usage.load(Ordering::Relaxed).max(0) as usize
+    }
+
+    pub fn get_peak_memory_usage(&self) -> usize {
+        self.peak_memory_usage.load(Ordering::Relaxed).max(0) as usize
+    }
+
+    pub fn get_limit(&self) -> usize {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    /// Set the max memory usage(bytes) of the tracker, 0 is no limited.
+    pub fn set_limit(&self, limit: usize) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
+    /// The allocator can't fail an allocation gracefully, so the limit is not enforced on
+    /// allocating, the allocation-heavy operators check it instead and fail the query with
+    /// `MemoryLimitExceeded` once the usage of the tracker or any of its parents is over the limit.
+    pub fn check_memory_limit(&self) -> Result<()> {
+        let limit = self.get_limit();
+        let usage = self.get_memory_usage();
+
+        if limit != 0 && usage > limit {
+            return Err(ErrorCode::MemoryLimitExceeded(format!(
+                "Memory limit exceeded: memory usage {} bytes, maximum {} bytes",
+                usage, limit
+            )));
+        }
+
+        match &self.parent_memory_tracker {
+            None => Ok(()),
+            Some(parent_memory_tracker) => parent_memory_tracker.check_memory_limit(),
+        }
+    }
+
+    /// Check the memory limit of the tracker of the current thread, if the thread is tracked.
+    pub fn check_current_memory_limit() -> Result<()> {
+        match MemoryTracker::current() {
+            None => Ok(()),
+            Some(tracker) => tracker.check_memory_limit(),
+        }
     }
 }
 
@@ -108,7 +172,12 @@ pub struct RuntimeTracker {
 
 impl RuntimeTracker {
     pub fn create() -> Arc<RuntimeTracker> {
-        let parent_memory_tracker = MemoryTracker::current();
+        RuntimeTracker::create_with_parent(MemoryTracker::current())
+    }
+
+    pub fn create_with_parent(
+        parent_memory_tracker: Option<Arc<MemoryTracker>>,
+    ) -> Arc<RuntimeTracker> {
         Arc::new(RuntimeTracker {
             memory_tracker: MemoryTracker::create(parent_memory_tracker),
         })
@@ -0,0 +1,75 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[test]
+fn test_memory_tracker_peak_memory_usage() -> Result<()> {
+    let parent = MemoryTracker::create(None);
+    let tracker = MemoryTracker::create(Some(parent.clone()));
+
+    tracker.alloc_memory(100);
+    tracker.realloc_memory(100, 300);
+    tracker.dealloc_memory(300);
+    tracker.alloc_memory(50);
+
+    assert_eq!(tracker.get_memory_usage(), 50);
+    assert_eq!(tracker.get_peak_memory_usage(), 300);
+    assert_eq!(parent.get_memory_usage(), 50);
+    assert_eq!(parent.get_peak_memory_usage(), 300);
+
+    // Freeing the memory allocated by another tracker must not wrap the usage.
+    tracker.dealloc_memory(200);
+    assert_eq!(tracker.get_memory_usage(), 0);
+    assert_eq!(tracker.get_peak_memory_usage(), 300);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_tracker_limit() -> Result<()> {
+    let parent = MemoryTracker::create(None);
+    let tracker = MemoryTracker::create(Some(parent.clone()));
+    let sibling = MemoryTracker::create(Some(parent.clone()));
+
+    tracker.alloc_memory(1000);
+    sibling.alloc_memory(1000);
+    assert!(tracker.check_memory_limit().is_ok());
+
+    // Over the limit of the tracker itself.
+    tracker.set_limit(500);
+    let res = tracker.check_memory_limit();
+    assert!(res.is_err());
+    assert_eq!(
+        ErrorCode::MemoryLimitExceeded("").code(),
+        res.unwrap_err().code()
+    );
+
+    // Over the limit of the parent, which is shared with the sibling.
+    tracker.set_limit(0);
+    parent.set_limit(1500);
+    let res = tracker.check_memory_limit();
+    assert!(res.is_err());
+    assert_eq!(
+        ErrorCode::MemoryLimitExceeded("").code(),
+        res.unwrap_err().code()
+    );
+
+    sibling.dealloc_memory(1000);
+    assert!(tracker.check_memory_limit().is_ok());
+
+    Ok(())
+}
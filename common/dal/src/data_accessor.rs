@@ -16,6 +16,8 @@ use std::io::Read;
 use std::io::Seek;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::Result;
 use futures::stream::Stream;
 use futures::AsyncRead;
@@ -35,6 +37,15 @@ pub trait SeekableReader: Read + Seek {}
 
 impl<T> SeekableReader for T where T: Read + Seek {}
 
+/// Meta information of an object in the storage
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectMeta {
+    /// Path of the object, which could be passed to the other methods of `DataAccessor`
+    pub path: String,
+    pub size: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
 #[async_trait::async_trait]
 pub trait DataAccessor: Send + Sync {
     fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream>;
@@ -53,6 +64,23 @@ pub trait DataAccessor: Send + Sync {
         stream_len: usize,
     ) -> Result<()>;
 
+    /// List all the objects whose path begins with the given prefix
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>>;
+
+    async fn delete(&self, path: &str) -> Result<()>;
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        for path in paths {
+            self.delete(path).await?;
+        }
+        Ok(())
+    }
+
+    /// Get the meta information of the object, fails if the object does not exist
+    async fn head(&self, path: &str) -> Result<ObjectMeta>;
+
+    async fn exists(&self, path: &str) -> Result<bool>;
+
     async fn read(&self, location: &str) -> Result<Vec<u8>> {
         let mut input_stream = self.get_input_stream(location, None)?;
         let mut buffer = vec![];
@@ -20,3 +20,15 @@ pub static METRIC_S3_GETOBJECT_ERRORS: &str = "s3.getobject_errors";
 pub static METRIC_S3_HEADOBJECT_NUMBERS: &str = "s3.headobject_numbers";
 pub static METRIC_S3_HEADOBJECT_USEDTIME: &str = "s3.headobject_usedtime";
 pub static METRIC_S3_HEADOBJECT_ERRORS: &str = "s3.headobject_errors";
+
+pub static METRIC_S3_LISTOBJECTS_NUMBERS: &str = "s3.listobjects_numbers";
+pub static METRIC_S3_LISTOBJECTS_USEDTIME: &str = "s3.listobjects_usedtime";
+pub static METRIC_S3_LISTOBJECTS_ERRORS: &str = "s3.listobjects_errors";
+
+pub static METRIC_S3_DELETEOBJECT_NUMBERS: &str = "s3.deleteobject_numbers";
+pub static METRIC_S3_DELETEOBJECT_USEDTIME: &str = "s3.deleteobject_usedtime";
+pub static METRIC_S3_DELETEOBJECT_ERRORS: &str = "s3.deleteobject_errors";
+
+pub static METRIC_S3_DELETEOBJECTS_NUMBERS: &str = "s3.deleteobjects_numbers";
+pub static METRIC_S3_DELETEOBJECTS_USEDTIME: &str = "s3.deleteobjects_usedtime";
+pub static METRIC_S3_DELETEOBJECTS_ERRORS: &str = "s3.deleteobjects_errors";
@@ -14,22 +14,37 @@
 //
 
 use std::str::FromStr;
+use std::time::Instant;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
+use metrics::counter;
+use metrics::histogram;
 use rusoto_core::credential::DefaultCredentialsProvider;
 use rusoto_core::credential::StaticProvider;
 use rusoto_core::ByteStream;
 use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
+use rusoto_core::RusotoError;
+use rusoto_s3::Delete;
+use rusoto_s3::DeleteObjectRequest;
+use rusoto_s3::DeleteObjectsRequest;
+use rusoto_s3::HeadObjectError;
+use rusoto_s3::HeadObjectOutput;
+use rusoto_s3::HeadObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
+use rusoto_s3::ObjectIdentifier;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 use crate::S3InputStream;
 
 pub struct S3 {
@@ -104,6 +119,29 @@ impl S3 {
         }
     }
 
+    async fn head_object(
+        &self,
+        path: &str,
+    ) -> std::result::Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
+        let req = HeadObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.to_string(),
+            ..Default::default()
+        };
+
+        counter!(super::metrics::METRIC_S3_HEADOBJECT_NUMBERS, 1);
+        let start = Instant::now();
+        let output = self.client.head_object(req).await.map_err(|e| {
+            counter!(super::metrics::METRIC_S3_HEADOBJECT_ERRORS, 1);
+            e
+        })?;
+        histogram!(
+            super::metrics::METRIC_S3_HEADOBJECT_USEDTIME,
+            start.elapsed()
+        );
+        Ok(output)
+    }
+
     async fn put_byte_stream(
         &self,
         path: &str,
@@ -156,4 +194,145 @@ impl DataAccessor for S3 {
         self.put_byte_stream(path, ByteStream::new_with_size(input_stream, stream_len))
             .await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        let mut objects = vec![];
+        let mut continuation_token = None;
+        loop {
+            let req = ListObjectsV2Request {
+                bucket: self.bucket.to_string(),
+                prefix: Some(prefix.to_string()),
+                continuation_token,
+                ..Default::default()
+            };
+
+            counter!(super::metrics::METRIC_S3_LISTOBJECTS_NUMBERS, 1);
+            let start = Instant::now();
+            let output = self.client.list_objects_v2(req).await.map_err(|e| {
+                counter!(super::metrics::METRIC_S3_LISTOBJECTS_ERRORS, 1);
+                ErrorCode::DALTransportError(e.to_string())
+            })?;
+            histogram!(
+                super::metrics::METRIC_S3_LISTOBJECTS_USEDTIME,
+                start.elapsed()
+            );
+
+            for object in output.contents.unwrap_or_default() {
+                if let Some(key) = object.key {
+                    objects.push(ObjectMeta {
+                        path: key,
+                        size: object.size.unwrap_or_default() as u64,
+                        last_modified: object
+                            .last_modified
+                            .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                            .map(|t| t.with_timezone(&Utc)),
+                    });
+                }
+            }
+
+            match output.next_continuation_token {
+                Some(token) if output.is_truncated.unwrap_or_default() => {
+                    continuation_token = Some(token)
+                }
+                _ => break,
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn delete(&self, path: &str) -> common_exception::Result<()> {
+        let req = DeleteObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.to_string(),
+            ..Default::default()
+        };
+
+        counter!(super::metrics::METRIC_S3_DELETEOBJECT_NUMBERS, 1);
+        let start = Instant::now();
+        self.client.delete_object(req).await.map_err(|e| {
+            counter!(super::metrics::METRIC_S3_DELETEOBJECT_ERRORS, 1);
+            ErrorCode::DALTransportError(e.to_string())
+        })?;
+        histogram!(
+            super::metrics::METRIC_S3_DELETEOBJECT_USEDTIME,
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> common_exception::Result<()> {
+        // at most 1000 keys could be deleted by one DeleteObjects request
+        for keys in paths.chunks(1000) {
+            let req = DeleteObjectsRequest {
+                bucket: self.bucket.to_string(),
+                delete: Delete {
+                    objects: keys
+                        .iter()
+                        .map(|key| ObjectIdentifier {
+                            key: key.clone(),
+                            version_id: None,
+                        })
+                        .collect(),
+                    quiet: Some(true),
+                },
+                ..Default::default()
+            };
+
+            counter!(super::metrics::METRIC_S3_DELETEOBJECTS_NUMBERS, 1);
+            let start = Instant::now();
+            let output = self.client.delete_objects(req).await.map_err(|e| {
+                counter!(super::metrics::METRIC_S3_DELETEOBJECTS_ERRORS, 1);
+                ErrorCode::DALTransportError(e.to_string())
+            })?;
+            histogram!(
+                super::metrics::METRIC_S3_DELETEOBJECTS_USEDTIME,
+                start.elapsed()
+            );
+
+            // in the quiet mode, only the keys failed to be deleted are given back
+            if let Some(error) = output.errors.unwrap_or_default().into_iter().next() {
+                counter!(super::metrics::METRIC_S3_DELETEOBJECTS_ERRORS, 1);
+                return Err(ErrorCode::DALTransportError(format!(
+                    "failed to delete object {}, {}",
+                    error.key.unwrap_or_default(),
+                    error.message.unwrap_or_default()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn head(&self, path: &str) -> common_exception::Result<ObjectMeta> {
+        let output = self.head_object(path).await.map_err(|e| {
+            ErrorCode::DALTransportError(format!(
+                "failed to head object {}, {}",
+                path,
+                e.to_string()
+            ))
+        })?;
+
+        Ok(ObjectMeta {
+            path: path.to_string(),
+            size: output.content_length.unwrap_or_default() as u64,
+            // the Last-Modified header is in the format of RFC 2822
+            last_modified: output
+                .last_modified
+                .and_then(|t| DateTime::parse_from_rfc2822(&t).ok())
+                .map(|t| t.with_timezone(&Utc)),
+        })
+    }
+
+    async fn exists(&self, path: &str) -> common_exception::Result<bool> {
+        match self.head_object(path).await {
+            Ok(_) => Ok(true),
+            Err(RusotoError::Service(HeadObjectError::NoSuchKey(_))) => Ok(false),
+            // the response of HEAD has no body, rusoto fails to recognize the 404 as NoSuchKey
+            Err(RusotoError::Unknown(response)) if response.status.as_u16() == 404 => Ok(false),
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "failed to head object {}, {}",
+                path,
+                e.to_string()
+            ))),
+        }
+    }
 }
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use azure_core_mirror::prelude::NextMarker;
 use azure_core_mirror::HttpClient;
 use azure_storage_mirror::clients::StorageAccountClient;
 use azure_storage_mirror::core::prelude::*;
@@ -27,6 +28,7 @@ use crate::AzureBlobInputStream;
 use crate::Bytes;
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct AzureBlobAccessor {
     client: Arc<StorageClient>,
@@ -161,4 +163,76 @@ impl DataAccessor for AzureBlobAccessor {
         }
         self.put_blob(path, data).await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        let container = self.client.as_container_client(&self.container);
+
+        let mut objects = vec![];
+        let mut next_marker: Option<NextMarker> = None;
+        loop {
+            let mut req = container.list_blobs().prefix(prefix.to_string());
+            if let Some(marker) = next_marker {
+                req = req.next_marker(marker);
+            }
+            let response = req.execute().await.map_err(|e| {
+                ErrorCode::DALTransportError(format!(
+                    "Failed on azure blob list operation, {}",
+                    e.to_string()
+                ))
+            })?;
+
+            objects.extend(response.blobs.blobs.into_iter().map(|blob| ObjectMeta {
+                path: blob.name,
+                size: blob.properties.content_length,
+                last_modified: Some(blob.properties.last_modified),
+            }));
+
+            match response.next_marker {
+                Some(marker) => next_marker = Some(marker),
+                None => break,
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn delete(&self, path: &str) -> common_exception::Result<()> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.delete().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob delete operation, {}",
+                e.to_string()
+            ))),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    async fn head(&self, path: &str) -> common_exception::Result<ObjectMeta> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.get_properties().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob get properties operation, {}",
+                e.to_string()
+            ))),
+            Ok(response) => Ok(ObjectMeta {
+                path: path.to_string(),
+                size: response.blob.properties.content_length,
+                last_modified: Some(response.blob.properties.last_modified),
+            }),
+        }
+    }
+
+    async fn exists(&self, path: &str) -> common_exception::Result<bool> {
+        // the errors of azure blob client are not typed, so a missing blob could not be told
+        // apart from the other failures of get properties, list the blobs of the path instead
+        let objects = self.list(path).await?;
+        Ok(objects.iter().any(|object| object.path == path))
+    }
 }
@@ -20,6 +20,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use async_compat::CompatExt;
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,6 +31,7 @@ use tokio::io::AsyncWriteExt;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct Local {
     root: PathBuf,
@@ -108,6 +111,87 @@ impl DataAccessor for Local {
         new_file.flush().await?;
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>> {
+        let root = normalize_path(&self.root);
+        let base = self.prefix_with_root(prefix)?;
+        // the prefix may end in the middle of a file name, walk from its parent in that case
+        let start_dir = if prefix.is_empty() || prefix.ends_with('/') {
+            base
+        } else {
+            base.parent().map(Path::to_path_buf).unwrap_or(base)
+        };
+
+        let mut objects = vec![];
+        let mut dirs = vec![start_dir];
+        while let Some(dir) = dirs.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            while let Some(entry) = entries.next_entry().await? {
+                let metadata = entry.metadata().await?;
+                if metadata.is_dir() {
+                    dirs.push(entry.path());
+                    continue;
+                }
+
+                let path = relative_path(&root, &entry.path());
+                if path.starts_with(prefix) {
+                    objects.push(ObjectMeta {
+                        path,
+                        size: metadata.len(),
+                        last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                    });
+                }
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let path = self.prefix_with_root(path)?;
+        tokio::fs::remove_file(path).await?;
+        Ok(())
+    }
+
+    async fn head(&self, path: &str) -> Result<ObjectMeta> {
+        let full_path = self.prefix_with_root(path)?;
+        let metadata = tokio::fs::metadata(full_path).await?;
+        if metadata.is_dir() {
+            return Err(ErrorCode::from(Error::new(
+                ErrorKind::Other,
+                format!("{} is a directory", path),
+            )));
+        }
+
+        Ok(ObjectMeta {
+            path: path.to_string(),
+            size: metadata.len(),
+            last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+        })
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool> {
+        let path = self.prefix_with_root(path)?;
+        match tokio::fs::metadata(path).await {
+            Ok(metadata) => Ok(metadata.is_file()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// path relative to the root, with "/" as separator (as the paths given by the callers)
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 // from cargo::util::path
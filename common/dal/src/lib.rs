@@ -17,6 +17,7 @@ pub use data_accessor::AsyncSeekableReader;
 pub use data_accessor::Bytes;
 pub use data_accessor::DataAccessor;
 pub use data_accessor::InputStream;
+pub use data_accessor::ObjectMeta;
 pub use data_accessor::SeekableReader;
 pub use impls::aws_s3::S3InputStream;
 pub use impls::aws_s3::S3;
@@ -28,6 +29,9 @@ pub use schemes::StorageScheme;
 
 pub use self::metrics::DalWithMetric;
 pub use self::metrics::InputStreamWithMetric;
+pub use self::metrics::METRIC_DAL_DELETE_NUMBERS;
+pub use self::metrics::METRIC_DAL_HEAD_NUMBERS;
+pub use self::metrics::METRIC_DAL_LIST_NUMBERS;
 pub use self::metrics::METRIC_DAL_READ_BYTES;
 pub use self::metrics::METRIC_DAL_WRITE_BYTES;
 
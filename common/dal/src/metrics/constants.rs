@@ -15,3 +15,6 @@
 
 pub const METRIC_DAL_READ_BYTES: &str = "dal_read_bytes";
 pub const METRIC_DAL_WRITE_BYTES: &str = "dal_write_bytes";
+pub const METRIC_DAL_LIST_NUMBERS: &str = "dal_list_numbers";
+pub const METRIC_DAL_DELETE_NUMBERS: &str = "dal_delete_numbers";
+pub const METRIC_DAL_HEAD_NUMBERS: &str = "dal_head_numbers";
@@ -15,15 +15,20 @@
 
 use std::sync::Arc;
 
+use common_metrics::label_counter;
 use common_metrics::label_counter_with_val;
 use common_metrics::TenantLabel;
 use futures::Stream;
 
 use crate::metrics::stream_metrics::InputStreamWithMetric;
+use crate::metrics::METRIC_DAL_DELETE_NUMBERS;
+use crate::metrics::METRIC_DAL_HEAD_NUMBERS;
+use crate::metrics::METRIC_DAL_LIST_NUMBERS;
 use crate::metrics::METRIC_DAL_WRITE_BYTES;
 use crate::AsyncSeekableReader;
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct DalWithMetric {
     tenant_label: TenantLabel,
@@ -86,4 +91,58 @@ impl DataAccessor for DalWithMetric {
                 )
             })
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        self.inner.list(prefix).await.map(|objects| {
+            label_counter(
+                METRIC_DAL_LIST_NUMBERS,
+                self.tenant_label.tenant_id.as_str(),
+                self.tenant_label.cluster_id.as_str(),
+            );
+            objects
+        })
+    }
+
+    async fn delete(&self, path: &str) -> common_exception::Result<()> {
+        self.inner.delete(path).await.map(|_| {
+            label_counter(
+                METRIC_DAL_DELETE_NUMBERS,
+                self.tenant_label.tenant_id.as_str(),
+                self.tenant_label.cluster_id.as_str(),
+            )
+        })
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> common_exception::Result<()> {
+        self.inner.batch_delete(paths).await.map(|_| {
+            label_counter_with_val(
+                METRIC_DAL_DELETE_NUMBERS,
+                paths.len() as u64,
+                self.tenant_label.tenant_id.as_str(),
+                self.tenant_label.cluster_id.as_str(),
+            )
+        })
+    }
+
+    async fn head(&self, path: &str) -> common_exception::Result<ObjectMeta> {
+        self.inner.head(path).await.map(|object| {
+            label_counter(
+                METRIC_DAL_HEAD_NUMBERS,
+                self.tenant_label.tenant_id.as_str(),
+                self.tenant_label.cluster_id.as_str(),
+            );
+            object
+        })
+    }
+
+    async fn exists(&self, path: &str) -> common_exception::Result<bool> {
+        self.inner.exists(path).await.map(|exists| {
+            label_counter(
+                METRIC_DAL_HEAD_NUMBERS,
+                self.tenant_label.tenant_id.as_str(),
+                self.tenant_label.cluster_id.as_str(),
+            );
+            exists
+        })
+    }
 }
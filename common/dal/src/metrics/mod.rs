@@ -16,6 +16,9 @@ mod constants;
 mod dal_metrics;
 mod stream_metrics;
 
+pub use constants::METRIC_DAL_DELETE_NUMBERS;
+pub use constants::METRIC_DAL_HEAD_NUMBERS;
+pub use constants::METRIC_DAL_LIST_NUMBERS;
 pub use constants::METRIC_DAL_READ_BYTES;
 pub use constants::METRIC_DAL_WRITE_BYTES;
 pub use dal_metrics::DalWithMetric;
@@ -115,3 +115,41 @@ async fn test_s3_input_stream_seek_api() -> common_exception::Result<()> {
     assert!(r.is_err());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[ignore]
+async fn test_s3_list_head_and_delete_api() -> common_exception::Result<()> {
+    let prefix = "test_s3_list_head_and_delete/";
+    let fixture = TestFixture::new(1024, format!("{}1", prefix));
+    let s3 = fixture.data_accessor()?;
+
+    let paths = (1..=3)
+        .map(|i| format!("{}{}", prefix, i))
+        .collect::<Vec<_>>();
+    for path in &paths {
+        s3.put(path, fixture.content.clone()).await?;
+    }
+
+    let mut listed = s3
+        .list(prefix)
+        .await?
+        .into_iter()
+        .map(|o| o.path)
+        .collect::<Vec<_>>();
+    listed.sort();
+    assert_eq!(paths, listed);
+
+    let meta = s3.head(&fixture.test_key).await?;
+    assert_eq!(fixture.test_key, meta.path);
+    assert_eq!(fixture.content.len() as u64, meta.size);
+    assert!(meta.last_modified.is_some());
+    assert!(s3.exists(&fixture.test_key).await?);
+
+    s3.delete(&fixture.test_key).await?;
+    assert!(!s3.exists(&fixture.test_key).await?);
+    assert!(s3.head(&fixture.test_key).await.is_err());
+
+    s3.batch_delete(&paths[1..]).await?;
+    assert!(s3.list(prefix).await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_base::tokio;
+use common_dal::DataAccessor;
+use common_dal::Local;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn test_local_list_and_delete() -> common_exception::Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let local = Local::with_path(tmp_dir.path().to_owned());
+    local.put("t1/_b/1.parquet", vec![1, 2, 3]).await?;
+    local.put("t1/_ss/1", vec![1]).await?;
+    local.put("t10/_b/1.parquet", vec![1]).await?;
+
+    // list by a directory
+    let mut paths = local
+        .list("t1/")
+        .await?
+        .into_iter()
+        .map(|o| o.path)
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, vec!["t1/_b/1.parquet", "t1/_ss/1"]);
+
+    // list by a prefix which is not a directory
+    let objects = local.list("t1/_b/1").await?;
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].path, "t1/_b/1.parquet");
+    assert_eq!(objects[0].size, 3);
+    assert!(objects[0].last_modified.is_some());
+
+    // list a path which does not exist
+    assert!(local.list("t2/").await?.is_empty());
+
+    local.delete("t1/_b/1.parquet").await?;
+    let paths = local
+        .list("t1/")
+        .await?
+        .into_iter()
+        .map(|o| o.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["t1/_ss/1"]);
+    assert!(local.delete("t1/_b/1.parquet").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_local_head_exists_and_batch_delete() -> common_exception::Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let local = Local::with_path(tmp_dir.path().to_owned());
+    local.put("t1/_sg/1", vec![1, 2, 3, 4]).await?;
+    local.put("t1/_sg/2", vec![1]).await?;
+
+    let meta = local.head("t1/_sg/1").await?;
+    assert_eq!(meta.path, "t1/_sg/1");
+    assert_eq!(meta.size, 4);
+    assert!(meta.last_modified.is_some());
+
+    // directories and missing objects are not objects
+    assert!(local.head("t1/_sg").await.is_err());
+    assert!(local.head("t1/_sg/3").await.is_err());
+    assert!(local.exists("t1/_sg/1").await?);
+    assert!(!local.exists("t1/_sg").await?);
+    assert!(!local.exists("t1/_sg/3").await?);
+
+    local
+        .batch_delete(&["t1/_sg/1".to_string(), "t1/_sg/2".to_string()])
+        .await?;
+    assert!(!local.exists("t1/_sg/1").await?);
+    assert!(!local.exists("t1/_sg/2").await?);
+    assert!(local.list("t1/").await?.is_empty());
+
+    Ok(())
+}
@@ -21,6 +21,9 @@ use common_base::tokio::io::SeekFrom;
 use common_dal::DalWithMetric;
 use common_dal::DataAccessor;
 use common_dal::Local;
+use common_dal::METRIC_DAL_DELETE_NUMBERS;
+use common_dal::METRIC_DAL_HEAD_NUMBERS;
+use common_dal::METRIC_DAL_LIST_NUMBERS;
 use common_dal::METRIC_DAL_READ_BYTES;
 use common_dal::METRIC_DAL_WRITE_BYTES;
 use common_metrics::dump_metric_samples;
@@ -183,6 +186,40 @@ async fn test_dal_metrics_partial_read() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_dal_metrics_list_head_and_delete() -> common_exception::Result<()> {
+    // setup
+    let label = TenantLabel::new("test_tenant_list_delete", "test_cluster_list_delete");
+    let fixture = TestFixture::new(label);
+    let dal = &fixture.da_with_metric;
+
+    fixture.gen_rand_content("t/1", 10).await?;
+    fixture.gen_rand_content("t/2", 10).await?;
+    fixture.gen_rand_content("t/3", 10).await?;
+
+    assert_eq!(3, dal.list("t/").await?.len());
+    assert_eq!(10, dal.head("t/1").await?.size);
+    assert!(dal.exists("t/2").await?);
+    dal.delete("t/1").await?;
+    dal.batch_delete(&["t/2".to_string(), "t/3".to_string()])
+        .await?;
+
+    let label_map = fixture.label_map();
+    let samples = dump_samples(METRIC_DAL_LIST_NUMBERS, label_map);
+    assert_eq!(1, samples.len());
+    assert_eq!(MetricValue::Counter(1.0), samples[0].value);
+
+    let samples = dump_samples(METRIC_DAL_HEAD_NUMBERS, label_map);
+    assert_eq!(1, samples.len());
+    assert_eq!(MetricValue::Counter(2.0), samples[0].value);
+
+    let samples = dump_samples(METRIC_DAL_DELETE_NUMBERS, label_map);
+    assert_eq!(1, samples.len());
+    assert_eq!(MetricValue::Counter(3.0), samples[0].value);
+
+    Ok(())
+}
+
 fn dump_samples(name: &str, lbl_map: &HashMap<String, String>) -> Vec<MetricSample> {
     let handle = try_handle().unwrap();
     dump_metric_samples(handle)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 
@@ -175,6 +176,91 @@ impl DataBlock {
         growable.as_box()
     }
 
+    /// Merges the head blocks of the sorted runs, only the rows up to the smallest last row of
+    /// the heads are merged, since no later block of any run can contain a row before them.
+    /// Returns the merged block along with the rest rows of each head.
+    pub fn merge_sort_heads(
+        heads: &[DataBlock],
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<(DataBlock, Vec<DataBlock>)> {
+        if heads.is_empty() || heads.iter().any(|head| head.num_rows() == 0) {
+            return Result::Err(ErrorCode::EmptyData("Can't merge empty heads"));
+        }
+
+        let sort_arrays = sort_columns_descriptions
+            .iter()
+            .map(|f| {
+                heads
+                    .iter()
+                    .map(|head| {
+                        let column = head.try_column_by_name(&f.column_name)?.to_array()?;
+                        Ok(column.get_array_ref())
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let sort_dyn_arrays = sort_arrays
+            .iter()
+            .map(|arrays| {
+                arrays
+                    .iter()
+                    .map(|array| array.as_ref())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let sort_options = sort_columns_descriptions
+            .iter()
+            .map(|f| arrow_sort::SortOptions {
+                descending: !f.asc,
+                nulls_first: f.nulls_first,
+            })
+            .collect::<Vec<_>>();
+
+        let sort_options_with_array = sort_dyn_arrays
+            .iter()
+            .zip(sort_options.iter())
+            .map(|(s, opt)| {
+                let paris: (&[&dyn Array], &SortOptions) = (s, opt);
+                paris
+            })
+            .collect::<Vec<_>>();
+
+        let comparator = build_comparator(&sort_options_with_array)?;
+        let last_row = |index: usize| heads[index].num_rows() - 1;
+
+        // The head whose last row is the smallest one bounds the rows to merge.
+        let bound = (1..heads.len()).fold(0, |bound, index| {
+            match comparator(index, last_row(index), bound, last_row(bound)) {
+                Ordering::Less => index,
+                _ => bound,
+            }
+        });
+
+        let mut prefixes = Vec::with_capacity(heads.len());
+        let mut rests = Vec::with_capacity(heads.len());
+        for (index, head) in heads.iter().enumerate() {
+            // The number of the rows not greater than the bound, by binary search.
+            let (mut low, mut high) = (0, head.num_rows());
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match comparator(index, mid, bound, last_row(bound)) {
+                    Ordering::Greater => high = mid,
+                    _ => low = mid + 1,
+                }
+            }
+
+            if low > 0 {
+                prefixes.push(DataBlock::slice_block(head, 0, low));
+            }
+            rests.push(DataBlock::slice_block(head, low, head.num_rows() - low));
+        }
+
+        let merged = DataBlock::merge_sort_blocks(&prefixes, sort_columns_descriptions, None)?;
+        Ok((merged, rests))
+    }
+
     pub fn merge_sort_blocks(
         blocks: &[DataBlock],
         sort_columns_descriptions: &[SortColumnDescription],
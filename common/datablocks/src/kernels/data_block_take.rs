@@ -51,4 +51,27 @@ impl DataBlock {
 
         Ok(DataBlock::create(raw.schema().clone(), columns))
     }
+
+    /// Take rows by optional indices, a `None` index yields a row of NULL values.
+    pub fn block_take_by_opt_indices(
+        raw: &DataBlock,
+        indices: &[Option<usize>],
+    ) -> Result<DataBlock> {
+        if indices.is_empty() {
+            return Ok(DataBlock::empty_with_schema(raw.schema().clone()));
+        }
+
+        let columns = raw
+            .columns()
+            .iter()
+            .map(|column| {
+                let array = column.to_array()?;
+                let mut indices = indices.iter().copied();
+                let series = unsafe { array.take_opt_iter_unchecked(&mut indices) }?;
+                Ok(DataColumn::Array(series))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DataBlock::create(raw.schema().clone(), columns))
+    }
 }
@@ -116,3 +116,52 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_merge_sort_heads() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    let head1 = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![3, 5, 7]),
+        Series::new(vec!["b1", "b2", "b3"]),
+    ]);
+
+    let head2 = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![2, 4, 6]),
+        Series::new(vec!["b4", "b5", "b6"]),
+    ]);
+
+    let head3 = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![8, 9]),
+        Series::new(vec!["b7", "b8"]),
+    ]);
+
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+    }];
+    let (merged, rests) = DataBlock::merge_sort_heads(&[head1, head2, head3], &options)?;
+
+    // The rows up to 6, the last row of the second head, are merged.
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 2 | b4 |",
+        "| 3 | b1 |",
+        "| 4 | b5 |",
+        "| 5 | b2 |",
+        "| 6 | b6 |",
+        "+---+----+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[merged]);
+
+    let rest_rows = rests.iter().map(|rest| rest.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rest_rows, vec![1, 0, 2]);
+
+    Ok(())
+}
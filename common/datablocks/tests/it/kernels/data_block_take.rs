@@ -43,3 +43,38 @@ fn test_data_block_take() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_take_by_opt_indices() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::String, true),
+    ]);
+
+    let raw = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![1i64, 2, 3]),
+        Series::new(vec!["b1", "b2", "b3"]),
+    ]);
+
+    let take = DataBlock::block_take_by_opt_indices(&raw, &[Some(2), None, Some(0)])?;
+    assert_eq!(raw.schema(), take.schema());
+
+    let expected = vec![
+        "+------+------+",
+        "| a    | b    |",
+        "+------+------+",
+        "| 3    | b3   |",
+        "| NULL | NULL |",
+        "| 1    | b1   |",
+        "+------+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[take]);
+
+    // All NULL rows from an empty block.
+    let empty = DataBlock::empty_with_schema(schema);
+    let take = DataBlock::block_take_by_opt_indices(&empty, &[None, None])?;
+    assert_eq!(2, take.num_rows());
+    assert_eq!(2, take.column(0).to_array()?.null_count());
+
+    Ok(())
+}
@@ -36,6 +36,15 @@ pub trait ArrayCast: Debug {
 }
 
 fn cast_ca(ca: &dyn Array, data_type: &DataType) -> Result<Series> {
+    // The decimal values are scaled, they must be rescaled by cast_to_decimal instead.
+    if let DataType::Decimal(_, _) = data_type {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
+            ca.data_type(),
+            data_type,
+        )));
+    }
+
     let arrow_type = data_type.to_arrow();
     let arrow_type = get_physical_arrow_type(&arrow_type);
     // we enable ignore_overflow by default
@@ -98,7 +107,9 @@ impl ArrayCast for DFNullArray {
             DataType::Int8 => Ok(DFInt8Array::full_null(self.len()).into_series()),
             DataType::Int16 => Ok(DFInt16Array::full_null(self.len()).into_series()),
             DataType::Int32 => Ok(DFInt32Array::full_null(self.len()).into_series()),
-            DataType::Int64 => Ok(DFInt64Array::full_null(self.len()).into_series()),
+            DataType::Int64 | DataType::Decimal(_, _) | DataType::DateTime64(_, _) => {
+                Ok(DFInt64Array::full_null(self.len()).into_series())
+            }
             DataType::Float32 => Ok(DFFloat32Array::full_null(self.len()).into_series()),
             DataType::Float64 => Ok(DFFloat64Array::full_null(self.len()).into_series()),
             DataType::String => Ok(DFStringArray::full_null(self.len()).into_series()),
@@ -17,6 +17,8 @@ use std::collections::BTreeMap;
 use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_macros::MallocSizeOf;
 
+use crate::parse_datetime64_metadata;
+use crate::parse_decimal_metadata;
 use crate::DataType;
 
 #[derive(
@@ -78,11 +80,19 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
+            DataType::Variant => Some("Variant"),
+            DataType::Decimal(_, _) => Some("Decimal"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, tz) => match tz {
+                Some(tz) => Some(format!("{},{}", precision, tz)),
+                None => Some(format!("{}", precision)),
+            },
+            DataType::Decimal(precision, scale) => Some(format!("{},{}", precision, scale)),
             _ => None,
         };
 
@@ -114,6 +124,21 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "DateTime64" => {
+                        if let Some((precision, tz)) =
+                            metatada.and_then(|m| parse_datetime64_metadata(m))
+                        {
+                            dt = DataType::DateTime64(precision, tz);
+                        }
+                    }
+                    "Variant" => dt = DataType::Variant,
+                    "Decimal" => {
+                        if let Some((precision, scale)) =
+                            metatada.and_then(|m| parse_decimal_metadata(m))
+                        {
+                            dt = DataType::Decimal(precision, scale);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -249,6 +249,25 @@ impl DataValue {
         }
     }
 
+    pub fn as_f64(&self) -> Result<f64> {
+        match self {
+            DataValue::Int8(Some(v)) => Ok(*v as f64),
+            DataValue::Int16(Some(v)) => Ok(*v as f64),
+            DataValue::Int32(Some(v)) => Ok(*v as f64),
+            DataValue::Int64(Some(v)) => Ok(*v as f64),
+            DataValue::UInt8(Some(v)) => Ok(*v as f64),
+            DataValue::UInt16(Some(v)) => Ok(*v as f64),
+            DataValue::UInt32(Some(v)) => Ok(*v as f64),
+            DataValue::UInt64(Some(v)) => Ok(*v as f64),
+            DataValue::Float32(Some(v)) => Ok(*v as f64),
+            DataValue::Float64(Some(v)) => Ok(*v),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get f64 number",
+                other.data_type()
+            ))),
+        }
+    }
+
     pub fn as_bool(&self) -> Result<bool> {
         match self {
             DataValue::Null => Ok(false),
@@ -359,8 +378,10 @@ impl From<&DataType> for DataValue {
             DataType::DateTime32(_) => DataValue::UInt32(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
-            DataType::String => DataValue::String(None),
-            DataType::Interval(_) => DataValue::Int64(None),
+            DataType::String | DataType::Variant => DataValue::String(None),
+            DataType::Interval(_) | DataType::Decimal(_, _) | DataType::DateTime64(_, _) => {
+                DataValue::Int64(None)
+            }
         }
     }
 }
@@ -66,7 +66,9 @@ impl DataValue {
             }
             DataType::Int16 => try_build_array! {PrimitiveArrayBuilder, i16, Int16, values},
             DataType::Int32 => try_build_array! {PrimitiveArrayBuilder, i32, Int32, values},
-            DataType::Int64 => try_build_array! {PrimitiveArrayBuilder, i64, Int64, values},
+            DataType::Int64 | DataType::Decimal(_, _) | DataType::DateTime64(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i64, Int64, values}
+            }
             DataType::UInt8 => try_build_array! {PrimitiveArrayBuilder, u8, UInt8, values},
             DataType::UInt16 => {
                 try_build_array! {PrimitiveArrayBuilder, u16, UInt16, values}
@@ -84,7 +86,7 @@ impl DataValue {
                 try_build_array! {PrimitiveArrayBuilder, f64, Float64, values}
             }
             DataType::Boolean => try_build_array! {values},
-            DataType::String => try_build_array! {String, values},
+            DataType::String | DataType::Variant => try_build_array! {String, values},
             DataType::Date16 => {
                 try_build_array! {PrimitiveArrayBuilder, u16, UInt16, values}
             }
@@ -94,6 +96,20 @@ impl DataValue {
             DataType::DateTime32(_) => {
                 try_build_array! {PrimitiveArrayBuilder, u32, UInt32, values}
             }
+            DataType::List(field) => {
+                let mut builder = get_list_builder(field.data_type(), 0, values.len());
+                for value in values.iter() {
+                    match value {
+                        DataValue::List(Some(v), _) => {
+                            let series = DataValue::try_into_data_array(v, field.data_type())?;
+                            builder.append_series(&series);
+                        }
+                        DataValue::List(None, _) => builder.append_null(),
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
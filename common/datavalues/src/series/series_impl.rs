@@ -189,6 +189,17 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
     /// This doesn't check any bounds or null validity.
     unsafe fn take_iter_unchecked(&self, _iter: &mut dyn Iterator<Item = usize>) -> Result<Series>;
 
+    /// Take by optional index from an iterator, a `None` index yields a Null value.
+    /// This operation clones the data.
+    ///
+    /// # Safety
+    ///
+    /// This doesn't check any bounds, the `Some` indices must be in bounds.
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        _iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Result<Series>;
+
     /// scatter the arrays by indices, the size of indices must be equal to the size of array
     /// # Safety
     /// Note this doesn't do any bound checking, for performance reason.
@@ -311,6 +311,14 @@ macro_rules! impl_dyn_array {
                 Ok(ArrayTake::take_unchecked(&self.0, iter.into())?.into_series())
             }
 
+            unsafe fn take_opt_iter_unchecked(
+                &self,
+                iter: &mut dyn Iterator<Item = Option<usize>>,
+            ) -> Result<Series> {
+                let indices: TakeIdxIterNull<_> = SeriesWrap(iter).into();
+                Ok(ArrayTake::take_unchecked(&self.0, indices)?.into_series())
+            }
+
             /// scatter the arrays by indices, the size of indices must be equal to the size of array
             unsafe fn scatter_unchecked(
                 &self,
@@ -36,6 +36,9 @@ pub enum DataType {
     Int64,
     Float32,
     Float64,
+    /// An exact numeric with precision and scale, e.g. Decimal(10, 2),
+    /// it's physical type is Int64 which holds the value scaled by 10^scale
+    Decimal(usize, usize),
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (16 bits), it's physical type is UInt16
     Date16,
@@ -48,11 +51,20 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// in ticks of 10^-precision seconds, it's physical type is Int64
+    /// Option<String> indicates the timezone, if it's None, it's UTC
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
+
+    /// A semi-structured value, e.g. a JSON object, array or scalar,
+    /// it's physical type is String which holds the JSON text
+    Variant,
 }
 
 #[derive(
@@ -102,16 +114,18 @@ impl DataType {
             Int64 => ArrowDataType::Int64,
             Float32 => ArrowDataType::Float32,
             Float64 => ArrowDataType::Float64,
+            Decimal(_, _) => ArrowDataType::Int64,
             Date16 => ArrowDataType::UInt16,
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
                 ArrowDataType::Struct(arrows_fields)
             }
-            String => ArrowDataType::LargeBinary,
+            String | Variant => ArrowDataType::LargeBinary,
             Interval(_) => ArrowDataType::Int64,
         }
     }
@@ -154,6 +168,15 @@ impl From<&ArrowDataType> for DataType {
                 "Date16" => DataType::Date16,
                 "Date32" => DataType::Date32,
                 "DateTime32" => DataType::DateTime32(extra.clone()),
+                "DateTime64" => match extra.as_ref().and_then(|m| parse_datetime64_metadata(m)) {
+                    Some((precision, tz)) => DataType::DateTime64(precision, tz),
+                    None => unimplemented!("data_type: {}", dt),
+                },
+                "Variant" => DataType::Variant,
+                "Decimal" => match extra.as_ref().and_then(|m| parse_decimal_metadata(m)) {
+                    Some((precision, scale)) => DataType::Decimal(precision, scale),
+                    None => unimplemented!("data_type: {}", dt),
+                },
                 _ => unimplemented!("data_type: {}", dt),
             },
 
@@ -170,6 +193,20 @@ impl From<&ArrowDataType> for DataType {
     }
 }
 
+/// Parses the decimal metadata `precision,scale` kept in the arrow field.
+pub fn parse_decimal_metadata(metadata: &str) -> Option<(usize, usize)> {
+    let (precision, scale) = metadata.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
+
+/// Parses the datetime64 metadata `precision[,timezone]` kept in the arrow field.
+pub fn parse_datetime64_metadata(metadata: &str) -> Option<(u32, Option<String>)> {
+    match metadata.split_once(',') {
+        Some((precision, tz)) => Some((precision.trim().parse().ok()?, Some(tz.to_string()))),
+        None => Some((metadata.trim().parse().ok()?, None)),
+    }
+}
+
 pub fn get_physical_arrow_type(data_type: &ArrowDataType) -> &ArrowDataType {
     if let ArrowDataType::Extension(_name, arrow_type, _extra) = data_type {
         return get_physical_arrow_type(arrow_type.as_ref());
@@ -193,6 +230,7 @@ impl fmt::Debug for DataType {
             Self::Int64 => write!(f, "Int64"),
             Self::Float32 => write!(f, "Float32"),
             Self::Float64 => write!(f, "Float64"),
+            Self::Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
             Self::Date16 => write!(f, "Date16"),
             Self::Date32 => write!(f, "Date32"),
             Self::DateTime32(arg0) => {
@@ -202,9 +240,17 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, tz) => {
+                if let Some(tz) = tz {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
             Self::String => write!(f, "String"),
+            Self::Variant => write!(f, "Variant"),
             Self::Interval(unit) => write!(f, "Interval({})", unit.to_string()),
         }
     }
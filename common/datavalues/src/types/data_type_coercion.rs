@@ -20,6 +20,7 @@ use common_exception::Result;
 use crate::prelude::DataType;
 use crate::DataField;
 use crate::DataValueArithmeticOperator;
+use crate::MAX_DECIMAL_PRECISION;
 
 /// Determine if a DataType is signed numeric or not
 pub fn is_signed_numeric(dt: &DataType) -> bool {
@@ -43,6 +44,10 @@ pub fn is_numeric(dt: &DataType) -> bool {
         )
 }
 
+pub fn is_decimal(dt: &DataType) -> bool {
+    matches!(dt, DataType::Decimal(_, _))
+}
+
 pub fn is_interval(dt: &DataType) -> bool {
     matches!(dt, DataType::Interval(_))
 }
@@ -61,7 +66,7 @@ pub fn is_floating(dt: &DataType) -> bool {
 pub fn is_date_or_date_time(dt: &DataType) -> bool {
     matches!(
         dt,
-        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) | DataType::DateTime64(_, _)
     )
 }
 
@@ -251,6 +256,102 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+/// Returns the precision and scale of the decimal type, an integer type is
+/// treated as a decimal type with scale 0 which holds all of its digits,
+/// and NULL as one without any digit, so it takes the type of the other side.
+pub fn decimal_precision_scale(dt: &DataType) -> Option<(usize, usize)> {
+    match dt {
+        DataType::Decimal(precision, scale) => Some((*precision, *scale)),
+        DataType::Null => Some((0, 0)),
+        DataType::Int8 | DataType::UInt8 => Some((3, 0)),
+        DataType::Int16 | DataType::UInt16 => Some((5, 0)),
+        DataType::Int32 | DataType::UInt32 => Some((10, 0)),
+        DataType::Int64 => Some((19, 0)),
+        DataType::UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
+
+/// Coercion rule for decimal types: The decimal type that both lhs and rhs
+/// can be casted to without losing any digits, a floating type falls back to Float64.
+pub fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    if is_floating(lhs_type) || is_floating(rhs_type) {
+        return Ok(DataType::Float64);
+    }
+
+    let (lhs, rhs) = match (lhs_type, rhs_type) {
+        (DataType::String, _) | (DataType::Null, _) => (rhs_type, rhs_type),
+        (_, DataType::String) | (_, DataType::Null) => (lhs_type, lhs_type),
+        _ => (lhs_type, rhs_type),
+    };
+
+    match (decimal_precision_scale(lhs), decimal_precision_scale(rhs)) {
+        (Some((p1, s1)), Some((p2, s2))) => {
+            let scale = cmp::max(s1, s2);
+            let precision = cmp::max(p1 - s1, p2 - s2) + scale;
+            Ok(DataType::Decimal(
+                cmp::min(precision, MAX_DECIMAL_PRECISION),
+                scale,
+            ))
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't coerce {} and {} to decimal",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
+/// Coercion rule for decimal arithmetic, the result scale is:
+/// * plus/minus/modulo: max(s1, s2)
+/// * mul: s1 + s2
+/// * div: max(s1, s2)
+#[inline]
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    if is_floating(lhs_type) || is_floating(rhs_type) {
+        return Ok(DataType::Float64);
+    }
+
+    let e = || {
+        Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported decimal coercion ({:?}) {} ({:?})",
+            lhs_type, op, rhs_type
+        )))
+    };
+
+    let ((p1, s1), (p2, s2)) = match (
+        decimal_precision_scale(lhs_type),
+        decimal_precision_scale(rhs_type),
+    ) {
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+        _ => return e(),
+    };
+
+    let (precision, scale) = match op {
+        DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+            let scale = cmp::max(s1, s2);
+            (cmp::max(p1 - s1, p2 - s2) + scale + 1, scale)
+        }
+        DataValueArithmeticOperator::Mul => (p1 + p2, s1 + s2),
+        DataValueArithmeticOperator::Div => (MAX_DECIMAL_PRECISION, cmp::max(s1, s2)),
+        DataValueArithmeticOperator::Modulo => {
+            let scale = cmp::max(s1, s2);
+            (cmp::max(p1 - s1, p2 - s2) + scale, scale)
+        }
+    };
+
+    if scale > MAX_DECIMAL_PRECISION {
+        return e();
+    }
+    Ok(DataType::Decimal(
+        cmp::min(precision, MAX_DECIMAL_PRECISION),
+        scale,
+    ))
+}
+
 #[inline]
 pub fn datetime_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
@@ -279,6 +380,11 @@ pub fn datetime_arithmetic_coercion(
         DataValueArithmeticOperator::Minus => {
             if is_numeric(&b) || is_interval(&b) {
                 Ok(a)
+            } else if matches!(a, DataType::DateTime64(_, _))
+                || matches!(b, DataType::DateTime64(_, _))
+            {
+                // DateTime64 minus DateTime64 or DateTime32 in the ticks of the higher precision
+                Ok(DataType::Int64)
             } else {
                 // Date minus Date or DateTime minus DateTime
                 Ok(DataType::Int32)
@@ -323,6 +429,19 @@ pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     val_type: &DataType,
 ) -> Result<DataType> {
+    // the negative decimal keeps its precision and scale
+    if is_decimal(val_type) {
+        return match op {
+            DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+                Ok(val_type.clone())
+            }
+            other => Result::Err(ErrorCode::UnknownFunction(format!(
+                "Unexpected operator:{:?} to unary function",
+                other
+            ))),
+        };
+    }
+
     // error on any non-numeric type
     if !is_numeric(val_type) {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -372,6 +491,11 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
         }
     }
 
+    // one of is decimal and other is decimal, number or string
+    if is_decimal(lhs_type) || is_decimal(rhs_type) {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     // one of is String and other is number
     if (is_numeric(lhs_type) && rhs_type == &DataType::String)
         || (is_numeric(rhs_type) && lhs_type == &DataType::String)
@@ -394,6 +518,18 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
 
     // one of is datetime and other is number or string
     if is_date_or_date_time(lhs_type) || is_date_or_date_time(rhs_type) {
+        // one of is datetime64, keep the higher precision
+        match (lhs_type, rhs_type) {
+            (DataType::DateTime64(p1, tz1), DataType::DateTime64(p2, tz2)) => {
+                let tz = tz1.clone().or_else(|| tz2.clone());
+                return Ok(DataType::DateTime64(*p1.max(p2), tz));
+            }
+            (DataType::DateTime64(precision, tz), _) | (_, DataType::DateTime64(precision, tz)) => {
+                return Ok(DataType::DateTime64(*precision, tz.clone()));
+            }
+            _ => {}
+        }
+
         // one of is datetime
         if matches!(lhs_type, DataType::DateTime32(_))
             || matches!(rhs_type, DataType::DateTime32(_))
@@ -475,6 +611,8 @@ pub fn merge_types(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType>
             }
             if is_numeric(lhs_type) && is_numeric(rhs_type) {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if is_decimal(lhs_type) || is_decimal(rhs_type) {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {} and {}",
@@ -15,13 +15,23 @@
 use chrono::Date;
 use chrono::DateTime;
 use chrono::Duration;
+use chrono::NaiveDate;
 use chrono::TimeZone;
+use chrono::Timelike;
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use num::cast::AsPrimitive;
 
+use crate::prelude::*;
+
+/// The max precision of DateTime64, the ticks are nanoseconds.
+pub const MAX_DATETIME64_PRECISION: u32 = 9;
+
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz>;
 }
 
 impl<T> DateConverter for T
@@ -36,4 +46,184 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz> {
+        let (seconds, nanos) = datetime64_to_parts(self.as_(), precision);
+        tz.timestamp(seconds, nanos)
+    }
+}
+
+pub fn check_datetime64_type(precision: u32, tz: &Option<String>) -> Result<()> {
+    if precision > MAX_DATETIME64_PRECISION {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Invalid type DateTime64({}), the precision must be in [0, {}]",
+            precision, MAX_DATETIME64_PRECISION
+        )));
+    }
+    parse_timezone(tz)?;
+    Ok(())
+}
+
+/// Parses the timezone of the datetime types, None means UTC.
+pub fn parse_timezone(tz: &Option<String>) -> Result<Tz> {
+    match tz {
+        None => Ok(Tz::UTC),
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {}", tz))),
+    }
+}
+
+#[inline]
+pub fn datetime64_ticks_per_second(precision: u32) -> i64 {
+    10_i64.pow(precision)
+}
+
+/// Splits the DateTime64 ticks into the seconds since UNIX epoch and the nanoseconds of the second.
+pub fn datetime64_to_parts(ticks: i64, precision: u32) -> (i64, u32) {
+    let per_second = datetime64_ticks_per_second(precision);
+    let nanos = ticks.rem_euclid(per_second) * 10_i64.pow(MAX_DATETIME64_PRECISION - precision);
+    (ticks.div_euclid(per_second), nanos as u32)
+}
+
+/// Builds the DateTime64 ticks from the seconds since UNIX epoch and the nanoseconds of the second,
+/// the nanoseconds beyond the precision are truncated.
+pub fn datetime64_from_parts(seconds: i64, nanos: u32, precision: u32) -> i64 {
+    let nanos = nanos as i64 / 10_i64.pow(MAX_DATETIME64_PRECISION - precision);
+    seconds * datetime64_ticks_per_second(precision) + nanos
+}
+
+/// Rescales the DateTime64 ticks from one precision to another, truncating towards the past.
+pub fn rescale_datetime64(ticks: i64, from: u32, to: u32) -> i64 {
+    if from <= to {
+        ticks * 10_i64.pow(to - from)
+    } else {
+        ticks.div_euclid(10_i64.pow(from - to))
+    }
+}
+
+/// Formats the DateTime64 ticks as `%Y-%m-%d %H:%M:%S` with `precision` fractional digits.
+pub fn datetime64_to_string(ticks: i64, precision: u32, tz: &Tz) -> String {
+    let dt = ticks.to_date_time64(precision, tz);
+    let seconds = dt.format("%Y-%m-%d %H:%M:%S").to_string();
+    if precision == 0 {
+        return seconds;
+    }
+    let fraction = ticks.rem_euclid(datetime64_ticks_per_second(precision));
+    format!(
+        "{}.{:0width$}",
+        seconds,
+        fraction,
+        width = precision as usize
+    )
+}
+
+/// Parses the datetime string in the timezone, the fraction of seconds is optional.
+pub fn parse_datetime64(value: &[u8], precision: u32, tz: &Tz) -> Option<i64> {
+    let value = std::str::from_utf8(value).ok()?;
+    tz.datetime_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|dt| datetime64_from_parts(dt.timestamp(), dt.nanosecond(), precision))
+}
+
+#[inline]
+fn local_days_since_epoch(dt: &DateTime<Tz>) -> i64 {
+    let epoch = NaiveDate::from_ymd(1970, 1, 1);
+    (dt.naive_local().date() - epoch).num_days()
+}
+
+/// Casts the DateTime64 ticks to the data type, the dates are taken in the timezone of the DateTime64.
+pub fn cast_from_datetime64(
+    series: &Series,
+    precision: u32,
+    tz: &Option<String>,
+    to_type: &DataType,
+) -> Result<Series> {
+    let array = series.i64()?;
+    let timezone = parse_timezone(tz)?;
+    match to_type {
+        DataType::DateTime64(to_precision, _) => {
+            let it = array
+                .into_iter()
+                .map(|v| v.map(|v| rescale_datetime64(*v, precision, *to_precision)));
+            Ok(DFInt64Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::DateTime32(_) => {
+            let per_second = datetime64_ticks_per_second(precision);
+            let it = array
+                .into_iter()
+                .map(|v| v.map(|v| v.div_euclid(per_second) as u32));
+            Ok(DFUInt32Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::Date16 => {
+            let it = array.into_iter().map(|v| {
+                v.map(|v| local_days_since_epoch(&v.to_date_time64(precision, &timezone)) as u16)
+            });
+            Ok(DFUInt16Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::Date32 => {
+            let it = array.into_iter().map(|v| {
+                v.map(|v| local_days_since_epoch(&v.to_date_time64(precision, &timezone)) as i32)
+            });
+            Ok(DFInt32Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::String => {
+            let it = array
+                .into_iter()
+                .map(|v| v.map(|v| datetime64_to_string(*v, precision, &timezone)));
+            Ok(DFStringArray::new_from_opt_iter(it).into_series())
+        }
+        dt if is_numeric(dt) => series.cast_with_type(dt),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from DateTime64({}) into {}",
+            precision, other
+        ))),
+    }
+}
+
+/// Casts the values of the data type to the DateTime64 ticks, the strings and dates are taken in the timezone.
+pub fn cast_to_datetime64(
+    series: &Series,
+    from_type: &DataType,
+    precision: u32,
+    tz: &Option<String>,
+) -> Result<Series> {
+    let timezone = parse_timezone(tz)?;
+    let per_second = datetime64_ticks_per_second(precision);
+    match from_type {
+        DataType::DateTime64(from_precision, from_tz) => cast_from_datetime64(
+            series,
+            *from_precision,
+            from_tz,
+            &DataType::DateTime64(precision, tz.clone()),
+        ),
+        DataType::Null => Ok(DFInt64Array::full_null(series.len()).into_series()),
+        DataType::DateTime32(_) => {
+            let it = series
+                .u32()?
+                .into_iter()
+                .map(|v| v.map(|v| *v as i64 * per_second));
+            Ok(DFInt64Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::Date16 | DataType::Date32 => {
+            let array = series.cast_with_type(&DataType::Int64)?;
+            let it = array
+                .i64()?
+                .into_iter()
+                .map(|v| v.map(|v| v.to_date(&timezone).and_hms(0, 0, 0).timestamp() * per_second));
+            Ok(DFInt64Array::new_from_opt_iter(it).into_series())
+        }
+        DataType::String => {
+            let it = series
+                .string()?
+                .into_iter()
+                .map(|v| v.and_then(|v| parse_datetime64(v, precision, &timezone)));
+            Ok(DFInt64Array::new_from_opt_iter(it).into_series())
+        }
+        dt if is_numeric(dt) => series.cast_with_type(&DataType::Int64),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {} into DateTime64({})",
+            other, precision
+        ))),
+    }
 }
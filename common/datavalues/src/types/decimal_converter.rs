@@ -0,0 +1,290 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// The max precision of the decimal type, the values are stored as Int64.
+pub const MAX_DECIMAL_PRECISION: usize = 18;
+
+pub fn check_decimal_type(precision: usize, scale: usize) -> Result<()> {
+    if precision == 0 || precision > MAX_DECIMAL_PRECISION || scale > precision {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Invalid type Decimal({}, {}), the precision must be in [1, {}] and the scale must be in [0, precision]",
+            precision, scale, MAX_DECIMAL_PRECISION
+        )));
+    }
+    Ok(())
+}
+
+#[inline]
+pub fn decimal_pow10(exp: usize) -> i64 {
+    10_i64.pow(exp as u32)
+}
+
+/// The max absolute value of the scaled decimal with the precision.
+#[inline]
+pub fn decimal_max_value(precision: usize) -> i64 {
+    decimal_pow10(precision) - 1
+}
+
+#[inline]
+pub fn decimal_in_range(value: i64, precision: usize) -> bool {
+    value.unsigned_abs() <= decimal_max_value(precision) as u64
+}
+
+pub fn decimal_to_string(value: i64, scale: usize) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let pow = decimal_pow10(scale) as u64;
+    let abs = value.unsigned_abs();
+    let sign = if value < 0 { "-" } else { "" };
+    format!("{}{}.{:0width$}", sign, abs / pow, abs % pow, width = scale)
+}
+
+/// Rescales the decimal value from one scale to another, the value is rounded
+/// half away from zero if the scale is reduced. Returns None on overflow.
+pub fn rescale_decimal(value: i64, from_scale: usize, to_scale: usize) -> Option<i64> {
+    match from_scale.cmp(&to_scale) {
+        Ordering::Equal => Some(value),
+        Ordering::Less => value.checked_mul(decimal_pow10(to_scale - from_scale)),
+        Ordering::Greater => {
+            let pow = decimal_pow10(from_scale - to_scale);
+            let (quotient, remainder) = (value / pow, value % pow);
+            if remainder.abs() * 2 >= pow {
+                Some(quotient + value.signum())
+            } else {
+                Some(quotient)
+            }
+        }
+    }
+}
+
+/// Parses the decimal text like `-12.345` into the scaled value, the fraction
+/// digits beyond the scale are rounded half away from zero.
+pub fn parse_decimal(text: &[u8], precision: usize, scale: usize) -> Result<i64> {
+    let text = String::from_utf8_lossy(text);
+    let text = text.trim();
+    let bad_value = || ErrorCode::BadBytes(format!("Incorrect decimal value: {}", text));
+
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (integer, fraction) = match digits.find('.') {
+        Some(pos) => (&digits[..pos], &digits[pos + 1..]),
+        None => (digits, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(bad_value());
+    }
+
+    let out_of_range = || {
+        ErrorCode::Overflow(format!(
+            "Decimal value {} is out of the range of Decimal({}, {})",
+            text, precision, scale
+        ))
+    };
+
+    let integer = integer.trim_start_matches('0');
+    if integer.len() > precision - scale {
+        return Err(out_of_range());
+    }
+
+    let mut value: i64 = 0;
+    for c in integer
+        .bytes()
+        .chain(fraction.bytes().chain(std::iter::repeat(b'0')).take(scale))
+    {
+        value = value * 10 + (c - b'0') as i64;
+    }
+    if matches!(fraction.as_bytes().get(scale), Some(c) if *c >= b'5') {
+        value += 1;
+    }
+    if !decimal_in_range(value, precision) {
+        return Err(out_of_range());
+    }
+
+    Ok(if negative { -value } else { value })
+}
+
+#[inline]
+pub fn decimal_to_f64(value: i64, scale: usize) -> f64 {
+    value as f64 / decimal_pow10(scale) as f64
+}
+
+/// Converts the float to the scaled decimal value, returns None if it's out of range.
+pub fn f64_to_decimal(value: f64, precision: usize, scale: usize) -> Option<i64> {
+    let scaled = (value * decimal_pow10(scale) as f64).round();
+    if scaled.is_finite() && scaled.abs() <= decimal_max_value(precision) as f64 {
+        Some(scaled as i64)
+    } else {
+        None
+    }
+}
+
+/// Casts the scaled decimal values with the scale to the data type.
+pub fn cast_from_decimal(series: &Series, scale: usize, to_type: &DataType) -> Result<Series> {
+    let array = series.i64()?;
+    match to_type {
+        DataType::Decimal(precision, to_scale) => {
+            let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(array.len());
+            for value in array {
+                match value {
+                    Some(v) => match rescale_decimal(*v, scale, *to_scale) {
+                        Some(v) if decimal_in_range(v, *precision) => builder.append_value(v),
+                        _ => {
+                            return Err(ErrorCode::Overflow(format!(
+                                "Decimal value {} is out of the range of {}",
+                                decimal_to_string(*v, scale),
+                                to_type
+                            )))
+                        }
+                    },
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        }
+        DataType::String => {
+            let it = array
+                .into_iter()
+                .map(|v| v.map(|v| decimal_to_string(*v, scale)));
+            Ok(DFStringArray::new_from_opt_iter(it).into_series())
+        }
+        DataType::Boolean => {
+            let it = array.into_iter().map(|v| v.map(|v| *v != 0));
+            Ok(DFBooleanArray::new_from_opt_iter(it).into_series())
+        }
+        dt if is_floating(dt) => {
+            let it = array
+                .into_iter()
+                .map(|v| v.map(|v| decimal_to_f64(*v, scale)));
+            DFFloat64Array::new_from_opt_iter(it).cast_with_type(dt)
+        }
+        // the fraction is truncated like the float to integer cast
+        dt if is_integer(dt) => {
+            let pow = decimal_pow10(scale);
+            let it = array.into_iter().map(|v| v.map(|v| *v / pow));
+            DFInt64Array::new_from_opt_iter(it).cast_with_type(dt)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from Decimal into {}",
+            other
+        ))),
+    }
+}
+
+/// Casts the integer values to Int64, it's an error if the UInt64 value is out of range
+/// instead of wrapping around.
+pub fn cast_integer_to_i64(series: &Series, from_type: &DataType) -> Result<Series> {
+    if from_type != &DataType::UInt64 {
+        return series.cast_with_type(&DataType::Int64);
+    }
+
+    let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(series.len());
+    for value in series.u64()? {
+        match value {
+            Some(v) => match i64::try_from(*v) {
+                Ok(v) => builder.append_value(v),
+                Err(_) => {
+                    return Err(ErrorCode::Overflow(format!(
+                        "Value {} is out of the range of Int64",
+                        v
+                    )))
+                }
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// Casts the values of the data type to the scaled decimal values.
+pub fn cast_to_decimal(
+    series: &Series,
+    from_type: &DataType,
+    precision: usize,
+    scale: usize,
+) -> Result<Series> {
+    let out_of_range = |value: &dyn std::fmt::Display| {
+        ErrorCode::Overflow(format!(
+            "Value {} is out of the range of Decimal({}, {})",
+            value, precision, scale
+        ))
+    };
+
+    match from_type {
+        DataType::Decimal(_, from_scale) => {
+            cast_from_decimal(series, *from_scale, &DataType::Decimal(precision, scale))
+        }
+        DataType::Null => Ok(DFInt64Array::full_null(series.len()).into_series()),
+        DataType::String => {
+            let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(series.len());
+            for value in series.string()? {
+                match value {
+                    Some(v) => builder.append_value(parse_decimal(v, precision, scale)?),
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        }
+        dt if is_floating(dt) => {
+            let array = series.cast_with_type(&DataType::Float64)?;
+            let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(array.len());
+            for value in array.f64()? {
+                match value {
+                    Some(v) => match f64_to_decimal(*v, precision, scale) {
+                        Some(v) => builder.append_value(v),
+                        None => return Err(out_of_range(v)),
+                    },
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        }
+        dt if is_integer(dt) || dt == &DataType::Boolean => {
+            let array = cast_integer_to_i64(series, dt)?;
+            let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(array.len());
+            for value in array.i64()? {
+                match value {
+                    Some(v) => match rescale_decimal(*v, 0, scale) {
+                        Some(scaled) if decimal_in_range(scaled, precision) => {
+                            builder.append_value(scaled)
+                        }
+                        _ => return Err(out_of_range(v)),
+                    },
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {} into Decimal({}, {})",
+            other, precision, scale
+        ))),
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+/// Deserializes the decimal values, they are stored as the Int64 values scaled by 10^scale.
+pub struct DecimalDeserializer {
+    pub builder: PrimitiveArrayBuilder<i64>,
+    pub precision: usize,
+    pub scale: usize,
+}
+
+impl TypeDeserializer for DecimalDeserializer {
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if reader.eq_ignore_ascii_case(b"null") {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        let value = parse_decimal(reader, self.precision, self.scale)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
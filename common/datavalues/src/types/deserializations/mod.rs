@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -21,14 +20,20 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod date_time64;
+mod decimal;
 mod number;
 mod string;
+mod variant;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use date_time64::*;
+pub use decimal::*;
 pub use number::*;
 pub use string::*;
+pub use variant::*;
 
 pub trait TypeDeserializer: Send + Sync {
     fn de(&mut self, reader: &mut &[u8]) -> Result<()>;
@@ -59,16 +64,26 @@ impl DataType {
                 DataType::Date32 => Ok(Box::new(DateDeserializer::<i32> {
                     builder: PrimitiveArrayBuilder::<i32>::with_capacity(capacity),
                 })),
-                DataType::DateTime32(tz) => {
-                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                    Ok(Box::new(DateTimeDeserializer::<u32> {
-                        builder: PrimitiveArrayBuilder::<u32>::with_capacity(capacity),
-                        tz: tz.parse::<Tz>().unwrap(),
-                    }))
-                }
+                DataType::DateTime32(tz) => Ok(Box::new(DateTimeDeserializer::<u32> {
+                    builder: PrimitiveArrayBuilder::<u32>::with_capacity(capacity),
+                    tz: parse_timezone(&tz)?,
+                })),
+                DataType::DateTime64(precision, tz) => Ok(Box::new(DateTime64Deserializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    tz: parse_timezone(&tz)?,
+                })),
                 DataType::String => Ok(Box::new(StringDeserializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
+                DataType::Variant => Ok(Box::new(VariantDeserializer {
+                    builder: StringArrayBuilder::with_capacity(capacity),
+                })),
+                DataType::Decimal(precision, scale) => Ok(Box::new(DecimalDeserializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    scale,
+                })),
                 DataType::Interval(_) => Ok(Box::new(DateDeserializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
@@ -49,12 +49,12 @@ impl From<DataType> for PhysicalDataType {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 | DataType::Date32 => Int32,
-            DataType::Int64 => Int64,
+            DataType::Int64 | DataType::Decimal(_, _) | DataType::DateTime64(_, _) => Int64,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
             DataType::Struct(x) => Struct(x),
-            DataType::String => String,
+            DataType::String | DataType::Variant => String,
             DataType::Interval(_) => Int64,
         }
     }
@@ -0,0 +1,45 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// Serializes the scaled Int64 values of the decimal with its scale, e.g. `-12.30`.
+pub struct DecimalSerializer {
+    pub scale: usize,
+}
+
+impl TypeSerializer for DecimalSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::Int64(Some(v)) => Ok(decimal_to_string(*v, self.scale)),
+            _ => Ok("NULL".to_owned()),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array: &DFInt64Array = array.static_cast();
+
+        let result: Vec<String> = array
+            .iter()
+            .map(|x| {
+                x.map(|v| decimal_to_string(*v, self.scale))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono_tz::Tz;
 use common_exception::Result;
 
 use crate::prelude::*;
@@ -19,6 +20,9 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod date_time64;
+mod decimal;
+mod list;
 mod nulls;
 mod number;
 mod string;
@@ -27,6 +31,9 @@ mod r#struct;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use date_time64::*;
+pub use decimal::*;
+pub use list::*;
 pub use nulls::*;
 pub use number::*;
 pub use r#struct::*;
@@ -54,11 +61,22 @@ impl DataType {
             DataType::Float64 => Box::new(NumberSerializer::<f64>::default()),
             DataType::Date16 => Box::new(DateSerializer::<u16>::default()),
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
-            DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
-            DataType::String => Box::new(StringSerializer {}),
+            DataType::DateTime32(tz) => match parse_timezone(tz) {
+                Ok(tz) => Box::new(DateTimeSerializer::<u32>::create(tz)),
+                Err(_) => Box::new(DateTimeSerializer::<u32>::default()),
+            },
+            DataType::DateTime64(precision, tz) => Box::new(DateTime64Serializer {
+                precision: *precision,
+                tz: parse_timezone(tz).unwrap_or(Tz::UTC),
+            }),
+            DataType::String | DataType::Variant => Box::new(StringSerializer {}),
+            DataType::Decimal(_, scale) => Box::new(DecimalSerializer { scale: *scale }),
             DataType::Struct(fields) => Box::new(StructSerializer {
                 fields: fields.to_vec(),
             }),
+            DataType::List(field) => Box::new(ListSerializer {
+                inner: field.data_type().clone(),
+            }),
             _ => todo!(),
         }
     }
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_decimal_converter() -> Result<()> {
+    assert_eq!(decimal_to_string(123456, 2), "1234.56");
+    assert_eq!(decimal_to_string(-5, 3), "-0.005");
+    assert_eq!(decimal_to_string(42, 0), "42");
+
+    assert_eq!(parse_decimal(b" -12.3 ", 5, 2)?, -1230);
+    assert_eq!(parse_decimal(b".995", 5, 2)?, 100);
+    assert_eq!(parse_decimal(b"+0012", 4, 2)?, 1200);
+    assert!(parse_decimal(b"123", 4, 2).is_err());
+    assert!(parse_decimal(b"99.995", 4, 2).is_err());
+    assert!(parse_decimal(b"1e3", 18, 0).is_err());
+    assert!(parse_decimal(b"-", 18, 0).is_err());
+
+    assert_eq!(rescale_decimal(1255, 2, 1), Some(126));
+    assert_eq!(rescale_decimal(-1255, 2, 1), Some(-126));
+    assert_eq!(rescale_decimal(-1254, 2, 1), Some(-125));
+    assert_eq!(rescale_decimal(12, 0, 3), Some(12000));
+    assert_eq!(rescale_decimal(i64::MAX, 0, 1), None);
+
+    assert_eq!(f64_to_decimal(12.345, 10, 2), Some(1235));
+    assert_eq!(f64_to_decimal(1e20, 18, 0), None);
+    // The UInt64 values beyond Int64 are out of range instead of wrapping around.
+    let series = Series::new(vec![1u64, u64::MAX]);
+    assert!(cast_to_decimal(&series, &DataType::UInt64, 18, 0).is_err());
+    let series = Series::new(vec![1u64, 42]);
+    let expect = Series::new(vec![100i64, 4200]);
+    assert!(cast_to_decimal(&series, &DataType::UInt64, 18, 2)?.series_equal(&expect));
+
+    assert!(check_decimal_type(19, 2).is_err());
+    assert!(check_decimal_type(4, 5).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_coercion() -> Result<()> {
+    let tests = vec![
+        (
+            Plus,
+            DataType::Decimal(10, 2),
+            DataType::Decimal(8, 3),
+            DataType::Decimal(12, 3),
+        ),
+        (
+            Minus,
+            DataType::Decimal(10, 2),
+            DataType::Int32,
+            DataType::Decimal(13, 2),
+        ),
+        (
+            Mul,
+            DataType::Decimal(10, 2),
+            DataType::Decimal(5, 3),
+            DataType::Decimal(15, 5),
+        ),
+        (
+            Div,
+            DataType::Decimal(10, 2),
+            DataType::Decimal(5, 3),
+            DataType::Decimal(18, 3),
+        ),
+        (
+            Modulo,
+            DataType::Decimal(10, 2),
+            DataType::Int8,
+            DataType::Decimal(10, 2),
+        ),
+        (
+            Plus,
+            DataType::Decimal(18, 2),
+            DataType::Int64,
+            DataType::Decimal(18, 2),
+        ),
+        (
+            Plus,
+            DataType::Decimal(10, 2),
+            DataType::Float32,
+            DataType::Float64,
+        ),
+    ];
+    for (op, lhs, rhs, expect) in tests {
+        let result = decimal_arithmetic_coercion(&op, &lhs, &rhs)?;
+        assert_eq!(result, expect, "{:?} {} {:?}", lhs, op, rhs);
+    }
+
+    let result =
+        decimal_arithmetic_coercion(&Mul, &DataType::Decimal(18, 10), &DataType::Decimal(18, 10));
+    assert!(result.is_err());
+
+    assert_eq!(
+        compare_coercion(&DataType::Decimal(10, 2), &DataType::Decimal(8, 4))?,
+        DataType::Decimal(12, 4)
+    );
+    assert_eq!(
+        compare_coercion(&DataType::String, &DataType::Decimal(8, 4))?,
+        DataType::Decimal(8, 4)
+    );
+    assert_eq!(
+        compare_coercion(&DataType::Decimal(8, 4), &DataType::Float64)?,
+        DataType::Float64
+    );
+    Ok(())
+}
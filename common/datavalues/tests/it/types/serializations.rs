@@ -55,6 +55,34 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "datetime32_with_timezone",
+            data_type: DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            value: DataValue::UInt32(Some(1630320462)),
+            column: Series::new(vec![Some(1630320462u32), None]).into(),
+            val_str: "2021-08-30 18:47:42",
+            col_str: vec!["2021-08-30 18:47:42".to_owned(), "NULL".to_owned()],
+        },
+        Test {
+            name: "datetime64",
+            data_type: DataType::DateTime64(3, None),
+            value: DataValue::Int64(Some(1630320462123)),
+            column: Series::new(vec![Some(1630320462123i64), Some(-1i64), None]).into(),
+            val_str: "2021-08-30 10:47:42.123",
+            col_str: vec![
+                "2021-08-30 10:47:42.123".to_owned(),
+                "1969-12-31 23:59:59.999".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
+        Test {
+            name: "datetime64_with_timezone",
+            data_type: DataType::DateTime64(6, Some("America/New_York".to_string())),
+            value: DataValue::Int64(Some(1630320462000001)),
+            column: Series::new(vec![Some(1630320462000001i64), None]).into(),
+            val_str: "2021-08-30 06:47:42.000001",
+            col_str: vec!["2021-08-30 06:47:42.000001".to_owned(), "NULL".to_owned()],
+        },
         Test {
             name: "date32",
             data_type: DataType::Date32,
@@ -75,6 +103,26 @@ fn test_serializers() -> Result<()> {
             val_str: "hello",
             col_str: vec!["hello".to_owned(), "world".to_owned(), "NULL".to_owned()],
         },
+        Test {
+            name: "variant",
+            data_type: DataType::Variant,
+            value: DataValue::String(Some(br#"{"a":1}"#.to_vec())),
+            column: Series::new(vec![Some(r#"{"a":1}"#), Some("[1,2]"), None]).into(),
+            val_str: r#"{"a":1}"#,
+            col_str: vec![
+                r#"{"a":1}"#.to_owned(),
+                "[1,2]".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
+        Test {
+            name: "decimal",
+            data_type: DataType::Decimal(10, 2),
+            value: DataValue::Int64(Some(-1205)),
+            column: Series::new(vec![Some(123456i64), Some(-5i64), None]).into(),
+            val_str: "-12.05",
+            col_str: vec!["1234.56".to_owned(), "-0.05".to_owned(), "NULL".to_owned()],
+        },
     ];
 
     for test in tests {
@@ -105,5 +153,111 @@ fn test_serializers() -> Result<()> {
         assert_eq!(&result, expect);
     }
 
+    {
+        let data_type = DataType::List(Box::new(DataField::new("item", DataType::Float64, true)));
+        let serializer = data_type.create_serializer();
+        let value = DataValue::List(
+            Some(vec![
+                DataValue::Float64(Some(1.5)),
+                DataValue::Float64(None),
+                DataValue::Float64(Some(2.0)),
+            ]),
+            DataType::Float64,
+        );
+        let result = serializer.serialize_value(&value)?;
+        assert_eq!(&result, "[1.5,NULL,2]");
+
+        let data_type = DataType::List(Box::new(DataField::new("item", DataType::String, true)));
+        let serializer = data_type.create_serializer();
+        let value = DataValue::List(
+            Some(vec![
+                DataValue::String(Some(b"a".to_vec())),
+                DataValue::String(Some(b"b".to_vec())),
+            ]),
+            DataType::String,
+        );
+        let result = serializer.serialize_value(&value)?;
+        assert_eq!(&result, "['a','b']");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_variant_deserializer() -> Result<()> {
+    let mut deserializer = DataType::Variant.create_deserializer(3)?;
+    deserializer.de_text(br#"{ "a": [1, "x"] }"#)?;
+    deserializer.de_text(b" true ")?;
+    deserializer.de_null();
+
+    let series = deserializer.finish_to_series();
+    assert_eq!(series.string()?.collect_values(), vec![
+        Some(br#"{"a":[1,"x"]}"#.to_vec()),
+        Some(b"true".to_vec()),
+        None
+    ]);
+
+    let result = deserializer.de_text(b"{a:1}");
+    assert!(result.is_err());
+
+    // The variant type is kept in the arrow field, so it's stored in parquet as it is.
+    let field = DataField::new("v", DataType::Variant, true);
+    assert_eq!(DataField::from(&field.to_arrow()), field);
+    Ok(())
+}
+
+#[test]
+fn test_decimal_deserializer() -> Result<()> {
+    let mut deserializer = DataType::Decimal(5, 2).create_deserializer(4)?;
+    deserializer.de_text(b"123.45")?;
+    deserializer.de_text(b"-0.125")?;
+    deserializer.de_text(b"7")?;
+    deserializer.de_null();
+
+    let series = deserializer.finish_to_series();
+    assert_eq!(series.i64()?.collect_values(), vec![
+        Some(12345),
+        Some(-13),
+        Some(700),
+        None
+    ]);
+
+    let result = deserializer.de_text(b"1234.5");
+    assert!(result.is_err());
+    let result = deserializer.de_text(b"1.2.3");
+    assert!(result.is_err());
+
+    // The precision and scale are kept in the arrow field.
+    let field = DataField::new("d", DataType::Decimal(5, 2), true);
+    assert_eq!(DataField::from(&field.to_arrow()), field);
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_deserializer() -> Result<()> {
+    let data_type = DataType::DateTime64(3, Some("Asia/Shanghai".to_string()));
+    let mut deserializer = data_type.create_deserializer(4)?;
+    deserializer.de_text(b"2021-08-30 18:47:42.123")?;
+    deserializer.de_text(b"2021-08-30 18:47:42")?;
+    deserializer.de_text(b"1630320462123")?;
+    deserializer.de_null();
+
+    let series = deserializer.finish_to_series();
+    assert_eq!(series.i64()?.collect_values(), vec![
+        Some(1630320462123),
+        Some(1630320462000),
+        Some(1630320462123),
+        None
+    ]);
+
+    let result = deserializer.de_text(b"2021-13-01 00:00:00");
+    assert!(result.is_err());
+
+    let result = DataType::DateTime64(3, Some("Mars/Olympus".to_string())).create_deserializer(1);
+    assert!(result.is_err());
+
+    // The precision and timezone are kept in the arrow field.
+    let field = DataField::new("t", data_type, true);
+    assert_eq!(DataField::from(&field.to_arrow()), field);
     Ok(())
 }
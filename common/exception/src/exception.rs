@@ -183,6 +183,9 @@ build_exceptions! {
     DateTimeParseError(55),
     BadPredicateRows(56),
     SHA1CheckFailed(57),
+    TableHistoricalDataNotFound(58),
+    MemoryLimitExceeded(59),
+    UnknownColumn(60),
 
     // uncategorized
     UnexpectedResponseType(600),
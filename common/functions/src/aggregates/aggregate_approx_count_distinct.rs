@@ -0,0 +1,409 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+const HLL_PRECISION: u32 = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+// The guard bit set in `add_hash` caps the rank of a hash.
+const HLL_MAX_RANK: u8 = (64 - HLL_PRECISION + 1) as u8;
+
+const SERIALIZED_SPARSE: u8 = 0;
+const SERIALIZED_DENSE: u8 = 1;
+
+/// HyperLogLog with 2^12 registers, the relative error is about 1.6%.
+/// The registers live in the state itself, so the state has a fixed size
+/// whatever the number of distinct values is.
+pub struct HyperLogLog {
+    registers: [u8; HLL_REGISTERS],
+}
+
+pub trait DistinctSketch: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn create() -> Self;
+
+    fn add_hash(&mut self, hash: u64);
+
+    fn merge(&mut self, rhs: &Self);
+
+    fn count(&self) -> u64;
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()>;
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()>;
+}
+
+impl DistinctSketch for HyperLogLog {
+    fn name() -> &'static str {
+        "AggregateUniqHLL12Function"
+    }
+
+    fn create() -> Self {
+        Self {
+            registers: [0; HLL_REGISTERS],
+        }
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // Set a guard bit, so the rank is at most HLL_MAX_RANK.
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(rhs.registers.iter()) {
+            if *register < *other {
+                *register = *other;
+            }
+        }
+    }
+
+    fn count(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for register in self.registers.iter() {
+            sum += 1.0 / (1u64 << *register) as f64;
+            if *register == 0 {
+                zeros += 1;
+            }
+        }
+
+        let estimate = alpha * m * m / sum;
+        // Linear counting is more accurate for the small cardinalities.
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+
+    // Writes the non-zero registers only when it's smaller than the dense form.
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        let non_zeros = self.registers.iter().filter(|r| **r != 0).count();
+        if non_zeros * 3 < HLL_REGISTERS {
+            SERIALIZED_SPARSE.serialize_to_buf(writer)?;
+            writer.write_uvarint(non_zeros as u64)?;
+            for (index, register) in self.registers.iter().enumerate() {
+                if *register != 0 {
+                    (index as u16).serialize_to_buf(writer)?;
+                    register.serialize_to_buf(writer)?;
+                }
+            }
+        } else {
+            SERIALIZED_DENSE.serialize_to_buf(writer)?;
+            writer.put_slice(&self.registers);
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        match u8::deserialize(reader)? {
+            SERIALIZED_SPARSE => {
+                self.registers = [0; HLL_REGISTERS];
+                let size = reader.read_uvarint()?;
+                for _i in 0..size {
+                    let index = u16::deserialize(reader)?;
+                    let register = u8::deserialize(reader)?;
+                    if index as usize >= HLL_REGISTERS {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "Invalid HyperLogLog register index: {}",
+                            index
+                        )));
+                    }
+                    self.registers[index as usize] = register;
+                }
+            }
+            SERIALIZED_DENSE => reader.read_exact(&mut self.registers)?,
+            other => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Unknown HyperLogLog serialization kind: {}",
+                    other
+                )))
+            }
+        }
+
+        // `count` shifts by the registers, an out of range one would overflow it.
+        match self.registers.iter().find(|r| **r > HLL_MAX_RANK) {
+            Some(register) => Err(ErrorCode::BadBytes(format!(
+                "Invalid HyperLogLog register: {}",
+                register
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+const SMALL_SET_SIZE: usize = 16;
+
+/// Counts exactly while there are only a few distinct hashes, then switches to HyperLogLog.
+pub struct UniqCombinedState {
+    small: [u64; SMALL_SET_SIZE],
+    small_len: usize,
+    large: bool,
+    hll: HyperLogLog,
+}
+
+impl UniqCombinedState {
+    fn convert_to_large(&mut self) {
+        if !self.large {
+            for hash in &self.small[..self.small_len] {
+                self.hll.add_hash(*hash);
+            }
+            self.small_len = 0;
+            self.large = true;
+        }
+    }
+}
+
+impl DistinctSketch for UniqCombinedState {
+    fn name() -> &'static str {
+        "AggregateUniqCombinedFunction"
+    }
+
+    fn create() -> Self {
+        Self {
+            small: [0; SMALL_SET_SIZE],
+            small_len: 0,
+            large: false,
+            hll: HyperLogLog::create(),
+        }
+    }
+
+    #[inline(always)]
+    fn add_hash(&mut self, hash: u64) {
+        if !self.large {
+            if self.small[..self.small_len].contains(&hash) {
+                return;
+            }
+            if self.small_len < SMALL_SET_SIZE {
+                self.small[self.small_len] = hash;
+                self.small_len += 1;
+                return;
+            }
+            self.convert_to_large();
+        }
+        self.hll.add_hash(hash);
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        if rhs.large {
+            self.convert_to_large();
+            self.hll.merge(&rhs.hll);
+        } else {
+            for hash in &rhs.small[..rhs.small_len] {
+                self.add_hash(*hash);
+            }
+        }
+    }
+
+    fn count(&self) -> u64 {
+        if self.large {
+            return self.hll.count();
+        }
+        self.small_len as u64
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.large.serialize_to_buf(writer)?;
+        if self.large {
+            return self.hll.serialize(writer);
+        }
+
+        writer.write_uvarint(self.small_len as u64)?;
+        for hash in &self.small[..self.small_len] {
+            hash.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        *self = Self::create();
+        self.large = bool::deserialize(reader)?;
+        if self.large {
+            return self.hll.deserialize(reader);
+        }
+
+        let size = reader.read_uvarint()?;
+        for _i in 0..size {
+            self.add_hash(u64::deserialize(reader)?);
+        }
+        Ok(())
+    }
+}
+
+pub struct AggregateApproxCountDistinctFunction<S> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    s: PhantomData<S>,
+}
+
+impl<S> AggregateApproxCountDistinctFunction<S>
+where S: DistinctSketch
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            s: PhantomData,
+        }))
+    }
+
+    /// Hashes the rows of all the arguments, rows with a NULL argument are skipped.
+    /// SipHash with the default keys is used, so that the sketches built on
+    /// different nodes can be merged.
+    fn get_hashes(arrays: &[Series], input_rows: usize) -> Result<Vec<Option<u64>>> {
+        let mut hashes = vec![Some(0u64); input_rows];
+        for array in arrays {
+            let hasher = DFHasher::SipHasher(DefaultHasher::new());
+            let array_hashes = array.vec_hash(hasher)?;
+            for (hash, value) in hashes.iter_mut().zip(array_hashes.iter()) {
+                *hash = match (*hash, value) {
+                    (Some(hash), Some(value)) => {
+                        Some((hash ^ *value).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+                    }
+                    _ => None,
+                };
+            }
+        }
+        Ok(hashes)
+    }
+}
+
+impl<S> AggregateFunction for AggregateApproxCountDistinctFunction<S>
+where S: DistinctSketch
+{
+    fn name(&self) -> &str {
+        S::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(S::create);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<S>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<S>();
+        let hashes = Self::get_hashes(arrays, input_rows)?;
+        hashes
+            .iter()
+            .flatten()
+            .for_each(|hash| state.add_hash(*hash));
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        input_rows: usize,
+    ) -> Result<()> {
+        let hashes = Self::get_hashes(arrays, input_rows)?;
+        hashes.iter().zip(places.iter()).for_each(|(hash, place)| {
+            if let Some(hash) = hash {
+                let place = place.next(offset);
+                let state = place.get::<S>();
+                state.add_hash(*hash);
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<S>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<S>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<S>();
+        let state = place.get::<S>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<S>();
+        Ok(DataValue::UInt64(Some(state.count())))
+    }
+}
+
+impl<S> fmt::Display for AggregateApproxCountDistinctFunction<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_approx_count_distinct<S: DistinctSketch>(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+    AggregateApproxCountDistinctFunction::<S>::try_create(display_name, arguments)
+}
+
+pub fn aggregate_uniq_hll12_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_approx_count_distinct::<HyperLogLog>,
+    ))
+}
+
+pub fn aggregate_uniq_combined_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_approx_count_distinct::<UniqCombinedState>,
+    ))
+}
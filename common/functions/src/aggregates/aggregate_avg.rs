@@ -57,10 +57,24 @@ where T: std::ops::Add<Output = T> + DFPrimitiveType
     }
 }
 
+impl AggregateAvgState<i64> {
+    // The scaled decimal values are summed exactly, it's an error if the sum overflows.
+    #[inline(always)]
+    fn checked_add(&mut self, value: i64, count: u64) -> Result<()> {
+        match self.value.checked_add(value) {
+            Some(v) => {
+                self.value = v;
+                self.count += count;
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow("Decimal overflow in avg")),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateAvgFunction<T, SumT> {
     display_name: String,
-    _arguments: Vec<DataField>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -98,9 +112,12 @@ where
         let state = place.get::<AggregateAvgState<SumT>>();
         let value = arrays[0].sum()?;
         let count = arrays[0].len() - arrays[0].null_count();
-        let opt_sum: Option<SumT> = DFTryFrom::try_from(value).ok();
 
-        state.add(&opt_sum, count as u64);
+        // the sum of the empty or all null array is null
+        if !value.is_null() {
+            let sum: SumT = DFTryFrom::try_from(value)?;
+            state.add(&Some(sum), count as u64);
+        }
         Ok(())
     }
 
@@ -165,19 +182,116 @@ where
     SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
     Option<SumT>: Into<DataValue>,
 {
-    pub fn try_create(
-        display_name: &str,
-        arguments: Vec<DataField>,
-    ) -> Result<AggregateFunctionRef> {
+    pub fn try_create(display_name: &str) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_string(),
-            _arguments: arguments,
             t: PhantomData,
             sum_t: PhantomData,
         }))
     }
 }
 
+/// The avg of the decimal values, it's computed on the scaled Int64 values and keeps the
+/// scale of the argument.
+#[derive(Clone)]
+pub struct AggregateDecimalAvgFunction {
+    display_name: String,
+    scale: usize,
+}
+
+impl AggregateFunction for AggregateDecimalAvgFunction {
+    fn name(&self) -> &str {
+        "AggregateDecimalAvgFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Decimal(MAX_DECIMAL_PRECISION, self.scale))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateAvgState::<i64> { value: 0, count: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateAvgState<i64>>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateAvgState<i64>>();
+        for value in arrays[0].i64()?.into_iter().flatten() {
+            state.checked_add(*value, 1)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in arrays[0].i64()?.into_iter().zip(places.iter()) {
+            if let Some(v) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateAvgState<i64>>();
+                state.checked_add(*v, 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateAvgState<i64>>();
+        state.value.serialize_to_buf(writer)?;
+        state.count.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateAvgState<i64>>();
+        state.value = i64::deserialize(reader)?;
+        state.count = u64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateAvgState<i64>>();
+        let rhs = rhs.get::<AggregateAvgState<i64>>();
+        state.checked_add(rhs.value, rhs.count)
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateAvgState<i64>>();
+        if state.count == 0 {
+            return Ok(DataValue::Int64(None));
+        }
+
+        // the scaled avg is rounded half away from zero
+        let (sum, count) = (state.value as i128, state.count as i128);
+        let avg = (2 * sum + sum.signum() * count) / (2 * count);
+        Ok(DataValue::Int64(Some(avg as i64)))
+    }
+}
+
+impl fmt::Display for AggregateDecimalAvgFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimalAvgFunction {
+    pub fn try_create(display_name: &str, scale: usize) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            scale,
+        }))
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     _params: Vec<DataValue>,
@@ -186,11 +300,12 @@ pub fn try_create_aggregate_avg_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if let DataType::Decimal(_, scale) = data_type {
+        return AggregateDecimalAvgFunction::try_create(display_name, *scale);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
-        AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
-            display_name,
-            arguments,
-        )
+        AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(display_name)
     },
 
     {
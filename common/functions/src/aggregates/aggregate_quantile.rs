@@ -0,0 +1,453 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::traits::AsPrimitive;
+
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile_tdigest::QuantileTDigestState;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::with_match_primitive_type;
+
+/// The state of a quantile aggregate function, it must be able to
+/// serialize itself so that partial states can be merged across nodes.
+pub trait QuantileState<T>: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn create() -> Self;
+
+    fn result_type(data_type: &DataType) -> DataType;
+
+    fn add(&mut self, value: T);
+
+    fn merge(&mut self, rhs: &Self);
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()>;
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()>;
+
+    /// Returns one value for each level, NULL if no value was added.
+    fn quantiles(&mut self, levels: &[f64]) -> Vec<DataValue>;
+}
+
+/// Keeps all the values, used by the exact quantile functions.
+pub struct QuantileExactState<T> {
+    pub values: Vec<T>,
+}
+
+impl<T> QuantileExactState<T>
+where T: DFPrimitiveType
+{
+    #[inline(always)]
+    fn add(&mut self, value: T) {
+        // NaN can't be ordered, skip it like the other values that can't be compared.
+        if value.partial_cmp(&value).is_some() {
+            self.values.push(value);
+        }
+    }
+
+    fn sort(&mut self) {
+        self.values
+            .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.values.len() as u64)?;
+        for value in self.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let size: u64 = reader.read_uvarint()?;
+        self.values = Vec::with_capacity(size as usize);
+        for _i in 0..size {
+            self.values.push(T::deserialize(reader)?);
+        }
+        Ok(())
+    }
+}
+
+/// Exact quantile, returns the value at position `level * count` of the sorted values.
+pub struct QuantileExactLowState<T>(QuantileExactState<T>);
+
+impl<T> QuantileState<T> for QuantileExactLowState<T>
+where T: DFPrimitiveType
+{
+    fn name() -> &'static str {
+        "AggregateQuantileExactFunction"
+    }
+
+    fn create() -> Self {
+        Self(QuantileExactState { values: vec![] })
+    }
+
+    fn result_type(data_type: &DataType) -> DataType {
+        data_type.clone()
+    }
+
+    fn add(&mut self, value: T) {
+        self.0.add(value);
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        self.0.values.extend_from_slice(&rhs.0.values);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.0.serialize(writer)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.0.deserialize(reader)
+    }
+
+    fn quantiles(&mut self, levels: &[f64]) -> Vec<DataValue> {
+        let values = &mut self.0;
+        if values.values.is_empty() {
+            return levels
+                .iter()
+                .map(|_| DataValue::from(&T::data_type()))
+                .collect();
+        }
+
+        values.sort();
+        let size = values.values.len();
+        levels
+            .iter()
+            .map(|level| {
+                let index = ((level * size as f64) as usize).min(size - 1);
+                values.values[index].into()
+            })
+            .collect()
+    }
+}
+
+/// Exact quantile, linearly interpolates between the two closest values.
+pub struct QuantileInterpolatedState<T>(QuantileExactState<T>);
+
+impl<T> QuantileState<T> for QuantileInterpolatedState<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name() -> &'static str {
+        "AggregateQuantileFunction"
+    }
+
+    fn create() -> Self {
+        Self(QuantileExactState { values: vec![] })
+    }
+
+    fn result_type(_data_type: &DataType) -> DataType {
+        DataType::Float64
+    }
+
+    fn add(&mut self, value: T) {
+        self.0.add(value);
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        self.0.values.extend_from_slice(&rhs.0.values);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.0.serialize(writer)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.0.deserialize(reader)
+    }
+
+    fn quantiles(&mut self, levels: &[f64]) -> Vec<DataValue> {
+        let values = &mut self.0;
+        if values.values.is_empty() {
+            return levels.iter().map(|_| DataValue::Float64(None)).collect();
+        }
+
+        values.sort();
+        let size = values.values.len();
+        levels
+            .iter()
+            .map(|level| {
+                let position = level * (size - 1) as f64;
+                let low = position.floor() as usize;
+                let high = (low + 1).min(size - 1);
+
+                let low_value: f64 = values.values[low].as_();
+                let high_value: f64 = values.values[high].as_();
+                let value = low_value + (high_value - low_value) * (position - low as f64);
+                DataValue::Float64(Some(value))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuantileKind {
+    Interpolated,
+    Exact,
+    TDigest,
+}
+
+pub struct AggregateQuantileFunction<T, S> {
+    display_name: String,
+    arguments: Vec<DataField>,
+    levels: Vec<f64>,
+    // `quantiles` returns an array with one element per level.
+    multiple: bool,
+    t: PhantomData<T>,
+    s: PhantomData<S>,
+}
+
+impl<T, S> AggregateFunction for AggregateQuantileFunction<T, S>
+where
+    T: DFPrimitiveType,
+    S: QuantileState<T>,
+{
+    fn name(&self) -> &str {
+        S::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        let data_type = S::result_type(self.arguments[0].data_type());
+        if self.multiple {
+            return Ok(DataType::List(Box::new(DataField::new(
+                "item", data_type, true,
+            ))));
+        }
+        Ok(data_type)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(S::create);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<S>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<S>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(*value);
+            }
+        } else {
+            array.iter().for_each(|value| {
+                if let Some(value) = value {
+                    state.add(*value);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<S>();
+                state.add(*value);
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<S>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<S>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<S>();
+        let state = place.get::<S>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<S>();
+        let mut values = state.quantiles(&self.levels);
+
+        if self.multiple {
+            let data_type = S::result_type(self.arguments[0].data_type());
+            return Ok(DataValue::List(Some(values), data_type));
+        }
+        Ok(values.remove(0))
+    }
+}
+
+impl<T, S> fmt::Display for AggregateQuantileFunction<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, S> AggregateQuantileFunction<T, S>
+where
+    T: DFPrimitiveType,
+    S: QuantileState<T>,
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        levels: Vec<f64>,
+        multiple: bool,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            arguments,
+            levels,
+            multiple,
+            t: PhantomData,
+            s: PhantomData,
+        }))
+    }
+}
+
+fn get_levels(display_name: &str, params: &[DataValue]) -> Result<Vec<f64>> {
+    params
+        .iter()
+        .map(|param| {
+            let level = param.as_f64()?;
+            if !(0.0..=1.0).contains(&level) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} expect the level to be in [0, 1], but got {}",
+                    display_name, param
+                )));
+            }
+            Ok(level)
+        })
+        .collect()
+}
+
+fn try_create_aggregate_quantile(
+    display_name: &str,
+    levels: Vec<f64>,
+    multiple: bool,
+    arguments: Vec<DataField>,
+    kind: QuantileKind,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type().clone();
+    with_match_primitive_type!(data_type, |$T| {
+        match kind {
+            QuantileKind::Interpolated => {
+                type S = QuantileInterpolatedState<$T>;
+                AggregateQuantileFunction::<$T, S>::try_create(display_name, arguments, levels, multiple)
+            }
+            QuantileKind::Exact => {
+                type S = QuantileExactLowState<$T>;
+                AggregateQuantileFunction::<$T, S>::try_create(display_name, arguments, levels, multiple)
+            }
+            QuantileKind::TDigest => {
+                type S = QuantileTDigestState;
+                AggregateQuantileFunction::<$T, S>::try_create(display_name, arguments, levels, multiple)
+            }
+        }
+    },
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+/// quantile(level)(x), the level defaults to 0.5.
+pub fn aggregate_quantile_desc(kind: QuantileKind) -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        move |display_name, params, arguments| -> Result<AggregateFunctionRef> {
+            if params.len() > 1 {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have at most one parameter, but got {}",
+                    display_name,
+                    params.len()
+                )));
+            }
+
+            let mut levels = get_levels(display_name, &params)?;
+            if levels.is_empty() {
+                levels.push(0.5);
+            }
+            try_create_aggregate_quantile(display_name, levels, false, arguments, kind)
+        },
+    ))
+}
+
+/// quantiles(level1, level2, ...)(x), returns an array of the quantiles.
+pub fn aggregate_quantiles_desc(kind: QuantileKind) -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        move |display_name, params, arguments| -> Result<AggregateFunctionRef> {
+            if params.is_empty() {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have at least one parameter",
+                    display_name
+                )));
+            }
+
+            let levels = get_levels(display_name, &params)?;
+            try_create_aggregate_quantile(display_name, levels, true, arguments, kind)
+        },
+    ))
+}
+
+/// median(x), an alias of quantile(0.5)(x).
+pub fn aggregate_median_desc(kind: QuantileKind) -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        move |display_name, params, arguments| -> Result<AggregateFunctionRef> {
+            if !params.is_empty() {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have no parameters, but got {}",
+                    display_name,
+                    params.len()
+                )));
+            }
+
+            try_create_aggregate_quantile(display_name, vec![0.5], false, arguments, kind)
+        },
+    ))
+}
@@ -0,0 +1,218 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::traits::AsPrimitive;
+
+use crate::aggregates::aggregate_quantile::QuantileState;
+
+// The compression factor of the digest, the number of centroids is in O(COMPRESSION).
+const COMPRESSION: f64 = 100.0;
+// Compress the digest once this many centroids are buffered without being merged.
+const MAX_UNMERGED: usize = 2048;
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    count: f64,
+}
+
+/// A merging t-digest, see https://github.com/tdunning/t-digest.
+/// Centroids near the tails are kept small, so extreme quantiles stay accurate
+/// while the memory usage is bounded regardless of the number of values.
+pub struct QuantileTDigestState {
+    centroids: Vec<Centroid>,
+    unmerged: usize,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl QuantileTDigestState {
+    fn add_centroid(&mut self, centroid: Centroid) {
+        if self.count == 0.0 {
+            self.min = centroid.mean;
+            self.max = centroid.mean;
+        } else {
+            self.min = self.min.min(centroid.mean);
+            self.max = self.max.max(centroid.mean);
+        }
+
+        self.count += centroid.count;
+        self.centroids.push(centroid);
+        self.unmerged += 1;
+        if self.unmerged > MAX_UNMERGED {
+            self.compress();
+        }
+    }
+
+    fn compress(&mut self) {
+        if self.unmerged == 0 {
+            return;
+        }
+        self.unmerged = 0;
+
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(Ordering::Equal));
+
+        let mut merged: Vec<Centroid> = Vec::with_capacity(centroids.len());
+        let mut weight_so_far = 0.0;
+        for centroid in centroids {
+            if let Some(current) = merged.last_mut() {
+                let proposed = current.count + centroid.count;
+                let q = (weight_so_far + proposed / 2.0) / self.count;
+                let limit = 4.0 * self.count * q * (1.0 - q) / COMPRESSION;
+
+                if proposed <= limit {
+                    current.mean += (centroid.mean - current.mean) * centroid.count / proposed;
+                    current.count = proposed;
+                    continue;
+                }
+                weight_so_far += current.count;
+            }
+            merged.push(centroid);
+        }
+        self.centroids = merged;
+    }
+
+    fn quantile(&self, level: f64) -> f64 {
+        let centroids = &self.centroids;
+        if centroids.len() == 1 {
+            return centroids[0].mean;
+        }
+
+        let target = level * self.count;
+        let first = &centroids[0];
+        if target < first.count / 2.0 {
+            return interpolate(0.0, self.min, first.count / 2.0, first.mean, target);
+        }
+
+        let mut weight_so_far = 0.0;
+        for pair in centroids.windows(2) {
+            let left_center = weight_so_far + pair[0].count / 2.0;
+            let right_center = weight_so_far + pair[0].count + pair[1].count / 2.0;
+            if target <= right_center {
+                return interpolate(
+                    left_center,
+                    pair[0].mean,
+                    right_center,
+                    pair[1].mean,
+                    target,
+                );
+            }
+            weight_so_far += pair[0].count;
+        }
+
+        let last = &centroids[centroids.len() - 1];
+        let last_center = self.count - last.count / 2.0;
+        interpolate(last_center, last.mean, self.count, self.max, target)
+    }
+}
+
+#[inline]
+fn interpolate(x0: f64, y0: f64, x1: f64, y1: f64, x: f64) -> f64 {
+    if x1 <= x0 {
+        return y0;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+impl<T> QuantileState<T> for QuantileTDigestState
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name() -> &'static str {
+        "AggregateQuantileTDigestFunction"
+    }
+
+    fn create() -> Self {
+        Self {
+            centroids: vec![],
+            unmerged: 0,
+            count: 0.0,
+            min: 0.0,
+            max: 0.0,
+        }
+    }
+
+    fn result_type(_data_type: &DataType) -> DataType {
+        DataType::Float64
+    }
+
+    fn add(&mut self, value: T) {
+        let value: f64 = value.as_();
+        if !value.is_nan() {
+            self.add_centroid(Centroid {
+                mean: value,
+                count: 1.0,
+            });
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        if rhs.count == 0.0 {
+            return;
+        }
+        for centroid in rhs.centroids.iter() {
+            self.add_centroid(*centroid);
+        }
+        // The means of the centroids are not the extremes once they are merged.
+        self.min = self.min.min(rhs.min);
+        self.max = self.max.max(rhs.max);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        self.min.serialize_to_buf(writer)?;
+        self.max.serialize_to_buf(writer)?;
+        writer.write_uvarint(self.centroids.len() as u64)?;
+        for centroid in self.centroids.iter() {
+            centroid.mean.serialize_to_buf(writer)?;
+            centroid.count.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        *self = <Self as QuantileState<T>>::create();
+        let min = f64::deserialize(reader)?;
+        let max = f64::deserialize(reader)?;
+        let size: u64 = reader.read_uvarint()?;
+        for _i in 0..size {
+            let mean = f64::deserialize(reader)?;
+            let count = f64::deserialize(reader)?;
+            self.add_centroid(Centroid { mean, count });
+        }
+        if size > 0 {
+            self.min = min;
+            self.max = max;
+        }
+        Ok(())
+    }
+
+    fn quantiles(&mut self, levels: &[f64]) -> Vec<DataValue> {
+        if self.centroids.is_empty() {
+            return levels.iter().map(|_| DataValue::Float64(None)).collect();
+        }
+
+        self.compress();
+        levels
+            .iter()
+            .map(|level| DataValue::Float64(Some(self.quantile(*level))))
+            .collect()
+    }
+}
@@ -59,10 +59,29 @@ where
     }
 }
 
+impl AggregateSumState<i64> {
+    // The scaled decimal values are summed exactly, it's an error if the sum is out of range.
+    #[inline(always)]
+    fn checked_add(&mut self, other: i64) -> Result<()> {
+        let value = match self.value {
+            Some(a) => a
+                .checked_add(other)
+                .filter(|v| decimal_in_range(*v, MAX_DECIMAL_PRECISION)),
+            None => Some(other),
+        };
+        match value {
+            Some(v) => {
+                self.value = Some(v);
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow("Decimal overflow in sum")),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
-    _arguments: Vec<DataField>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -97,9 +116,10 @@ where
 
     fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
         let value = arrays[0].sum()?;
-        let opt_sum: Result<SumT> = DFTryFrom::try_from(value);
 
-        if let Ok(s) = opt_sum {
+        // the sum of the empty or all null array is null
+        if !value.is_null() {
+            let s: SumT = DFTryFrom::try_from(value)?;
             let state = place.get::<AggregateSumState<SumT>>();
             state.add(s);
         }
@@ -180,19 +200,109 @@ where
     SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
     Option<SumT>: Into<DataValue>,
 {
-    pub fn try_create(
-        display_name: &str,
-        arguments: Vec<DataField>,
-    ) -> Result<AggregateFunctionRef> {
+    pub fn try_create(display_name: &str) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
-            _arguments: arguments,
             t: PhantomData,
             sum_t: PhantomData,
         }))
     }
 }
 
+/// The sum of the decimal values, it's computed on the scaled Int64 values and keeps the
+/// scale of the argument.
+#[derive(Clone)]
+pub struct AggregateDecimalSumFunction {
+    display_name: String,
+    scale: usize,
+}
+
+impl AggregateFunction for AggregateDecimalSumFunction {
+    fn name(&self) -> &str {
+        "AggregateDecimalSumFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Decimal(MAX_DECIMAL_PRECISION, self.scale))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateSumState::<i64> { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateSumState<i64>>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateSumState<i64>>();
+        for value in arrays[0].i64()?.into_iter().flatten() {
+            state.checked_add(*value)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in arrays[0].i64()?.into_iter().zip(places.iter()) {
+            if let Some(v) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateSumState<i64>>();
+                state.checked_add(*v)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateSumState<i64>>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateSumState<i64>>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateSumState<i64>>();
+        if let Some(s) = rhs.value {
+            let state = place.get::<AggregateSumState<i64>>();
+            state.checked_add(s)?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateSumState<i64>>();
+        Ok(state.value.into())
+    }
+}
+
+impl fmt::Display for AggregateDecimalSumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimalSumFunction {
+    pub fn try_create(display_name: &str, scale: usize) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            scale,
+        }))
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     _params: Vec<DataValue>,
@@ -201,11 +311,12 @@ pub fn try_create_aggregate_sum_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if let DataType::Decimal(_, scale) = data_type {
+        return AggregateDecimalSumFunction::try_create(display_name, *scale);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
-        AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
-             display_name,
-             arguments,
-        )
+        AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(display_name)
     },
 
     // no matching branch
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::aggregates::aggregate_approx_count_distinct::aggregate_uniq_combined_desc;
+use crate::aggregates::aggregate_approx_count_distinct::aggregate_uniq_hll12_desc;
 use crate::aggregates::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use crate::aggregates::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use crate::aggregates::aggregate_avg::aggregate_avg_function_desc;
@@ -20,6 +22,10 @@ use crate::aggregates::aggregate_covariance::aggregate_covariance_sample_desc;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionFactory;
 use crate::aggregates::aggregate_min_max::aggregate_max_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_min_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_median_desc;
+use crate::aggregates::aggregate_quantile::aggregate_quantile_desc;
+use crate::aggregates::aggregate_quantile::aggregate_quantiles_desc;
+use crate::aggregates::aggregate_quantile::QuantileKind;
 use crate::aggregates::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 use crate::aggregates::aggregate_window_funnel::aggregate_window_funnel_function_desc;
@@ -44,8 +50,42 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register("uniqHLL12", aggregate_uniq_hll12_desc());
+        factory.register("approx_count_distinct", aggregate_uniq_hll12_desc());
+        factory.register("uniqCombined", aggregate_uniq_combined_desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+
+        factory.register(
+            "quantile",
+            aggregate_quantile_desc(QuantileKind::Interpolated),
+        );
+        factory.register(
+            "quantiles",
+            aggregate_quantiles_desc(QuantileKind::Interpolated),
+        );
+        factory.register("median", aggregate_median_desc(QuantileKind::Interpolated));
+        factory.register(
+            "quantileExact",
+            aggregate_quantile_desc(QuantileKind::Exact),
+        );
+        factory.register(
+            "quantilesExact",
+            aggregate_quantiles_desc(QuantileKind::Exact),
+        );
+        factory.register("medianExact", aggregate_median_desc(QuantileKind::Exact));
+        factory.register(
+            "quantileTDigest",
+            aggregate_quantile_desc(QuantileKind::TDigest),
+        );
+        factory.register(
+            "quantilesTDigest",
+            aggregate_quantiles_desc(QuantileKind::TDigest),
+        );
+        factory.register(
+            "medianTDigest",
+            aggregate_median_desc(QuantileKind::TDigest),
+        );
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
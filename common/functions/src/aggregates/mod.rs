@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
 mod aggregate_combinator_distinct;
@@ -21,6 +22,8 @@ mod aggregate_function;
 mod aggregate_function_factory;
 mod aggregate_function_state;
 mod aggregate_min_max;
+mod aggregate_quantile;
+mod aggregate_quantile_tdigest;
 mod aggregate_window_funnel;
 
 // mod aggregate_min_max;
@@ -33,8 +36,10 @@ mod aggregator_common;
 #[macro_use]
 mod macros;
 
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
+pub use aggregate_avg::AggregateDecimalAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
@@ -46,7 +51,9 @@ pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
 pub use aggregate_min_max::AggregateMinMaxFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
+pub use aggregate_sum::AggregateDecimalSumFunction;
 pub use aggregate_sum::AggregateSumFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
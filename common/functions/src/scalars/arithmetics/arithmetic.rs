@@ -21,6 +21,7 @@ use common_datavalues::DataValueArithmeticOperator;
 use common_exception::Result;
 
 use crate::scalars::dates::IntervalFunctionFactory;
+use crate::scalars::decimal_arithmetic;
 use crate::scalars::function::MonotonicityNode;
 use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::ArithmeticDivFunction;
@@ -28,6 +29,7 @@ use crate::scalars::ArithmeticMinusFunction;
 use crate::scalars::ArithmeticModuloFunction;
 use crate::scalars::ArithmeticMulFunction;
 use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::CastFunction;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -64,6 +66,9 @@ impl Function for ArithmeticFunction {
             return numerical_unary_arithmetic_coercion(&self.op, &args[0]);
         }
 
+        if is_decimal(&args[0]) || is_decimal(&args[1]) {
+            return decimal_arithmetic_coercion(&self.op, &args[0], &args[1]);
+        }
         if is_interval(&args[0]) || is_interval(&args[1]) {
             return interval_arithmetic_coercion(&self.op, &args[0], &args[1]);
         }
@@ -77,7 +82,33 @@ impl Function for ArithmeticFunction {
         Ok(false)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        // Decimal needs the precision and scale, which are only kept in the DataType.
+        if columns.iter().any(|c| is_decimal(c.data_type())) {
+            let args = columns
+                .iter()
+                .map(|f| f.data_type().clone())
+                .collect::<Vec<_>>();
+            let data_type = self.return_type(&args)?;
+            return decimal_arithmetic(&self.op, columns, &data_type);
+        }
+
+        // The ticks of DateTime64 depend on the precision, both sides are rescaled to the
+        // higher precision before subtracting, DateTime32 is taken as precision 0.
+        if let [lhs, rhs] = columns {
+            if matches!(self.op, DataValueArithmeticOperator::Minus)
+                && is_date_or_date_time(lhs.data_type())
+                && is_date_or_date_time(rhs.data_type())
+                && (matches!(lhs.data_type(), DataType::DateTime64(_, _))
+                    || matches!(rhs.data_type(), DataType::DateTime64(_, _)))
+            {
+                let data_type = compare_coercion(lhs.data_type(), rhs.data_type())?;
+                let lhs = CastFunction::cast_column(lhs, &data_type, input_rows)?;
+                let rhs = CastFunction::cast_column(rhs, &data_type, input_rows)?;
+                return lhs.arithmetic(self.op.clone(), &rhs);
+            }
+        }
+
         let result: DataColumn = {
             // Some logic type need DateType information, try arithmetic on column with field first.
             if let Some(f) = IntervalFunctionFactory::try_get_arithmetic_func(columns) {
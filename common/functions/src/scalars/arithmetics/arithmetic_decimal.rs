@@ -0,0 +1,134 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueArithmeticOperator;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Evaluates the arithmetic on the decimal columns, the decimal and integer operands
+/// are rescaled to the result scale and computed exactly on the scaled values.
+/// If the result type is a floating type, the operands are casted to Float64.
+pub fn decimal_arithmetic(
+    op: &DataValueArithmeticOperator,
+    columns: &DataColumnsWithField,
+    result_type: &DataType,
+) -> Result<DataColumn> {
+    let (precision, scale) = match result_type {
+        DataType::Decimal(precision, scale) => (*precision, *scale),
+        _ => {
+            let columns = columns
+                .iter()
+                .map(|c| Ok(DataColumn::Array(to_float64(c)?)))
+                .collect::<Result<Vec<_>>>()?;
+            return match columns.len() {
+                1 => columns[0].unary_arithmetic(op.clone()),
+                _ => columns[0].arithmetic(op.clone(), &columns[1]),
+            };
+        }
+    };
+
+    if columns.len() == 1 {
+        let series = columns[0].column().to_array()?;
+        return match op {
+            DataValueArithmeticOperator::Minus => {
+                let array = series.i64()?.apply(|v| -v);
+                Ok(array.into_series().into())
+            }
+            _ => Ok(series.into()),
+        };
+    }
+
+    let (lhs, s1) = to_scaled_i64(&columns[0])?;
+    let (rhs, s2) = to_scaled_i64(&columns[1])?;
+    match op {
+        DataValueArithmeticOperator::Plus => binary_decimal(&lhs, &rhs, precision, |a, b| {
+            Ok(rescale(a, scale - s1) + rescale(b, scale - s2))
+        }),
+        DataValueArithmeticOperator::Minus => binary_decimal(&lhs, &rhs, precision, |a, b| {
+            Ok(rescale(a, scale - s1) - rescale(b, scale - s2))
+        }),
+        DataValueArithmeticOperator::Mul => binary_decimal(&lhs, &rhs, precision, |a, b| Ok(a * b)),
+        DataValueArithmeticOperator::Modulo => {
+            binary_decimal(&lhs, &rhs, precision, |a, b| match rescale(b, scale - s2) {
+                0 => Err(ErrorCode::BadArguments("Division by zero")),
+                b => Ok(rescale(a, scale - s1) % b),
+            })
+        }
+        // a / b = (a * 10^(scale + s2 - s1) / b) / 10^scale, rounded half away from zero
+        DataValueArithmeticOperator::Div => binary_decimal(&lhs, &rhs, precision, |a, b| {
+            if b == 0 {
+                return Err(ErrorCode::BadArguments("Division by zero"));
+            }
+            let a = a
+                .checked_mul(10_i128.pow((scale + s2 - s1) as u32))
+                .ok_or_else(overflow)?;
+            let (quotient, remainder) = (a / b, a % b);
+            if remainder.abs() * 2 >= b.abs() {
+                Ok(quotient + a.signum() * b.signum())
+            } else {
+                Ok(quotient)
+            }
+        }),
+    }
+}
+
+fn overflow() -> ErrorCode {
+    ErrorCode::Overflow("Decimal overflow")
+}
+
+#[inline]
+fn rescale(value: i128, exp: usize) -> i128 {
+    value * 10_i128.pow(exp as u32)
+}
+
+fn to_float64(column: &DataColumnWithField) -> Result<Series> {
+    let series = column.column().to_array()?;
+    match column.data_type() {
+        DataType::Decimal(_, scale) => cast_from_decimal(&series, *scale, &DataType::Float64),
+        _ => series.cast_with_type(&DataType::Float64),
+    }
+}
+
+/// Returns the scaled Int64 values of the decimal or integer column and its scale.
+fn to_scaled_i64(column: &DataColumnWithField) -> Result<(Series, usize)> {
+    let series = column.column().to_array()?;
+    match column.data_type() {
+        DataType::Decimal(_, scale) => Ok((series, *scale)),
+        DataType::Null => Ok((DFInt64Array::full_null(series.len()).into_series(), 0)),
+        dt => Ok((cast_integer_to_i64(&series, dt)?, 0)),
+    }
+}
+
+fn binary_decimal<F>(lhs: &Series, rhs: &Series, precision: usize, f: F) -> Result<DataColumn>
+where F: Fn(i128, i128) -> Result<i128> {
+    let max_value = decimal_max_value(precision) as i128;
+    let (lhs, rhs) = (lhs.i64()?, rhs.i64()?);
+
+    let mut builder = PrimitiveArrayBuilder::<i64>::with_capacity(lhs.len());
+    for (a, b) in lhs.into_iter().zip(rhs.into_iter()) {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let value = f(*a as i128, *b as i128)?;
+                if value.abs() > max_value {
+                    return Err(overflow());
+                }
+                builder.append_value(value as i64);
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series().into())
+}
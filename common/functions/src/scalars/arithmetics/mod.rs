@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetic;
+mod arithmetic_decimal;
 mod arithmetic_div;
 mod arithmetic_minus;
 mod arithmetic_modulo;
@@ -20,6 +21,7 @@ mod arithmetic_mul;
 mod arithmetic_plus;
 
 pub use arithmetic::ArithmeticFunction;
+pub use arithmetic_decimal::decimal_arithmetic;
 pub use arithmetic_div::ArithmeticDivFunction;
 pub use arithmetic_minus::ArithmeticMinusFunction;
 pub use arithmetic_modulo::ArithmeticModuloFunction;
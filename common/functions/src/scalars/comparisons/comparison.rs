@@ -24,11 +24,15 @@ use crate::scalars::CastFunction;
 use crate::scalars::ComparisonEqFunction;
 use crate::scalars::ComparisonGtEqFunction;
 use crate::scalars::ComparisonGtFunction;
+use crate::scalars::ComparisonInFunction;
 use crate::scalars::ComparisonLikeFunction;
 use crate::scalars::ComparisonLtEqFunction;
 use crate::scalars::ComparisonLtFunction;
 use crate::scalars::ComparisonNotEqFunction;
+use crate::scalars::ComparisonNotInFunction;
 use crate::scalars::ComparisonNotLikeFunction;
+use crate::scalars::ComparisonNotRegexpFunction;
+use crate::scalars::ComparisonRegexpFunction;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -47,6 +51,15 @@ impl ComparisonFunction {
         factory.register("<>", ComparisonNotEqFunction::desc());
         factory.register("like", ComparisonLikeFunction::desc());
         factory.register("not like", ComparisonNotLikeFunction::desc());
+        factory.register("in", ComparisonInFunction::desc());
+        factory.register("not in", ComparisonNotInFunction::desc());
+        factory.register("regexp", ComparisonRegexpFunction::desc());
+        factory.register("rlike", ComparisonRegexpFunction::desc());
+        factory.register("match", ComparisonRegexpFunction::desc());
+        factory.register("~", ComparisonRegexpFunction::desc());
+        factory.register("not regexp", ComparisonNotRegexpFunction::desc());
+        factory.register("not rlike", ComparisonNotRegexpFunction::desc());
+        factory.register("!~", ComparisonNotRegexpFunction::desc());
     }
 
     pub fn try_create_func(op: DataValueComparisonOperator) -> Result<Box<dyn Function>> {
@@ -0,0 +1,245 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::CastFunction;
+use crate::scalars::Function;
+
+/// The constant lists longer than this are probed with a hash set,
+/// instead of being compared with the value one by one.
+const HASH_SET_THRESHOLD: usize = 8;
+
+/// `value IN (item1, item2, ...)`, the arguments are (value, item1, item2, ...).
+///
+/// `value IN (SELECT ...)` has the subquery result list as the only item,
+/// the hash set of the list is built once and shared by all the blocks.
+#[derive(Clone)]
+pub struct ComparisonInFunction {
+    negated: bool,
+    subquery_set: Arc<Mutex<Option<Arc<InSet>>>>,
+}
+
+impl ComparisonInFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: false,
+            subquery_set: Arc::new(Mutex::new(None)),
+        }))
+    }
+
+    pub fn try_create_negated_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: true,
+            subquery_set: Arc::new(Mutex::new(None)),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("not in")
+                .bool_function(),
+        )
+    }
+
+    fn eval_by_comparison(value: &DataColumn, list: &[DataColumn]) -> Result<DataColumn> {
+        let mut result = value.compare(DataValueComparisonOperator::Eq, &list[0])?;
+        for item in &list[1..] {
+            let matched = value.compare(DataValueComparisonOperator::Eq, item)?;
+            result = result.logic(DataValueLogicOperator::Or, &[matched])?;
+        }
+        Ok(result)
+    }
+
+    fn eval_list(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let mut data_type = columns[0].data_type().clone();
+        for column in &columns[1..] {
+            data_type = compare_coercion(&data_type, column.data_type())?;
+        }
+
+        let cast = |column: &DataColumnWithField| {
+            CastFunction::cast_column(column, &data_type, input_rows)
+        };
+        let value = cast(&columns[0])?;
+        let list = columns[1..].iter().map(cast).collect::<Result<Vec<_>>>()?;
+
+        let is_constant_list = list
+            .iter()
+            .all(|item| matches!(item, DataColumn::Constant(_, _)));
+        match is_constant_list && list.len() > HASH_SET_THRESHOLD {
+            true => {
+                let items = list
+                    .iter()
+                    .map(|item| item.to_minimal_array())
+                    .collect::<Result<Vec<_>>>()?;
+                InSet::try_create(&items)?.probe(&value)
+            }
+            false => Self::eval_by_comparison(&value, &list),
+        }
+    }
+
+    fn eval_subquery(
+        &self,
+        columns: &DataColumnsWithField,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        let (values, item_type) = match columns[1].column() {
+            DataColumn::Constant(DataValue::List(Some(values), item_type), _) => {
+                (values, item_type)
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "Logical error: IN subquery result set must be const List(Some).",
+                ))
+            }
+        };
+
+        let data_type = compare_coercion(columns[0].data_type(), item_type)?;
+        let value = CastFunction::cast_column(&columns[0], &data_type, input_rows)?;
+
+        // Nothing is IN an empty set, even NULL.
+        if values.is_empty() {
+            return Ok(DataColumn::Constant(
+                DataValue::Boolean(Some(false)),
+                input_rows,
+            ));
+        }
+
+        let mut subquery_set = self.subquery_set.lock().unwrap();
+        let set = match subquery_set.as_ref() {
+            Some(set) => set.clone(),
+            None => {
+                let items = DataValue::try_into_data_array(values, item_type)?;
+                let items = DataColumnWithField::new(
+                    items.into(),
+                    DataField::new("items", item_type.clone(), true),
+                );
+                let items = CastFunction::cast_column(&items, &data_type, values.len())?;
+                let items = items.to_array()?;
+                let set = Arc::new(InSet::try_create(&[items])?);
+                *subquery_set = Some(set.clone());
+                set
+            }
+        };
+        drop(subquery_set);
+
+        set.probe(&value)
+    }
+}
+
+impl Function for ComparisonInFunction {
+    fn name(&self) -> &str {
+        "ComparisonInFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if let [_, DataType::Struct(_)] = args {
+            return Err(ErrorCode::BadArguments(
+                "IN subquery must return only one column",
+            ));
+        }
+        Ok(DataType::Boolean)
+    }
+
+    // NULL for a NULL value, and for a value not in a list with NULL.
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let result = match columns[1..] {
+            [ref subquery] if matches!(subquery.data_type(), DataType::List(_)) => {
+                self.eval_subquery(columns, input_rows)?
+            }
+            _ => self.eval_list(columns, input_rows)?,
+        };
+
+        match self.negated {
+            true => result.logic(DataValueLogicOperator::Not, &[]),
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonInFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.negated {
+            true => write!(f, "NOT IN"),
+            false => write!(f, "IN"),
+        }
+    }
+}
+
+/// The serialized keys of the IN list items.
+struct InSet {
+    keys: HashSet<Vec<u8>>,
+    has_null: bool,
+}
+
+impl InSet {
+    fn try_create(items: &[Series]) -> Result<InSet> {
+        let mut has_null = false;
+        let mut keys = HashSet::new();
+        for item in items {
+            let mut item_keys = vec![vec![]; item.len()];
+            item.serialize(&mut item_keys)?;
+
+            for (row, key) in item_keys.into_iter().enumerate() {
+                match item.is_null(row) {
+                    true => has_null = true,
+                    false => {
+                        keys.insert(key);
+                    }
+                }
+            }
+        }
+
+        Ok(InSet { keys, has_null })
+    }
+
+    fn probe(&self, value: &DataColumn) -> Result<DataColumn> {
+        let values = value.to_array()?;
+        let mut keys = vec![vec![]; values.len()];
+        values.serialize(&mut keys)?;
+
+        // Same as the comparisons: NULL if the value is NULL,
+        // or if it is not found and there is a NULL in the list.
+        let result: DFBooleanArray = keys
+            .iter()
+            .enumerate()
+            .map(|(row, key)| match values.is_null(row) {
+                true => None,
+                false if self.keys.contains(key) => Some(true),
+                false if self.has_null => None,
+                false => Some(false),
+            })
+            .collect();
+        Ok(result.into_series().into())
+    }
+}
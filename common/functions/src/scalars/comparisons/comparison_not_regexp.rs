@@ -0,0 +1,37 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ComparisonRegexpFunction;
+use crate::scalars::Function;
+
+pub struct ComparisonNotRegexpFunction;
+
+impl ComparisonNotRegexpFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        ComparisonRegexpFunction::try_create_negated_func(display_name)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("regexp")
+                .bool_function(),
+        )
+    }
+}
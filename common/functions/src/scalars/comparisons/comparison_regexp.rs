@@ -0,0 +1,103 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::cast_arguments;
+use crate::scalars::strings::check_string_argument;
+use crate::scalars::strings::string_at;
+use crate::scalars::strings::RegexpCache;
+use crate::scalars::Function;
+
+/// `str REGEXP pattern` checks whether the string matches the regular expression,
+/// the match is unanchored: the pattern may match any part of the string.
+#[derive(Clone)]
+pub struct ComparisonRegexpFunction {
+    negated: bool,
+}
+
+impl ComparisonRegexpFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonRegexpFunction { negated: false }))
+    }
+
+    pub fn try_create_negated_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonRegexpFunction { negated: true }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("not regexp")
+                .bool_function(),
+        )
+    }
+}
+
+impl Function for ComparisonRegexpFunction {
+    fn name(&self) -> &str {
+        match self.negated {
+            true => "not regexp",
+            false => "regexp",
+        }
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String, DataType::String])?;
+        let (values, patterns) = (series[0].string()?, series[1].string()?);
+
+        let mut regexps = RegexpCache::default();
+        let mut result = Vec::with_capacity(values.len());
+        for row in 0..values.len() {
+            result.push(match (string_at(values, row), string_at(patterns, row)) {
+                (Some(value), Some(pattern)) => {
+                    Some(regexps.get(pattern)?.is_match(value) != self.negated)
+                }
+                _ => None,
+            });
+        }
+
+        let column: DataColumn = DFBooleanArray::new_from_opt_slice(&result).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ComparisonRegexpFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.negated {
+            true => write!(f, "NOT REGEXP"),
+            false => write!(f, "REGEXP"),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnWithField;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::CastFunction;
+use crate::scalars::Function;
+
+/// CASE WHEN cond1 THEN result1 [WHEN cond2 THEN result2 ...] ELSE default END,
+/// the arguments are (cond1, result1, cond2, result2, ..., default).
+#[derive(Clone)]
+pub struct CaseFunction {
+    _display_name: String,
+}
+
+impl CaseFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CaseFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn result_types(args: &[DataType]) -> Result<Vec<DataType>> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Case expects (condition, result) pairs and a default result, but got {} arguments",
+                args.len()
+            )));
+        }
+
+        let mut result_types = args.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        result_types.push(args[args.len() - 1].clone());
+        Ok(result_types)
+    }
+}
+
+impl Function for CaseFunction {
+    fn name(&self) -> &str {
+        "CaseFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        common_datavalues::aggregate_types(&Self::result_types(args)?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let return_type = self.return_type(&types)?;
+        let cast = |column: &DataColumnWithField| {
+            CastFunction::cast_column(column, &return_type, input_rows)
+        };
+
+        // Fold from the default result backwards, so the first matched condition wins.
+        let mut result = cast(&columns[columns.len() - 1])?;
+        for pair in columns[..columns.len() - 1].chunks(2).rev() {
+            result = pair[0].column().if_then_else(&cast(&pair[1])?, &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for CaseFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CASE")
+    }
+}
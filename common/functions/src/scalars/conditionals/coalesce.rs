@@ -0,0 +1,97 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::CastFunction;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct CoalesceFunction {
+    _display_name: String,
+}
+
+impl CoalesceFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CoalesceFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    /// Returns the first non-null value of the columns, row by row.
+    pub(crate) fn coalesce(
+        columns: &DataColumnsWithField,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let return_type = common_datavalues::aggregate_types(&types)?;
+
+        // Cast by the field types first, the decimals of different scales or the integers
+        // mixed with decimals can't be merged by their physical values.
+        let columns = columns
+            .iter()
+            .map(|c| CastFunction::cast_column(c, &return_type, input_rows))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = columns[columns.len() - 1].clone();
+        for column in columns[..columns.len() - 1].iter().rev() {
+            result = column.is_not_null()?.if_then_else(column, &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl Function for CoalesceFunction {
+    fn name(&self) -> &str {
+        "CoalesceFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        common_datavalues::aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        Self::coalesce(columns, input_rows)
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COALESCE")
+    }
+}
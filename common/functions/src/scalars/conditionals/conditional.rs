@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::CaseFunction;
+use crate::scalars::CoalesceFunction;
 use crate::scalars::IfFunction;
+use crate::scalars::IfNullFunction;
+use crate::scalars::NullIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -21,5 +25,9 @@ pub struct ConditionalFunction;
 impl ConditionalFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("if", IfFunction::desc());
+        factory.register("case", CaseFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("ifNull", IfNullFunction::desc());
+        factory.register("nullIf", NullIfFunction::desc());
     }
 }
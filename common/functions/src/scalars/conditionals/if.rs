@@ -22,6 +22,7 @@ use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::CastFunction;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -59,10 +60,14 @@ impl Function for IfFunction {
         Ok(false)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
-        columns[0]
-            .column()
-            .if_then_else(columns[1].column(), columns[2].column())
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let return_type = common_datavalues::aggregate_types(&[
+            columns[1].data_type().clone(),
+            columns[2].data_type().clone(),
+        ])?;
+        let lhs = CastFunction::cast_column(&columns[1], &return_type, input_rows)?;
+        let rhs = CastFunction::cast_column(&columns[2], &return_type, input_rows)?;
+        columns[0].column().if_then_else(&lhs, &rhs)
     }
 }
 
@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod case;
+mod coalesce;
 mod conditional;
 mod r#if;
+mod if_null;
+mod null_if;
 
+pub use case::CaseFunction;
+pub use coalesce::CoalesceFunction;
 pub use conditional::ConditionalFunction;
+pub use if_null::IfNullFunction;
+pub use null_if::NullIfFunction;
 pub use r#if::IfFunction;
@@ -0,0 +1,82 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ComparisonEqFunction;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct NullIfFunction {
+    _display_name: String,
+}
+
+impl NullIfFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(NullIfFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for NullIfFunction {
+    fn name(&self) -> &str {
+        "NullIfFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let equals = ComparisonEqFunction::try_create_func("=")?.eval(columns, input_rows)?;
+        let nulls = DataColumn::Constant(DataValue::Null, input_rows);
+        let result = equals.if_then_else(&nulls, columns[0].column())?;
+
+        // Keep the physical type of the value, the field type may be a logical type like Decimal.
+        let data_type = columns[0].column().data_type();
+        match result.data_type() == data_type {
+            true => Ok(result),
+            false => result.cast_with_type(&data_type),
+        }
+    }
+}
+
+impl fmt::Display for NullIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NULLIF")
+    }
+}
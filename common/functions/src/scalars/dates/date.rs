@@ -19,6 +19,12 @@ use super::interval_function::MonthsArithmeticFunction;
 use super::interval_function::SecondsArithmeticFunction;
 use super::now::NowFunction;
 use super::number_function::ToMondayFunction;
+use super::DateAddFunction;
+use super::DateDiffFunction;
+use super::DateFormatStyle;
+use super::DateTruncFunction;
+use super::FormatDateTimeFunction;
+use super::ParseDateTimeFunction;
 use super::RoundFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
@@ -53,7 +59,8 @@ impl DateFunction {
             RoundFunction::try_create(display_name, round)
         });
 
-        FunctionDescription::creator(creator).features(FunctionFeatures::default().deterministic())
+        FunctionDescription::creator(creator)
+            .features(FunctionFeatures::default().deterministic().timezone_aware())
     }
 
     fn month_arithmetic_function_creator(factor: i64) -> FunctionDescription {
@@ -172,5 +179,35 @@ impl DateFunction {
             "subtractSeconds",
             Self::seconds_arithmetic_function_creator(-1),
         );
+
+        // formatters and parsers
+        factory.register(
+            "formatDateTime",
+            FormatDateTimeFunction::desc(DateFormatStyle::Strftime),
+        );
+        factory.register(
+            "date_format",
+            FormatDateTimeFunction::desc(DateFormatStyle::MySQL),
+        );
+        factory.register(
+            "parseDateTime",
+            ParseDateTimeFunction::desc(DateFormatStyle::Strftime),
+        );
+        factory.register(
+            "str_to_date",
+            ParseDateTimeFunction::desc(DateFormatStyle::MySQL),
+        );
+
+        // unit functions
+        factory.register("date_trunc", DateTruncFunction::desc());
+        factory.register("date_diff", DateDiffFunction::desc());
+        factory.register(
+            "date_add",
+            DateAddFunction::desc(DataValueArithmeticOperator::Plus),
+        );
+        factory.register(
+            "date_sub",
+            DateAddFunction::desc(DataValueArithmeticOperator::Minus),
+        );
     }
 }
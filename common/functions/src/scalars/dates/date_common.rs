@@ -0,0 +1,187 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Gets the timezone used by the date functions. The timezone argument takes precedence,
+/// then the timezone of the datetime type, otherwise it's UTC.
+/// The timezone argument is also where the planner passes the session timezone in.
+pub fn get_timezone(data_type: &DataType, tz_column: Option<&DataColumnWithField>) -> Result<Tz> {
+    if let Some(tz_column) = tz_column {
+        return match tz_column.column().try_get(0)? {
+            DataValue::String(Some(tz)) => {
+                let tz = String::from_utf8(tz)?;
+                parse_timezone(&Some(tz))
+            }
+            DataValue::String(None) => Ok(Tz::UTC),
+            other => Err(ErrorCode::BadArguments(format!(
+                "The timezone argument must be a string, but got {:?}",
+                other
+            ))),
+        };
+    }
+
+    match data_type {
+        DataType::DateTime32(tz) | DataType::DateTime64(_, tz) => parse_timezone(tz),
+        _ => Ok(Tz::UTC),
+    }
+}
+
+/// The number of days since UNIX epoch of the local date of the datetime.
+#[inline]
+pub fn get_day(date: DateTime<Tz>) -> u32 {
+    get_day_of_date(date.naive_local().date())
+}
+
+#[inline]
+pub fn get_day_of_date(date: NaiveDate) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
+    date.signed_duration_since(start).num_days() as u32
+}
+
+/// The local midnight of the days since UNIX epoch, returns the seconds since UNIX epoch.
+pub fn get_local_midnight(days: i64, tz: &Tz) -> i64 {
+    let midnight = (NaiveDate::from_ymd(1970, 1, 1) + Duration::days(days)).and_hms(0, 0, 0);
+    match tz.from_local_datetime(&midnight).earliest() {
+        Some(dt) => dt.timestamp(),
+        // The midnight is skipped by the daylight saving.
+        None => {
+            let offset = tz.offset_from_utc_datetime(&midnight).fix();
+            midnight.timestamp() - offset.local_minus_utc() as i64
+        }
+    }
+}
+
+/// Gets the datetimes of the date or datetime column in the timezone, the dates are the local midnight.
+pub fn get_datetimes(column: &DataColumnWithField, tz: &Tz) -> Result<Vec<Option<DateTime<Tz>>>> {
+    let array = column.column().to_array()?;
+    match column.data_type() {
+        DataType::Date16 | DataType::Date32 => {
+            let days = array.cast_with_type(&DataType::Int64)?;
+            let days = days.i64()?;
+            Ok(days
+                .into_iter()
+                .map(|v| v.map(|v| get_local_midnight(*v, tz).to_date_time(tz)))
+                .collect())
+        }
+        DataType::DateTime32(_) => Ok(array
+            .u32()?
+            .into_iter()
+            .map(|v| v.map(|v| v.to_date_time(tz)))
+            .collect()),
+        DataType::DateTime64(precision, _) => Ok(array
+            .i64()?
+            .into_iter()
+            .map(|v| v.map(|v| v.to_date_time64(*precision, tz)))
+            .collect()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected a date or datetime type, but got {}",
+            other
+        ))),
+    }
+}
+
+/// The unit of date_trunc, date_diff and date_add, e.g. `date_trunc('week', ts)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateUnit {
+    pub fn try_create(unit: &str) -> Result<Self> {
+        match unit.to_lowercase().as_str() {
+            "second" | "seconds" | "ss" | "s" => Ok(DateUnit::Second),
+            "minute" | "minutes" | "mi" | "n" => Ok(DateUnit::Minute),
+            "hour" | "hours" | "hh" | "h" => Ok(DateUnit::Hour),
+            "day" | "days" | "dd" | "d" => Ok(DateUnit::Day),
+            "week" | "weeks" | "wk" | "ww" => Ok(DateUnit::Week),
+            "month" | "months" | "mm" | "m" => Ok(DateUnit::Month),
+            "quarter" | "quarters" | "qq" | "q" => Ok(DateUnit::Quarter),
+            "year" | "years" | "yyyy" | "yy" => Ok(DateUnit::Year),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown date unit: {:?}, expected one of second, minute, hour, day, week, month, quarter and year",
+                unit
+            ))),
+        }
+    }
+
+    /// The unit argument must be a constant string, e.g. `'day'`.
+    pub fn try_from_column(function: &str, column: &DataColumnWithField) -> Result<Self> {
+        match column.column() {
+            DataColumn::Constant(DataValue::String(Some(unit)), _) => {
+                Self::try_create(&String::from_utf8_lossy(unit))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The unit argument of function {} must be a constant string",
+                function
+            ))),
+        }
+    }
+
+    /// The seconds of the units which have a fixed length.
+    pub fn seconds(&self) -> Option<u32> {
+        match self {
+            DateUnit::Second => Some(1),
+            DateUnit::Minute => Some(60),
+            DateUnit::Hour => Some(60 * 60),
+            DateUnit::Day => Some(24 * 60 * 60),
+            DateUnit::Week => Some(7 * 24 * 60 * 60),
+            _ => None,
+        }
+    }
+
+    /// The months of the units which are calendar based.
+    pub fn months(&self) -> Option<i64> {
+        match self {
+            DateUnit::Month => Some(1),
+            DateUnit::Quarter => Some(3),
+            DateUnit::Year => Some(12),
+            _ => None,
+        }
+    }
+
+    /// The number of the units since UNIX epoch of the local datetime,
+    /// the weeks start on Monday, e.g. `date_diff('week', a, b)` counts the Mondays in between.
+    pub fn relative_number(&self, dt: &NaiveDateTime) -> i64 {
+        let seconds = dt.timestamp();
+        match self {
+            DateUnit::Second => seconds,
+            DateUnit::Minute => seconds.div_euclid(60),
+            DateUnit::Hour => seconds.div_euclid(60 * 60),
+            DateUnit::Day => seconds.div_euclid(24 * 60 * 60),
+            // 1970-01-01 is Thursday
+            DateUnit::Week => (seconds.div_euclid(24 * 60 * 60) + 3).div_euclid(7),
+            DateUnit::Month => dt.year() as i64 * 12 + dt.month0() as i64,
+            DateUnit::Quarter => dt.year() as i64 * 4 + (dt.month0() / 3) as i64,
+            DateUnit::Year => dt.year() as i64,
+        }
+    }
+}
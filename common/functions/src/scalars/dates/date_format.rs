@@ -0,0 +1,234 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use common_datavalues::chrono::format::parse;
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::Parsed;
+use common_datavalues::chrono::format::StrftimeItems;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The style of the format specifiers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateFormatStyle {
+    /// The strftime specifiers, e.g. `%Y-%m-%d %H:%M:%S`, it's used by formatDateTime and parseDateTime.
+    Strftime,
+    /// The MySQL specifiers, e.g. `%Y-%m-%d %H:%i:%s`, it's used by DATE_FORMAT and STR_TO_DATE.
+    MySQL,
+}
+
+#[derive(Clone, Debug)]
+enum FormatItem {
+    Chrono(Vec<Item<'static>>),
+    /// The day of the month with English suffix, e.g. 1st, 2nd, it's `%D` of MySQL.
+    DayWithSuffix,
+}
+
+/// The compiled format of the date functions, both the strftime and MySQL specifiers
+/// are compiled into the chrono items.
+#[derive(Clone, Debug)]
+pub struct DateFormat {
+    format: String,
+    items: Vec<FormatItem>,
+}
+
+impl DateFormat {
+    pub fn try_create(format: &str, style: DateFormatStyle) -> Result<Self> {
+        let mut date_format = DateFormat {
+            format: format.to_string(),
+            items: vec![],
+        };
+        match style {
+            DateFormatStyle::Strftime => {
+                for item in StrftimeItems::new(format) {
+                    date_format.push(date_format.to_owned_item(item)?);
+                }
+            }
+            DateFormatStyle::MySQL => date_format.compile_mysql()?,
+        }
+        Ok(date_format)
+    }
+
+    pub fn format(&self, dt: &DateTime<Tz>) -> String {
+        let mut buf = String::with_capacity(self.format.len() + 8);
+        for item in &self.items {
+            // Writing into a String never fails, the invalid items are rejected by try_create.
+            let _ = match item {
+                FormatItem::Chrono(items) => write!(buf, "{}", dt.format_with_items(items.iter())),
+                FormatItem::DayWithSuffix => {
+                    let day = dt.day();
+                    let suffix = match day {
+                        1 | 21 | 31 => "st",
+                        2 | 22 => "nd",
+                        3 | 23 => "rd",
+                        _ => "th",
+                    };
+                    write!(buf, "{}{}", day, suffix)
+                }
+            };
+        }
+        buf
+    }
+
+    /// The chrono items for parsing, the specifiers which can't be parsed are rejected.
+    pub fn parse_items(&self) -> Result<Vec<Item<'static>>> {
+        let mut parse_items = vec![];
+        for item in &self.items {
+            match item {
+                FormatItem::Chrono(items) => parse_items.extend(items.iter().cloned()),
+                FormatItem::DayWithSuffix => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The format {:?} can't be used for parsing, %D is not supported",
+                        self.format
+                    )))
+                }
+            }
+        }
+        Ok(parse_items)
+    }
+
+    fn push(&mut self, item: Item<'static>) {
+        match self.items.last_mut() {
+            Some(FormatItem::Chrono(items)) => items.push(item),
+            _ => self.items.push(FormatItem::Chrono(vec![item])),
+        }
+    }
+
+    fn push_literal(&mut self, literal: &mut String) {
+        if !literal.is_empty() {
+            self.push(Item::OwnedLiteral(std::mem::take(literal).into()));
+        }
+    }
+
+    fn push_strftime(&mut self, specifier: &'static str) {
+        for item in StrftimeItems::new(specifier) {
+            self.push(item);
+        }
+    }
+
+    fn to_owned_item(&self, item: Item<'_>) -> Result<Item<'static>> {
+        match item {
+            Item::Literal(s) => Ok(Item::OwnedLiteral(s.into())),
+            Item::OwnedLiteral(s) => Ok(Item::OwnedLiteral(s)),
+            Item::Space(s) => Ok(Item::OwnedSpace(s.into())),
+            Item::OwnedSpace(s) => Ok(Item::OwnedSpace(s)),
+            Item::Numeric(numeric, pad) => Ok(Item::Numeric(numeric, pad)),
+            Item::Fixed(fixed) => Ok(Item::Fixed(fixed)),
+            Item::Error => Err(ErrorCode::BadArguments(format!(
+                "Invalid format string: {:?}",
+                self.format
+            ))),
+        }
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_date-format
+    fn compile_mysql(&mut self) -> Result<()> {
+        let format = self.format.clone();
+        let mut literal = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let specifier = match chars.next() {
+                Some(specifier) => specifier,
+                None => {
+                    literal.push('%');
+                    break;
+                }
+            };
+            let strftime = match specifier {
+                'a' => "%a",
+                'b' => "%b",
+                'c' => "%-m",
+                'D' => {
+                    self.push_literal(&mut literal);
+                    self.items.push(FormatItem::DayWithSuffix);
+                    continue;
+                }
+                'd' => "%d",
+                'e' => "%-d",
+                'f' => "%6f",
+                'H' => "%H",
+                'h' | 'I' => "%I",
+                'i' => "%M",
+                'j' => "%j",
+                'k' => "%-H",
+                'l' => "%-I",
+                'M' => "%B",
+                'm' => "%m",
+                'p' => "%p",
+                'r' => "%I:%M:%S %p",
+                'S' | 's' => "%S",
+                'T' => "%H:%M:%S",
+                'U' => "%U",
+                'v' => "%V",
+                'W' => "%A",
+                'w' => "%w",
+                'x' => "%G",
+                'Y' => "%Y",
+                'y' => "%y",
+                'u' | 'V' | 'X' => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The format specifier %{} is not supported",
+                        specifier
+                    )))
+                }
+                // %% is %, and %x is x for any x not listed above.
+                other => {
+                    literal.push(other);
+                    continue;
+                }
+            };
+
+            self.push_literal(&mut literal);
+            self.push_strftime(strftime);
+        }
+        self.push_literal(&mut literal);
+        Ok(())
+    }
+}
+
+/// Parses the datetime in the timezone with the items, the time parts are 0 if they are not given.
+/// Returns None if the value doesn't match the format.
+pub fn parse_datetime(value: &str, items: &[Item<'static>], tz: &Tz) -> Option<DateTime<Tz>> {
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, value, items.iter()).ok()?;
+    if let Some(timestamp) = parsed.timestamp {
+        return tz.timestamp_opt(timestamp, 0).single();
+    }
+
+    if parsed.hour_div_12.is_none() {
+        parsed.hour_div_12 = Some(0);
+    }
+    if parsed.hour_mod_12.is_none() {
+        parsed.hour_mod_12 = Some(0);
+    }
+    if parsed.minute.is_none() {
+        parsed.minute = Some(0);
+    }
+    if parsed.second.is_none() {
+        parsed.second = Some(0);
+    }
+    let date = parsed.to_naive_date().ok()?;
+    let time = parsed.to_naive_time().ok()?;
+    tz.from_local_datetime(&date.and_time(time)).earliest()
+}
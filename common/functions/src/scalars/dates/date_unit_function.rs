@@ -0,0 +1,320 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueArithmeticOperator;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_common::get_datetimes;
+use super::date_common::get_local_midnight;
+use super::date_common::get_timezone;
+use super::date_common::DateUnit;
+use super::IntervalFunctionFactory;
+use super::MonthsArithmeticFunction;
+use super::RoundFunction;
+use super::SecondsArithmeticFunction;
+use super::ToMondayFunction;
+use super::ToStartOfMonthFunction;
+use super::ToStartOfQuarterFunction;
+use super::ToStartOfYearFunction;
+use crate::scalars::function_factory::FactoryCreator;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+fn check_unit_argument(function: &str, unit: &DataType) -> Result<()> {
+    if unit != &DataType::String {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Function {} must have a string as the unit argument, but got {}",
+            function, unit
+        )));
+    }
+    Ok(())
+}
+
+fn check_date_argument(function: &str, date: &DataType) -> Result<()> {
+    if !is_date_or_date_time(date) {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Function {} must have a date or datetime type as argument, but got {}",
+            function, date
+        )));
+    }
+    Ok(())
+}
+
+/// date_trunc(unit, datetime[, timezone]) truncates the date or datetime to the start of the unit,
+/// the units are rounded by toStartOfSecond .. toStartOfDay, toMonday and toStartOfMonth .. toStartOfYear.
+#[derive(Clone)]
+pub struct DateTruncFunction {
+    display_name: String,
+}
+
+impl DateTruncFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTruncFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().timezone_aware())
+    }
+}
+
+impl Function for DateTruncFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_unit_argument(&self.display_name, &args[0])?;
+        check_date_argument(&self.display_name, &args[1])?;
+        Ok(args[1].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let unit = DateUnit::try_from_column(&self.display_name, &columns[0])?;
+        // (datetime[, timezone])
+        let args = &columns[1..];
+        let data_type = args[0].data_type();
+
+        let start_of = match unit {
+            DateUnit::Second | DateUnit::Minute | DateUnit::Hour | DateUnit::Day => {
+                return match data_type {
+                    DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                        let round = unit.seconds().unwrap_or(1);
+                        RoundFunction::try_create(&self.display_name, round)?.eval(args, input_rows)
+                    }
+                    // The dates are already the start of the day.
+                    _ => Ok(args[0].column().clone()),
+                };
+            }
+            DateUnit::Week => ToMondayFunction::try_create(&self.display_name)?,
+            DateUnit::Month => ToStartOfMonthFunction::try_create(&self.display_name)?,
+            DateUnit::Quarter => ToStartOfQuarterFunction::try_create(&self.display_name)?,
+            DateUnit::Year => ToStartOfYearFunction::try_create(&self.display_name)?,
+        };
+
+        // The days since UNIX epoch of the start date.
+        let days = start_of.eval(args, input_rows)?.to_array()?;
+        let days = days.u16()?;
+        let tz = get_timezone(data_type, args.get(1))?;
+        match data_type {
+            DataType::Date16 => Ok(days.clone().into_series().into()),
+            DataType::Date32 => {
+                let array: DFInt32Array = days.apply_cast_numeric(|v| v as i32);
+                Ok(array.into_series().into())
+            }
+            DataType::DateTime32(_) => {
+                let array: DFUInt32Array =
+                    days.apply_cast_numeric(|v| get_local_midnight(v as i64, &tz) as u32);
+                Ok(array.into_series().into())
+            }
+            DataType::DateTime64(precision, _) => {
+                let per_second = datetime64_ticks_per_second(*precision);
+                let array: DFInt64Array =
+                    days.apply_cast_numeric(|v| get_local_midnight(v as i64, &tz) * per_second);
+                Ok(array.into_series().into())
+            }
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Function {} must have a date or datetime type as argument, but got {}",
+                self.display_name, other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DateTruncFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// date_diff(unit, start, end[, timezone]) returns the number of the unit boundaries crossed from start to end,
+/// e.g. `date_diff('month', '2021-01-31', '2021-02-01')` is 1.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().timezone_aware())
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, 4))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_unit_argument(&self.display_name, &args[0])?;
+        check_date_argument(&self.display_name, &args[1])?;
+        check_date_argument(&self.display_name, &args[2])?;
+        Ok(DataType::Int64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let unit = DateUnit::try_from_column(&self.display_name, &columns[0])?;
+        let tz = get_timezone(columns[1].data_type(), columns.get(3))?;
+        let start = get_datetimes(&columns[1], &tz)?;
+        let end = get_datetimes(&columns[2], &tz)?;
+
+        let array = DFInt64Array::new_from_opt_iter(start.iter().zip(end.iter()).map(
+            |(start, end)| match (start, end) {
+                (Some(start), Some(end)) => Some(
+                    unit.relative_number(&end.naive_local())
+                        - unit.relative_number(&start.naive_local()),
+                ),
+                _ => None,
+            },
+        ));
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// date_add(unit, n, datetime) adds n units to the date or datetime, the MySQL form
+/// `DATE_ADD(datetime, INTERVAL n unit)` is also supported. date_sub is the subtraction.
+#[derive(Clone)]
+pub struct DateAddFunction {
+    display_name: String,
+    op: DataValueArithmeticOperator,
+}
+
+impl DateAddFunction {
+    pub fn try_create(
+        display_name: &str,
+        op: DataValueArithmeticOperator,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateAddFunction {
+            display_name: display_name.to_string(),
+            op,
+        }))
+    }
+
+    pub fn desc(op: DataValueArithmeticOperator) -> FunctionDescription {
+        let creator: FactoryCreator = Box::new(move |display_name| -> Result<Box<dyn Function>> {
+            Self::try_create(display_name, op.clone())
+        });
+
+        FunctionDescription::creator(creator).features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for DateAddFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() == 2 {
+            // DATE_ADD(datetime, INTERVAL n unit)
+            check_date_argument(&self.display_name, &args[0])?;
+            if !is_interval(&args[1]) {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Function {} must have an interval as the second argument, but got {}",
+                    self.display_name, args[1]
+                )));
+            }
+            return Ok(args[0].clone());
+        }
+
+        check_unit_argument(&self.display_name, &args[0])?;
+        if !is_integer(&args[1]) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} must have an integer as the number of units, but got {}",
+                self.display_name, args[1]
+            )));
+        }
+        check_date_argument(&self.display_name, &args[2])?;
+        Ok(args[2].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns.len() == 2 {
+            let func =
+                IntervalFunctionFactory::try_get_arithmetic_func(columns).ok_or_else(|| {
+                    ErrorCode::IllegalDataType(format!(
+                        "Function {} must have a date or datetime and an interval as arguments",
+                        self.display_name
+                    ))
+                })?;
+            return func(&self.op, &columns[0], &columns[1]);
+        }
+
+        let unit = DateUnit::try_from_column(&self.display_name, &columns[0])?;
+        // (n, datetime)
+        let args = &columns[1..];
+        let func = match (unit.months(), unit.seconds()) {
+            (Some(months), _) => {
+                MonthsArithmeticFunction::try_create(&self.display_name, self.op.clone(), months)?
+            }
+            (None, Some(seconds)) => SecondsArithmeticFunction::try_create(
+                &self.display_name,
+                self.op.clone(),
+                seconds as i64,
+            )?,
+            (None, None) => unreachable!(),
+        };
+        func.eval(args, input_rows)
+    }
+}
+
+impl fmt::Display for DateAddFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
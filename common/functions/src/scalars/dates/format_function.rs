@@ -0,0 +1,189 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_common::get_datetimes;
+use super::date_common::get_timezone;
+use super::date_format::parse_datetime;
+use super::date_format::DateFormat;
+use super::date_format::DateFormatStyle;
+use crate::scalars::function_factory::FactoryCreator;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// formatDateTime(datetime, format[, timezone]) formats the date or datetime with the strftime specifiers,
+/// DATE_FORMAT(datetime, format) is the same with the MySQL specifiers.
+#[derive(Clone)]
+pub struct FormatDateTimeFunction {
+    display_name: String,
+    style: DateFormatStyle,
+}
+
+impl FormatDateTimeFunction {
+    pub fn try_create(display_name: &str, style: DateFormatStyle) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatDateTimeFunction {
+            display_name: display_name.to_string(),
+            style,
+        }))
+    }
+
+    pub fn desc(style: DateFormatStyle) -> FunctionDescription {
+        let creator: FactoryCreator = Box::new(move |display_name| -> Result<Box<dyn Function>> {
+            Self::try_create(display_name, style)
+        });
+
+        FunctionDescription::creator(creator)
+            .features(FunctionFeatures::default().deterministic().timezone_aware())
+    }
+}
+
+impl Function for FormatDateTimeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !is_date_or_date_time(&args[0]) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} must have a date or datetime type as the first argument, but got {}",
+                self.display_name, args[0]
+            )));
+        }
+        if args[1] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} must have a string as the format argument, but got {}",
+                self.display_name, args[1]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let format = get_format(&self.display_name, &columns[1], self.style)?;
+        let tz = get_timezone(columns[0].data_type(), columns.get(2))?;
+        let datetimes = get_datetimes(&columns[0], &tz)?;
+        let array = DFStringArray::new_from_opt_iter(
+            datetimes
+                .iter()
+                .map(|v| v.as_ref().map(|v| format.format(v))),
+        );
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for FormatDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// parseDateTime(string, format[, timezone]) parses the string with the strftime specifiers,
+/// STR_TO_DATE(string, format) is the same with the MySQL specifiers.
+/// Returns NULL if the string doesn't match the format.
+#[derive(Clone)]
+pub struct ParseDateTimeFunction {
+    display_name: String,
+    style: DateFormatStyle,
+}
+
+impl ParseDateTimeFunction {
+    pub fn try_create(display_name: &str, style: DateFormatStyle) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseDateTimeFunction {
+            display_name: display_name.to_string(),
+            style,
+        }))
+    }
+
+    pub fn desc(style: DateFormatStyle) -> FunctionDescription {
+        let creator: FactoryCreator = Box::new(move |display_name| -> Result<Box<dyn Function>> {
+            Self::try_create(display_name, style)
+        });
+
+        FunctionDescription::creator(creator)
+            .features(FunctionFeatures::default().deterministic().timezone_aware())
+    }
+}
+
+impl Function for ParseDateTimeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::String || args[1] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} must have the string and format arguments, but got {} and {}",
+                self.display_name, args[0], args[1]
+            )));
+        }
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let format = get_format(&self.display_name, &columns[1], self.style)?;
+        let items = format.parse_items()?;
+        let tz = get_timezone(columns[0].data_type(), columns.get(2))?;
+        let array = columns[0].column().to_array()?;
+        let array = DFUInt32Array::new_from_opt_iter(array.string()?.into_iter().map(|v| {
+            let value = std::str::from_utf8(v?).ok()?;
+            let datetime = parse_datetime(value, &items, &tz)?;
+            u32::try_from(datetime.timestamp()).ok()
+        }));
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for ParseDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn get_format(
+    function: &str,
+    column: &DataColumnWithField,
+    style: DateFormatStyle,
+) -> Result<DateFormat> {
+    match column.column() {
+        DataColumn::Constant(DataValue::String(Some(format)), _) => {
+            DateFormat::try_create(&String::from_utf8_lossy(format), style)
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The format argument of function {} must be a constant string",
+            function
+        ))),
+    }
+}
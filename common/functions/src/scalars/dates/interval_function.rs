@@ -191,9 +191,10 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
@@ -236,6 +237,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -246,12 +249,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -331,6 +336,29 @@ impl IntervalFunctionFactory {
         Ok(res.into())
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        let precision = Self::datetime64_precision(datetime.data_type());
+        let res = Self::interval_operation(
+            interval.column().to_array()?.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |ms: &i64, ticks: &i64| {
+                let delta = rescale_datetime64(*ms, 3, precision);
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + delta,
+                    DataValueArithmeticOperator::Minus => *ticks - delta,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     fn interval_month_plus_minus_date16(
         op: &DataValueArithmeticOperator,
         a: &DataColumnWithField,
@@ -358,6 +386,14 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        Self::month_plus_minus_datetime64(op, a, b, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -404,6 +440,7 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => Self::month_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -437,6 +474,41 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    // datetime64 functions, the integer months are casted to i64 first
+    fn month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+        let precision = Self::datetime64_precision(datetime64.data_type());
+        let months = interval_months
+            .column()
+            .cast_with_type(&DataType::Int64)?
+            .to_array()?;
+
+        let res = Self::interval_operation(
+            months.i64()?,
+            datetime64.column().to_array()?.i64()?,
+            |months: &i64, ticks: &i64| {
+                let (seconds, nanos) = datetime64_to_parts(*ticks, precision);
+                let dt = Self::seconds_to_datetime(seconds)?;
+                let new_dt = match op {
+                    DataValueArithmeticOperator::Plus => {
+                        Self::datetime_plus_signed_months(&dt, *months * mul)?
+                    }
+                    DataValueArithmeticOperator::Minus => {
+                        Self::datetime_plus_signed_months(&dt, -*months * mul)?
+                    }
+                    _ => unreachable!(),
+                };
+                Ok(datetime64_from_parts(new_dt.timestamp(), nanos, precision))
+            },
+        )?;
+        Ok(res.into())
+    }
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -483,6 +555,7 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => Self::time_secs_plus_minus_datetime64,
             _ => unreachable!(),
         }
     }
@@ -516,6 +589,36 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    // datetime64 functions, the integer seconds are casted to i64 first
+    fn time_secs_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+        mul: i64,
+    ) -> Result<DataColumn> {
+        let (interval_seconds, datetime64) = Self::validate_input(op, a, b)?;
+        let per_second =
+            datetime64_ticks_per_second(Self::datetime64_precision(datetime64.data_type()));
+        let seconds = interval_seconds
+            .column()
+            .cast_with_type(&DataType::Int64)?
+            .to_array()?;
+
+        let res = Self::interval_operation(
+            seconds.i64()?,
+            datetime64.column().to_array()?.i64()?,
+            |secs: &i64, ticks: &i64| {
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + *secs * mul * per_second,
+                    DataValueArithmeticOperator::Minus => *ticks - *secs * mul * per_second,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -542,6 +645,13 @@ impl IntervalFunctionFactory {
         }
     }
 
+    fn datetime64_precision(data_type: &DataType) -> u32 {
+        match data_type {
+            DataType::DateTime64(precision, _) => *precision,
+            _ => 0,
+        }
+    }
+
     // A private helper function to add/subtract month to/from days
     fn days_plus_signed_months(days: i64, months: i64) -> Result<u32> {
         let naive = NaiveDateTime::from_timestamp(0, 0).checked_add_signed(Duration::days(days));
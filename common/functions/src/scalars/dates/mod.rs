@@ -13,6 +13,10 @@
 // limitations under the License.
 
 mod date;
+mod date_common;
+mod date_format;
+mod date_unit_function;
+mod format_function;
 mod interval_function;
 mod now;
 mod number_function;
@@ -21,6 +25,12 @@ mod simple_date;
 mod week_date;
 
 pub use date::DateFunction;
+pub use date_format::DateFormatStyle;
+pub use date_unit_function::DateAddFunction;
+pub use date_unit_function::DateDiffFunction;
+pub use date_unit_function::DateTruncFunction;
+pub use format_function::FormatDateTimeFunction;
+pub use format_function::ParseDateTimeFunction;
 pub use interval_function::IntervalArithmeticFunction;
 pub use interval_function::IntervalFunctionFactory;
 pub use interval_function::MonthsArithmeticFunction;
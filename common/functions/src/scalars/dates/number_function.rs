@@ -17,13 +17,17 @@ use std::marker::PhantomData;
 
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
-use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::Timelike;
-use common_datavalues::chrono::Utc;
+use common_datavalues::chrono::Weekday;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::date_common::get_day;
+use super::date_common::get_day_of_date;
+use super::date_common::get_timezone;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -39,8 +43,8 @@ pub trait NumberResultFunction<R> {
     const IS_DETERMINISTIC: bool;
 
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -52,11 +56,11 @@ impl NumberResultFunction<u32> for ToYYYYMM {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 100 + value.month()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -70,11 +74,11 @@ impl NumberResultFunction<u32> for ToYYYYMMDD {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 10000 + value.month() * 100 + value.day()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -89,7 +93,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
         Ok(DataType::UInt64)
     }
 
-    fn to_number(value: DateTime<Utc>) -> u64 {
+    fn to_number(value: DateTime<Tz>) -> u64 {
         value.year() as u64 * 10000000000
             + value.month() as u64 * 100000000
             + value.day() as u64 * 1000000
@@ -98,7 +102,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
             + value.second() as u64
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt64(Some(Self::to_number(value)))
     }
 }
@@ -112,12 +116,12 @@ impl NumberResultFunction<u16> for ToStartOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
-        get_day(end) as u16
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        let date = NaiveDate::from_ymd(value.year(), 1, 1);
+        get_day_of_date(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -131,17 +135,12 @@ impl NumberResultFunction<u16> for ToStartOfISOYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let week_day = value.weekday().num_days_from_monday();
-        let iso_week = value.iso_week();
-        let iso_week_num = iso_week.week();
-        let sub_days = (iso_week_num - 1) * 7 + week_day;
-        let result = value.timestamp_millis() - sub_days as i64 * 24 * 3600 * 1000;
-        let end: DateTime<Utc> = Utc.timestamp_millis(result);
-        get_day(end) as u16
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        let date = NaiveDate::from_isoywd(value.iso_week().year(), 1, Weekday::Mon);
+        get_day_of_date(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -155,13 +154,13 @@ impl NumberResultFunction<u16> for ToStartOfQuarter {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let new_month = value.month0() / 3 * 3 + 1;
-        let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+        let date = NaiveDate::from_ymd(value.year(), new_month, 1);
+        get_day_of_date(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -175,12 +174,12 @@ impl NumberResultFunction<u16> for ToStartOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        let date = NaiveDate::from_ymd(value.year(), value.month(), 1);
+        get_day_of_date(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -194,11 +193,11 @@ impl NumberResultFunction<u8> for ToMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.month() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -212,11 +211,11 @@ impl NumberResultFunction<u16> for ToDayOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         value.ordinal() as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value)))
     }
 }
@@ -230,11 +229,11 @@ impl NumberResultFunction<u8> for ToDayOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.day() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -248,11 +247,11 @@ impl NumberResultFunction<u8> for ToDayOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.weekday().number_from_monday() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -266,11 +265,11 @@ impl NumberResultFunction<u8> for ToHour {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.hour() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -284,11 +283,11 @@ impl NumberResultFunction<u8> for ToMinute {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.minute() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -302,11 +301,11 @@ impl NumberResultFunction<u8> for ToSecond {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.second() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 }
@@ -320,12 +319,12 @@ impl NumberResultFunction<u16> for ToMonday {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let weekday = value.weekday();
         (get_day(value) - weekday.num_days_from_monday()) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value)))
     }
 }
@@ -351,6 +350,8 @@ where
             features = features.deterministic();
         }
 
+        features = features.timezone_aware();
+
         FunctionDescription::creator(Box::new(Self::try_create)).features(features)
     }
 }
@@ -366,7 +367,11 @@ where
     }
 
     fn num_arguments(&self) -> usize {
-        1
+        0
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
     }
 
     fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
@@ -379,10 +384,11 @@ where
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
+        let tz = get_timezone(data_type, columns.get(1))?;
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = v.as_u64()?.to_date(&Tz::UTC).and_hms(0, 0, 0);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -390,7 +396,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = v.to_date(&Tz::UTC).and_hms(0, 0, 0);
                             T::to_number(date_time)
                         }
                         );
@@ -399,7 +405,7 @@ where
             }
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
+                    let date_time = v.as_i64()?.to_date(&Tz::UTC).and_hms(0, 0, 0);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -407,7 +413,7 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = v.to_date(&Tz::UTC).and_hms(0, 0, 0);
                             T::to_number(date_time)
                         }
                         );
@@ -416,7 +422,7 @@ where
             }
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = v.as_u64()?.to_date_time(&tz);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -424,7 +430,24 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = v.to_date_time(&tz);
+                            T::to_number(date_time)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            }
+            DataType::DateTime64(precision, _) => {
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = v.as_i64()?.to_date_time64(*precision, &tz);
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = v.to_date_time64(*precision, &tz);
                             T::to_number(date_time)
                         }
                         );
@@ -432,7 +455,7 @@ where
                 }
             }
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -446,12 +469,6 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
-    duration.num_days() as u32
-}
-
 pub type ToYYYYMMFunction = NumberFunction<ToYYYYMM, u32>;
 pub type ToYYYYMMDDFunction = NumberFunction<ToYYYYMMDD, u32>;
 pub type ToYYYYMMDDhhmmssFunction = NumberFunction<ToYYYYMMDDhhmmss, u64>;
@@ -14,10 +14,15 @@
 
 use std::fmt;
 
+use common_datavalues::chrono::LocalResult;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::date_common::get_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -36,12 +41,23 @@ impl RoundFunction {
         Ok(Box::new(s))
     }
 
-    // TODO: (sundy-li)
-    // Consider about the timezones/offsets
-    // Currently: assuming timezone offset is a multiple of round.
+    // Rounds the seconds since UNIX epoch on the local time of the timezone,
+    // e.g. toStartOfDay is the local midnight rather than the UTC midnight.
     #[inline]
-    fn execute(&self, time: u32) -> u32 {
-        time / self.round * self.round
+    fn execute(&self, time: i64, tz: &Tz) -> i64 {
+        let round = self.round as i64;
+        if *tz == Tz::UTC {
+            return time.div_euclid(round) * round;
+        }
+
+        let local = tz.timestamp(time, 0).naive_local().timestamp();
+        let rounded = NaiveDateTime::from_timestamp(local.div_euclid(round) * round, 0);
+        match tz.from_local_datetime(&rounded) {
+            LocalResult::Single(t) => t.timestamp(),
+            LocalResult::Ambiguous(t, _) => t.timestamp(),
+            // The local time is skipped by the daylight saving, keep the same offset as the input.
+            LocalResult::None => time - (local - rounded.timestamp()),
+        }
     }
 }
 
@@ -51,8 +67,9 @@ impl Function for RoundFunction {
     }
 
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
-        match args[0] {
-            DataType::DateTime32(_) => Ok(DataType::DateTime32(None)),
+        match &args[0] {
+            DataType::DateTime32(tz) => Ok(DataType::DateTime32(tz.clone())),
+            DataType::DateTime64(precision, tz) => Ok(DataType::DateTime64(*precision, tz.clone())),
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Function {} must have a DateTime type as argument, but got {}",
                 self.display_name, args[0],
@@ -65,27 +82,56 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
-        match columns[0].column() {
-            DataColumn::Array(array) => {
-                let array = array.u32()?;
-                let arr = array.apply(|x| self.execute(x));
-                Ok(DataColumn::Array(arr.into_series()))
-            }
-            DataColumn::Constant(v, rows) => {
-                if v.is_null() {
-                    return Ok(DataColumn::Constant(DataValue::UInt32(None), *rows));
+        let tz = get_timezone(columns[0].data_type(), columns.get(1))?;
+        match columns[0].data_type() {
+            DataType::DateTime64(precision, _) => {
+                let per_second = datetime64_ticks_per_second(*precision);
+                let execute =
+                    |ticks: i64| self.execute(ticks.div_euclid(per_second), &tz) * per_second;
+                match columns[0].column() {
+                    DataColumn::Array(array) => {
+                        let array = array.i64()?;
+                        let arr = array.apply(execute);
+                        Ok(DataColumn::Array(arr.into_series()))
+                    }
+                    DataColumn::Constant(v, rows) => {
+                        if v.is_null() {
+                            return Ok(DataColumn::Constant(DataValue::Int64(None), *rows));
+                        }
+                        let value = v.as_i64()?;
+                        Ok(DataColumn::Constant(
+                            DataValue::Int64(Some(execute(value))),
+                            *rows,
+                        ))
+                    }
                 }
-                let value = v.as_u64()?;
-                Ok(DataColumn::Constant(
-                    DataValue::UInt32(Some(self.execute(value as u32))),
-                    *rows,
-                ))
             }
+            _ => match columns[0].column() {
+                DataColumn::Array(array) => {
+                    let array = array.u32()?;
+                    let arr = array.apply(|x| self.execute(x as i64, &tz) as u32);
+                    Ok(DataColumn::Array(arr.into_series()))
+                }
+                DataColumn::Constant(v, rows) => {
+                    if v.is_null() {
+                        return Ok(DataColumn::Constant(DataValue::UInt32(None), *rows));
+                    }
+                    let value = v.as_u64()?;
+                    Ok(DataColumn::Constant(
+                        DataValue::UInt32(Some(self.execute(value as i64, &tz) as u32)),
+                        *rows,
+                    ))
+                }
+            },
         }
     }
 
     fn num_arguments(&self) -> usize {
-        1
+        0
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
     }
 }
 
@@ -19,12 +19,13 @@ use std::ops::Sub;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::Duration;
-use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::date_common::get_day;
+use super::date_common::get_timezone;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -40,8 +41,8 @@ pub trait WeekResultFunction<R> {
     const IS_DETERMINISTIC: bool;
 
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>, mode: Option<u64>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>, mode: Option<u64>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>, mode: Option<u64>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>, mode: Option<u64>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -53,7 +54,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u32 {
+    fn to_number(value: DateTime<Tz>, mode: Option<u64>) -> u32 {
         let week_mode = mode.unwrap_or(0);
         let mut weekday = value.weekday().number_from_sunday();
         if week_mode & 1 == 1 {
@@ -65,7 +66,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
         get_day(result)
     }
 
-    fn to_constant_value(value: DateTime<Utc>, mode: Option<u64>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>, mode: Option<u64>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value, mode) as u16))
     }
 }
@@ -91,6 +92,8 @@ where
             features = features.deterministic();
         }
 
+        features = features.timezone_aware();
+
         FunctionDescription::creator(Box::new(Self::try_create)).features(features)
     }
 }
@@ -110,7 +113,7 @@ where
     }
 
     fn variadic_arguments(&self) -> Option<(usize, usize)> {
-        Some((1, 2))
+        Some((1, 3))
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -120,8 +123,16 @@ where
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
         let mut mode: Option<u64> = None;
-        if columns.len() == 2 && !columns[1].column().is_empty() {
-            let week_mode = columns[1].column().to_values()?[0].clone().as_u64()?;
+        // The optional arguments are the week mode and the timezone: (date[, mode][, timezone])
+        let tz_column = columns[1..]
+            .iter()
+            .find(|c| c.data_type() == &DataType::String);
+        let tz = get_timezone(data_type, tz_column)?;
+        let mode_column = columns[1..]
+            .iter()
+            .find(|c| c.data_type() != &DataType::String);
+        if let Some(mode_column) = mode_column.filter(|c| !c.column().is_empty()) {
+            let week_mode = mode_column.column().to_values()?[0].clone().as_u64()?;
             if !(0..=9).contains(&week_mode) {
                 return Err(ErrorCode::BadArguments(format!(
                     "The parameter:{} range is abnormal, it should be between 0-9",
@@ -133,7 +144,7 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = v.as_u64()?.to_date(&Tz::UTC).and_hms(0, 0, 0);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -141,7 +152,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = v.to_date(&Tz::UTC).and_hms(0, 0, 0);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -150,7 +161,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
+                    let date_time = v.as_i64()?.to_date(&Tz::UTC).and_hms(0, 0, 0);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -158,7 +169,7 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = v.to_date(&Tz::UTC).and_hms(0, 0, 0);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -167,7 +178,7 @@ where
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?, 0_u32);
+                    let date_time = v.as_i64()?.to_date_time(&tz);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -175,7 +186,24 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = v.to_date_time(&tz);
+                            T::to_number(date_time, mode)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, _) => {
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = v.as_i64()?.to_date_time64(*precision, &tz);
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = v.to_date_time64(*precision, &tz);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -183,7 +211,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -197,10 +225,4 @@ impl<T, R> fmt::Display for WeekFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
-    duration.num_days() as u32
-}
-
 pub type ToStartOfWeekFunction = WeekFunction<ToStartOfWeek, u32>;
@@ -18,22 +18,31 @@ use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::NaiveDate;
-use common_datavalues::chrono::NaiveDateTime;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::cast_from_datetime64;
+use common_datavalues::prelude::cast_from_decimal;
+use common_datavalues::prelude::cast_to_datetime64;
+use common_datavalues::prelude::cast_to_decimal;
+use common_datavalues::prelude::parse_timezone;
 use common_datavalues::prelude::ArrayApply;
 use common_datavalues::prelude::DFInt32Array;
 use common_datavalues::prelude::DFStringArray;
 use common_datavalues::prelude::DFUInt16Array;
 use common_datavalues::prelude::DFUInt32Array;
+use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::prelude::DateConverter;
 use common_datavalues::series::IntoSeries;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::scalars::cast_from_variant;
+use crate::scalars::cast_to_variant;
 use crate::scalars::Function;
 use crate::with_match_primitive_type;
 
@@ -51,6 +60,20 @@ impl CastFunction {
             cast_type,
         }))
     }
+
+    /// Casts the column from the data type of its field. Unlike `DataColumn::cast_with_type`,
+    /// which casts the physical values, the values of Decimal and DateTime64 are converted.
+    pub fn cast_column(
+        column: &DataColumnWithField,
+        cast_type: &DataType,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        let function = Self {
+            _display_name: "cast".to_string(),
+            cast_type: cast_type.clone(),
+        };
+        function.eval(&[column.clone()], input_rows)
+    }
 }
 
 impl Function for CastFunction {
@@ -82,6 +105,24 @@ impl Function for CastFunction {
         ));
 
         let array = match (columns[0].data_type(), &self.cast_type) {
+            // Variant to others and others to Variant
+            (DataType::Variant, _) => cast_from_variant(&series, &self.cast_type),
+            (_, DataType::Variant) => cast_to_variant(&series, columns[0].data_type()),
+
+            // Decimal to others and others to Decimal
+            (DataType::Decimal(_, scale), _) => cast_from_decimal(&series, *scale, &self.cast_type),
+            (_, DataType::Decimal(precision, scale)) => {
+                cast_to_decimal(&series, columns[0].data_type(), *precision, *scale)
+            }
+
+            // DateTime64 to others and others to DateTime64
+            (DataType::DateTime64(precision, tz), _) => {
+                cast_from_datetime64(&series, *precision, tz, &self.cast_type)
+            }
+            (_, DataType::DateTime64(precision, tz)) => {
+                cast_to_datetime64(&series, columns[0].data_type(), *precision, tz)
+            }
+
             // Date/DateTime to others
             (DataType::Date16, _) => with_match_primitive_type!(&self.cast_type, |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -107,14 +148,19 @@ impl Function for CastFunction {
                }
             }),
 
-            (DataType::DateTime32(_), _) => with_match_primitive_type!(&self.cast_type, |$T| {
+            (DataType::DateTime32(tz), _) => with_match_primitive_type!(&self.cast_type, |$T| {
                 series.cast_with_type(&self.cast_type)
             }, {
                let arr = series.u32()?;
                match &self.cast_type {
                 Date16 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as u16).into_series()),
                 Date32 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as i32).into_series()),
-                String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64, 0_u32), TIME_FMT))) ).into_series()),
+                // the timezone is changed, e.g. toTimeZone
+                DateTime32(_) => Ok(series.clone()),
+                String => {
+                    let tz = parse_timezone(tz)?;
+                    Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( x.to_date_time(&tz), TIME_FMT))) ).into_series())
+                },
                 _ =>  Err(error)
                }
             }),
@@ -148,14 +194,15 @@ impl Function for CastFunction {
                }
             }),
 
-            (_, DataType::DateTime32(_)) => {
+            (_, DataType::DateTime32(tz)) => {
                 with_match_primitive_type!(columns[0].data_type(), |$T| {
                     series.cast_with_type(&self.cast_type)
                 }, {
                    match columns[0].data_type() {
                     String => {
+                        let tz = parse_timezone(tz)?;
                         let it = series.string()?.into_iter().map(|v| {
-                            v.and_then(string_to_datetime).map(|t| t.timestamp() as u32)
+                            v.and_then(|v| string_to_datetime(v, &tz)).map(|t| t.timestamp() as u32)
                         });
                         Ok(DFUInt32Array::from_iter(it).into_series())
                     },
@@ -183,16 +230,15 @@ impl fmt::Display for CastFunction {
 }
 
 #[inline]
-fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
+fn datetime_to_string<T: TimeZone>(date: DateTime<T>, fmt: &str) -> String
+where T::Offset: fmt::Display {
     date.format(fmt).to_string()
 }
 
-// currently use UTC by default
-// TODO support timezone
 #[inline]
-fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
+fn string_to_datetime(date_str: impl AsRef<[u8]>, tz: &Tz) -> Option<DateTime<Tz>> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
+    s.and_then(|c| tz.datetime_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
 }
 
 #[inline]
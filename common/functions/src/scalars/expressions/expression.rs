@@ -52,6 +52,7 @@ impl ToCastFunction {
         factory.register("toDate16", Self::cast_function_creator(DataType::Date16));
         factory.register("toDate32", Self::cast_function_creator(DataType::Date32));
         factory.register("toString", Self::cast_function_creator(DataType::String));
+        factory.register("toVariant", Self::cast_function_creator(DataType::Variant));
 
         // aliases
         factory.register("toDate", Self::cast_function_creator(DataType::Date16));
@@ -29,6 +29,7 @@ use crate::scalars::LogicFunction;
 use crate::scalars::MathsFunction;
 use crate::scalars::NullableFunction;
 use crate::scalars::OtherFunction;
+use crate::scalars::SemiStructuredFunction;
 use crate::scalars::StringFunction;
 use crate::scalars::ToCastFunction;
 use crate::scalars::TupleClassFunction;
@@ -41,6 +42,9 @@ pub struct FunctionFeatures {
     pub is_deterministic: bool,
     pub negative_function_name: Option<String>,
     pub is_bool_func: bool,
+    /// The function takes an optional timezone argument after the datetime argument,
+    /// the session timezone is passed to it if the datetime has no timezone.
+    pub is_timezone_aware: bool,
 }
 
 impl FunctionFeatures {
@@ -49,6 +53,7 @@ impl FunctionFeatures {
             is_deterministic: false,
             negative_function_name: None,
             is_bool_func: false,
+            is_timezone_aware: false,
         }
     }
 
@@ -66,6 +71,11 @@ impl FunctionFeatures {
         self.is_bool_func = true;
         self
     }
+
+    pub fn timezone_aware(mut self) -> FunctionFeatures {
+        self.is_timezone_aware = true;
+        self
+    }
 }
 
 pub struct FunctionDescription {
@@ -109,6 +119,7 @@ lazy_static! {
         OtherFunction::register(&mut function_factory);
         MathsFunction::register(&mut function_factory);
         TupleClassFunction::register(&mut function_factory);
+        SemiStructuredFunction::register(&mut function_factory);
 
         Arc::new(function_factory)
     };
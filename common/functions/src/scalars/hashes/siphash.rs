@@ -68,6 +68,7 @@ impl Function for SipHashFunction {
             | DataType::Date16
             | DataType::Date32
             | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _)
             | DataType::String => Ok(DataType::UInt64),
             _ => Result::Err(ErrorCode::BadArguments(format!(
                 "Function Error: {} does not support {} type parameters",
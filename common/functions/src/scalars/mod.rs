@@ -27,6 +27,7 @@ mod logics;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
@@ -49,6 +50,7 @@ pub use logics::*;
 pub use maths::*;
 pub use nullables::*;
 pub use others::*;
+pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
 pub use udfs::*;
@@ -0,0 +1,79 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::semi_structureds::semi_structured_common::check_path_argument;
+use crate::scalars::semi_structureds::semi_structured_common::check_variant_argument;
+use crate::scalars::semi_structureds::semi_structured_common::eval_path;
+use crate::scalars::semi_structureds::semi_structured_common::json_to_string;
+use crate::scalars::Function;
+
+/// json_extract_path_text(json, path) returns the inner value located by the path as text,
+/// JSON strings are returned without quotes. Returns NULL if nothing is located.
+#[derive(Clone)]
+pub struct JsonExtractPathTextFunction {
+    display_name: String,
+}
+
+impl JsonExtractPathTextFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonExtractPathTextFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for JsonExtractPathTextFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&args[0])?;
+        check_path_argument(&args[1])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        eval_path(columns, input_rows, |value, builder| match value {
+            Some(value) => builder.append_value(json_to_string(value)),
+            None => builder.append_null(),
+        })
+    }
+}
+
+impl fmt::Display for JsonExtractPathTextFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::semi_structureds::semi_structured_common::check_variant_argument;
+use crate::scalars::semi_structureds::semi_structured_common::json_typeof;
+use crate::scalars::strings::cast_arguments;
+use crate::scalars::Function;
+
+/// json_typeof(json) returns the type of the JSON value: null, boolean, number, string,
+/// array or object. Returns NULL for SQL NULL or the invalid JSON text.
+#[derive(Clone)]
+pub struct JsonTypeofFunction {
+    display_name: String,
+}
+
+impl JsonTypeofFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonTypeofFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for JsonTypeofFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&args[0])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String])?;
+        let values = series[0].string()?;
+
+        let mut builder = StringArrayBuilder::with_capacity(values.len());
+        for value in values {
+            match value.and_then(|v| serde_json::from_slice::<JsonValue>(v).ok()) {
+                Some(value) => builder.append_value(json_typeof(&value)),
+                None => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for JsonTypeofFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::semi_structureds::semi_structured_common::cast_to_variant;
+use crate::scalars::semi_structureds::semi_structured_common::check_variant_argument;
+use crate::scalars::Function;
+
+/// parse_json(str) parses the JSON text into a variant, fails if the text is invalid.
+#[derive(Clone)]
+pub struct ParseJsonFunction {
+    display_name: String,
+}
+
+impl ParseJsonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseJsonFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ParseJsonFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&args[0])?;
+        Ok(DataType::Variant)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns[0].data_type() == &DataType::Variant {
+            return Ok(columns[0].column().clone());
+        }
+
+        let series = columns[0].column().to_minimal_array()?;
+        let column: DataColumn = cast_to_variant(&series, columns[0].data_type())?.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ParseJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::GetFunction;
+use crate::scalars::JsonExtractPathTextFunction;
+use crate::scalars::JsonTypeofFunction;
+use crate::scalars::ParseJsonFunction;
+
+#[derive(Clone)]
+pub struct SemiStructuredFunction;
+
+impl SemiStructuredFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("parse_json", ParseJsonFunction::desc());
+        factory.register("get", GetFunction::desc());
+        factory.register(
+            "json_extract_path_text",
+            JsonExtractPathTextFunction::desc(),
+        );
+        factory.register("json_typeof", JsonTypeofFunction::desc());
+    }
+}
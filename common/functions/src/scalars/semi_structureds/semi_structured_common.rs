@@ -0,0 +1,295 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::scalars::strings::cast_arguments;
+use crate::scalars::strings::i64_at;
+use crate::scalars::strings::string_at;
+
+/// An element of the path to access the inner value of a variant.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PathElement {
+    Key(String),
+    Index(usize),
+}
+
+pub(crate) fn check_variant_argument(data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::Variant | DataType::String | DataType::Null => Ok(()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected variant or string type, but got {}",
+            other
+        ))),
+    }
+}
+
+/// Checks that the argument is a path string or an array index.
+pub(crate) fn check_path_argument(data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::String | DataType::Null => Ok(()),
+        dt if is_integer(dt) => Ok(()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected string or integer type as the path, but got {}",
+            other
+        ))),
+    }
+}
+
+/// Parses the path like `a.b[0]` or `["a b"][0]` into path elements.
+pub(crate) fn parse_path(path: &[u8]) -> Result<Vec<PathElement>> {
+    let invalid = || {
+        ErrorCode::BadArguments(format!(
+            "Invalid variant path: {}",
+            String::from_utf8_lossy(path)
+        ))
+    };
+
+    let mut elements = vec![];
+    let mut pos = 0;
+    while pos < path.len() {
+        if path[pos] == b'[' {
+            pos += 1;
+            match path.get(pos) {
+                Some(quote @ (b'"' | b'\'')) => {
+                    let len = path[pos + 1..]
+                        .iter()
+                        .position(|c| c == quote)
+                        .ok_or_else(invalid)?;
+                    let key = String::from_utf8_lossy(&path[pos + 1..pos + 1 + len]);
+                    elements.push(PathElement::Key(key.into_owned()));
+                    pos += len + 2;
+                }
+                _ => {
+                    let len = path[pos..]
+                        .iter()
+                        .position(|c| *c == b']')
+                        .ok_or_else(invalid)?;
+                    let index = std::str::from_utf8(&path[pos..pos + len])
+                        .ok()
+                        .and_then(|index| index.trim().parse::<usize>().ok())
+                        .ok_or_else(invalid)?;
+                    elements.push(PathElement::Index(index));
+                    pos += len;
+                }
+            }
+            if path.get(pos) != Some(&b']') {
+                return Err(invalid());
+            }
+            pos += 1;
+            continue;
+        }
+
+        match path[pos] == b'.' {
+            true if elements.is_empty() => return Err(invalid()),
+            true => pos += 1,
+            false if !elements.is_empty() => return Err(invalid()),
+            false => {}
+        }
+        let len = path[pos..]
+            .iter()
+            .position(|c| *c == b'.' || *c == b'[')
+            .unwrap_or(path.len() - pos);
+        if len == 0 {
+            return Err(invalid());
+        }
+        let key = String::from_utf8_lossy(&path[pos..pos + len]);
+        elements.push(PathElement::Key(key.into_owned()));
+        pos += len;
+    }
+    Ok(elements)
+}
+
+pub(crate) fn get_by_path<'a>(value: &'a JsonValue, path: &[PathElement]) -> Option<&'a JsonValue> {
+    path.iter().try_fold(value, |value, element| match element {
+        PathElement::Key(key) => value.get(key),
+        PathElement::Index(index) => value.get(index),
+    })
+}
+
+/// Evaluates `f` on the inner value located by the path argument for each row of the
+/// variant (or JSON string) argument. The inner value is None if any argument is NULL,
+/// the JSON text is invalid or nothing is located. An integer path is the array index.
+pub(crate) fn eval_path<F>(
+    columns: &DataColumnsWithField,
+    input_rows: usize,
+    mut f: F,
+) -> Result<DataColumn>
+where
+    F: FnMut(Option<&JsonValue>, &mut StringArrayBuilder),
+{
+    let series = match is_integer(columns[1].data_type()) {
+        true => cast_arguments(columns, &[DataType::String, DataType::Int64])?,
+        false => cast_arguments(columns, &[DataType::String, DataType::String])?,
+    };
+    let values = series[0].string()?;
+
+    let mut path: Vec<PathElement> = vec![];
+    let mut last_path: Option<&[u8]> = None;
+    let mut builder = StringArrayBuilder::with_capacity(values.len());
+    for row in 0..values.len() {
+        let has_path = match series[1].data_type() {
+            DataType::Int64 => match i64_at(series[1].i64()?, row) {
+                Some(index) if index >= 0 => {
+                    path = vec![PathElement::Index(index as usize)];
+                    true
+                }
+                _ => false,
+            },
+            _ => match string_at(series[1].string()?, row) {
+                Some(text) => {
+                    // The path is usually a constant, so it's parsed only when it changes.
+                    if last_path != Some(text) {
+                        path = parse_path(text)?;
+                        last_path = Some(text);
+                    }
+                    true
+                }
+                None => false,
+            },
+        };
+
+        let value = match has_path {
+            true => {
+                string_at(values, row).and_then(|v| serde_json::from_slice::<JsonValue>(v).ok())
+            }
+            false => None,
+        };
+        f(
+            value.as_ref().and_then(|v| get_by_path(v, &path)),
+            &mut builder,
+        );
+    }
+
+    let column: DataColumn = builder.finish().into();
+    Ok(column.resize_constant(input_rows))
+}
+
+/// Returns the string itself for JSON strings, otherwise the JSON text.
+pub(crate) fn json_to_string(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn json_typeof(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+/// Casts the values to the variant type, strings are parsed as JSON text.
+pub fn cast_to_variant(series: &Series, from_type: &DataType) -> Result<Series> {
+    match from_type {
+        DataType::Null => Ok(DFStringArray::full_null(series.len()).into_series()),
+        DataType::String => {
+            let mut builder = StringArrayBuilder::with_capacity(series.len());
+            for value in series.string()? {
+                match value {
+                    Some(value) => {
+                        let json = serde_json::from_slice::<JsonValue>(value).map_err(|e| {
+                            ErrorCode::BadDataValueType(format!(
+                                "Unable to parse {} as JSON text: {}",
+                                String::from_utf8_lossy(value),
+                                e
+                            ))
+                        })?;
+                        builder.append_value(serde_json::to_vec(&json)?);
+                    }
+                    None => builder.append_null(),
+                }
+            }
+            Ok(builder.finish().into_series())
+        }
+        DataType::Boolean => {
+            let it = series.bool()?.into_iter().map(|v| match v {
+                Some(true) => Some("true"),
+                Some(false) => Some("false"),
+                None => None,
+            });
+            Ok(DFStringArray::new_from_opt_iter(it).into_series())
+        }
+        dt if is_numeric(dt) => series.cast_with_type(&DataType::String),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {} into Variant",
+            other
+        ))),
+    }
+}
+
+/// Casts the variant values to the scalar type, a value which can't be represented
+/// by the type is casted to NULL.
+pub fn cast_from_variant(series: &Series, to_type: &DataType) -> Result<Series> {
+    let values = series
+        .string()?
+        .into_iter()
+        .map(|v| v.and_then(|v| serde_json::from_slice::<JsonValue>(v).ok()))
+        .collect::<Vec<_>>();
+
+    match to_type {
+        DataType::String => {
+            let it = values.iter().map(|v| v.as_ref().map(json_to_string));
+            Ok(DFStringArray::new_from_opt_iter(it).into_series())
+        }
+        DataType::Boolean => {
+            let it = values.iter().map(|v| match v {
+                Some(JsonValue::Bool(v)) => Some(*v),
+                Some(JsonValue::String(v)) => v.parse::<bool>().ok(),
+                Some(JsonValue::Number(v)) => v.as_f64().map(|v| v != 0.0),
+                _ => None,
+            });
+            Ok(DFBooleanArray::new_from_opt_iter(it).into_series())
+        }
+        dt if is_floating(dt) => {
+            let it = values.iter().map(|v| match v {
+                Some(JsonValue::Number(v)) => v.as_f64(),
+                Some(JsonValue::String(v)) => v.parse::<f64>().ok(),
+                Some(JsonValue::Bool(v)) => Some(*v as u8 as f64),
+                _ => None,
+            });
+            DFFloat64Array::new_from_opt_iter(it).cast_with_type(to_type)
+        }
+        dt if is_signed_numeric(dt) => {
+            let it = values.iter().map(|v| match v {
+                Some(JsonValue::Number(v)) => v.as_i64().or_else(|| v.as_f64().map(|v| v as i64)),
+                Some(JsonValue::String(v)) => v.parse::<i64>().ok(),
+                Some(JsonValue::Bool(v)) => Some(*v as i64),
+                _ => None,
+            });
+            DFInt64Array::new_from_opt_iter(it).cast_with_type(to_type)
+        }
+        dt if is_numeric(dt) => {
+            let it = values.iter().map(|v| match v {
+                Some(JsonValue::Number(v)) => v.as_u64().or_else(|| v.as_f64().map(|v| v as u64)),
+                Some(JsonValue::String(v)) => v.parse::<u64>().ok(),
+                Some(JsonValue::Bool(v)) => Some(*v as u64),
+                _ => None,
+            });
+            DFUInt64Array::new_from_opt_iter(it).cast_with_type(to_type)
+        }
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from Variant into {}",
+            other
+        ))),
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// Concatenates the strings, the result is NULL if any of them is NULL.
+#[derive(Clone)]
+pub struct ConcatFunction {
+    display_name: String,
+}
+
+impl ConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ConcatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ConcatFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            check_string_argument(arg)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &vec![DataType::String; columns.len()])?;
+        let arrays = series
+            .iter()
+            .map(|s| s.string())
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows = arrays[0].len();
+        let mut buffer = Vec::new();
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        'rows: for row in 0..rows {
+            buffer.clear();
+            for array in &arrays {
+                match string_at(array, row) {
+                    Some(value) => buffer.extend_from_slice(value),
+                    None => {
+                        builder.append_null();
+                        continue 'rows;
+                    }
+                }
+            }
+            builder.append_value(&buffer);
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
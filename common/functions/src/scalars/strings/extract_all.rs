@@ -0,0 +1,103 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::regexp_extract::default_group;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::strings::string_common::RegexpCache;
+use crate::scalars::Function;
+
+/// extractAll(str, pattern) returns the array of all the non-overlapping matches, each one is the
+/// first captured group if the pattern has one, otherwise the whole match.
+#[derive(Clone)]
+pub struct ExtractAllFunction {
+    display_name: String,
+}
+
+impl ExtractAllFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtractAllFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ExtractAllFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::String,
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String, DataType::String])?;
+        let (values, patterns) = (series[0].string()?, series[1].string()?);
+
+        let mut regexps = RegexpCache::default();
+        let mut builder = ListStringArrayBuilder::with_capacity(values.len() * 4, values.len());
+        for row in 0..values.len() {
+            match (string_at(values, row), string_at(patterns, row)) {
+                (Some(value), Some(pattern)) => {
+                    let regex = regexps.get(pattern)?;
+                    let group = default_group(regex);
+                    let matches = regex
+                        .captures_iter(value)
+                        .filter_map(|captures| captures.get(group))
+                        .map(|m| m.as_bytes())
+                        .collect::<Vec<_>>();
+                    builder.append_series(&DFStringArray::new_from_slice(&matches).into_series());
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ExtractAllFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// format(pattern, s0, s1, ...) replaces `{}` in the pattern by the arguments in order and `{N}`
+/// by the N-th (0-based) argument, `{{` and `}}` are escaped braces.
+#[derive(Clone)]
+pub struct FormatFunction {
+    display_name: String,
+}
+
+impl FormatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn format(pattern: &[u8], args: &[&[u8]], buffer: &mut Vec<u8>) -> Result<()> {
+        let mut next_arg = 0;
+        let mut pos = 0;
+        while pos < pattern.len() {
+            match pattern[pos] {
+                b'{' if pattern.get(pos + 1) == Some(&b'{') => {
+                    buffer.push(b'{');
+                    pos += 2;
+                }
+                b'}' if pattern.get(pos + 1) == Some(&b'}') => {
+                    buffer.push(b'}');
+                    pos += 2;
+                }
+                b'{' => {
+                    let end = match pattern[pos..].iter().position(|c| *c == b'}') {
+                        Some(end) => pos + end,
+                        None => {
+                            return Err(ErrorCode::BadArguments(
+                                "Unclosed '{' in the pattern of function format",
+                            ))
+                        }
+                    };
+
+                    let index = match &pattern[pos + 1..end] {
+                        [] => {
+                            next_arg += 1;
+                            next_arg - 1
+                        }
+                        index => std::str::from_utf8(index)
+                            .ok()
+                            .and_then(|index| index.parse::<usize>().ok())
+                            .ok_or_else(|| {
+                                ErrorCode::BadArguments(format!(
+                                    "Invalid placeholder {{{}}} in the pattern of function format",
+                                    String::from_utf8_lossy(index)
+                                ))
+                            })?,
+                    };
+
+                    match args.get(index) {
+                        Some(arg) => buffer.extend_from_slice(arg),
+                        None => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Placeholder {} is out of the {} arguments of function format",
+                                index,
+                                args.len()
+                            )))
+                        }
+                    }
+                    pos = end + 1;
+                }
+                b'}' => {
+                    return Err(ErrorCode::BadArguments(
+                        "Unmatched '}' in the pattern of function format",
+                    ))
+                }
+                c => {
+                    buffer.push(c);
+                    pos += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Function for FormatFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            check_string_argument(arg)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &vec![DataType::String; columns.len()])?;
+        let arrays = series
+            .iter()
+            .map(|s| s.string())
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows = arrays[0].len();
+        let mut args = Vec::with_capacity(arrays.len() - 1);
+        let mut buffer = Vec::new();
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            args.clear();
+            let pattern = string_at(arrays[0], row);
+            for array in &arrays[1..] {
+                if let Some(arg) = string_at(array, row) {
+                    args.push(arg);
+                }
+            }
+
+            match pattern {
+                Some(pattern) if args.len() == arrays.len() - 1 => {
+                    buffer.clear();
+                    Self::format(pattern, &args, &mut buffer)?;
+                    builder.append_value(&buffer);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for FormatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::is_integer;
+use common_datavalues::is_signed_numeric;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::Function;
+
+/// hex(str) returns the uppercase hexadecimal representation of the bytes of the string,
+/// hex(n) the one of the integer, negative integers are taken as 64-bit two's complement.
+#[derive(Clone)]
+pub struct HexFunction {
+    display_name: String,
+}
+
+impl HexFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(HexFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for HexFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] == DataType::String || args[0] == DataType::Null || is_integer(&args[0]) {
+            Ok(DataType::String)
+        } else {
+            Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or integer type, but got {}",
+                args[0]
+            )))
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = columns[0].data_type();
+        let result = if is_integer(data_type) && is_signed_numeric(data_type) {
+            let series = cast_arguments(columns, &[DataType::Int64])?;
+            let opt_iter = series[0]
+                .i64()?
+                .into_iter()
+                .map(|vo| vo.map(|v| format!("{:X}", *v as u64)));
+            DFStringArray::new_from_opt_iter(opt_iter)
+        } else if is_integer(data_type) {
+            let series = cast_arguments(columns, &[DataType::UInt64])?;
+            let opt_iter = series[0]
+                .u64()?
+                .into_iter()
+                .map(|vo| vo.map(|v| format!("{:X}", v)));
+            DFStringArray::new_from_opt_iter(opt_iter)
+        } else {
+            let series = cast_arguments(columns, &[DataType::String])?;
+            let opt_iter = series[0]
+                .string()?
+                .into_iter()
+                .map(|vo| vo.map(|v| v.iter().map(|c| format!("{:02X}", c)).collect::<String>()));
+            DFStringArray::new_from_opt_iter(opt_iter)
+        };
+
+        let column: DataColumn = result.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for HexFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// left(str, len) and right(str, len) return the leftmost or rightmost `len` bytes of the string.
+#[derive(Clone)]
+pub struct LeftRightFunction<const IS_LEFT: bool> {
+    display_name: String,
+}
+
+impl<const IS_LEFT: bool> LeftRightFunction<IS_LEFT> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LeftRightFunction::<IS_LEFT> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn take(value: &[u8], len: i64) -> &[u8] {
+        let len = (len.max(0) as usize).min(value.len());
+        match IS_LEFT {
+            true => &value[..len],
+            false => &value[value.len() - len..],
+        }
+    }
+}
+
+impl<const IS_LEFT: bool> Function for LeftRightFunction<IS_LEFT> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_integer_argument(&args[1])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String, DataType::Int64])?;
+        let (values, lens) = (series[0].string()?, series[1].i64()?);
+
+        let opt_iter =
+            (0..values.len()).map(|row| match (string_at(values, row), i64_at(lens, row)) {
+                (Some(value), Some(len)) => Some(Self::take(value, len)),
+                _ => None,
+            });
+
+        let column: DataColumn = DFStringArray::new_from_opt_iter(opt_iter).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<const IS_LEFT: bool> fmt::Display for LeftRightFunction<IS_LEFT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type LeftFunction = LeftRightFunction<true>;
+pub type RightFunction = LeftRightFunction<false>;
@@ -0,0 +1,114 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::find;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// locate(substr, str[, pos]) returns the 1-based byte position of the first occurrence of
+/// `substr` in `str`, starting the search at `pos`. Returns 0 if it's not found.
+#[derive(Clone)]
+pub struct LocateFunction {
+    display_name: String,
+}
+
+impl LocateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LocateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn locate(substr: &[u8], value: &[u8], pos: i64) -> u64 {
+        if pos < 1 || pos as usize > value.len() + 1 {
+            return 0;
+        }
+
+        let start = pos as usize - 1;
+        find(&value[start..], substr).map_or(0, |offset| (start + offset + 1) as u64)
+    }
+}
+
+impl Function for LocateFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        if let Some(pos) = args.get(2) {
+            check_integer_argument(pos)?;
+        }
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::String,
+            DataType::Int64,
+        ])?;
+        let (substrs, values) = (series[0].string()?, series[1].string()?);
+        let positions = match series.get(2) {
+            Some(positions) => Some(positions.i64()?),
+            None => None,
+        };
+
+        let opt_iter = (0..values.len()).map(|row| {
+            let pos = match positions {
+                Some(positions) => i64_at(positions, row),
+                None => Some(1),
+            };
+
+            match (string_at(substrs, row), string_at(values, row), pos) {
+                (Some(substr), Some(value), Some(pos)) => Some(Self::locate(substr, value, pos)),
+                _ => None,
+            }
+        });
+
+        let column: DataColumn = DFUInt64Array::new_from_opt_iter(opt_iter).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for LocateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -12,8 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod concat;
+mod concat_ws;
+mod extract_all;
+mod format;
+mod hex;
+mod left_right;
+mod length;
+mod locate;
+mod pad;
+mod regexp_extract;
+mod regexp_replace;
+mod regexp_substr;
+mod repeat;
+mod replace;
+mod split_part;
+mod starts_ends_with;
 mod string;
+mod string_common;
+mod string_unary;
 mod substring;
 
+pub use concat::ConcatFunction;
+pub use concat_ws::ConcatWsFunction;
+pub use extract_all::ExtractAllFunction;
+pub use format::FormatFunction;
+pub use hex::HexFunction;
+pub use left_right::LeftFunction;
+pub use left_right::RightFunction;
+pub use length::LengthFunction;
+pub use locate::LocateFunction;
+pub use pad::LeftPadFunction;
+pub use pad::RightPadFunction;
+pub use regexp_extract::RegexpExtractFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_substr::RegexpSubstrFunction;
+pub use repeat::RepeatFunction;
+pub use replace::ReplaceFunction;
+pub use split_part::SplitPartFunction;
+pub use starts_ends_with::EndsWithFunction;
+pub use starts_ends_with::StartsWithFunction;
 pub use string::StringFunction;
+pub(crate) use string_common::cast_arguments;
+pub(crate) use string_common::check_string_argument;
+pub(crate) use string_common::i64_at;
+pub(crate) use string_common::string_at;
+pub(crate) use string_common::RegexpCache;
+pub use string_unary::Base64DecodeFunction;
+pub use string_unary::Base64EncodeFunction;
+pub use string_unary::LTrimFunction;
+pub use string_unary::LowerFunction;
+pub use string_unary::RTrimFunction;
+pub use string_unary::ReverseFunction;
+pub use string_unary::TrimFunction;
+pub use string_unary::UnhexFunction;
+pub use string_unary::UpperFunction;
 pub use substring::SubstringFunction;
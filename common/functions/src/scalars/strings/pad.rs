@@ -0,0 +1,145 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::strings::string_common::MAX_STRING_BYTES;
+use crate::scalars::Function;
+
+/// lpad(str, len[, pad]) and rpad(str, len[, pad]) pad the string with `pad` (a space by default)
+/// on the left or on the right to `len` bytes. A longer string is truncated to `len` bytes,
+/// an empty `pad` leaves the string as it is.
+#[derive(Clone)]
+pub struct PadFunction<const IS_LEFT: bool> {
+    display_name: String,
+}
+
+impl<const IS_LEFT: bool> PadFunction<IS_LEFT> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PadFunction::<IS_LEFT> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn pad(value: &[u8], len: usize, pad: &[u8], buffer: &mut Vec<u8>) {
+        if value.len() >= len {
+            buffer.extend_from_slice(&value[..len]);
+            return;
+        }
+
+        if pad.is_empty() {
+            buffer.extend_from_slice(value);
+            return;
+        }
+
+        if !IS_LEFT {
+            buffer.extend_from_slice(value);
+        }
+        let padding = len - value.len();
+        buffer.extend(pad.iter().cycle().take(padding));
+        if IS_LEFT {
+            buffer.extend_from_slice(value);
+        }
+    }
+}
+
+impl<const IS_LEFT: bool> Function for PadFunction<IS_LEFT> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_integer_argument(&args[1])?;
+        if let Some(pad) = args.get(2) {
+            check_string_argument(pad)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::Int64,
+            DataType::String,
+        ])?;
+        let (values, lens) = (series[0].string()?, series[1].i64()?);
+        let pads = match series.get(2) {
+            Some(pads) => Some(pads.string()?),
+            None => None,
+        };
+
+        let mut buffer = Vec::new();
+        let mut builder = StringArrayBuilder::with_capacity(values.len());
+        for row in 0..values.len() {
+            let pad = match pads {
+                Some(pads) => string_at(pads, row),
+                None => Some(&b" "[..]),
+            };
+
+            match (string_at(values, row), i64_at(lens, row), pad) {
+                (Some(value), Some(len), Some(pad)) => {
+                    let len = len.max(0) as usize;
+                    if len > MAX_STRING_BYTES {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Too large length to pad: {}, the result must not exceed {} bytes",
+                            len, MAX_STRING_BYTES
+                        )));
+                    }
+
+                    buffer.clear();
+                    Self::pad(value, len, pad, &mut buffer);
+                    builder.append_value(&buffer);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<const IS_LEFT: bool> fmt::Display for PadFunction<IS_LEFT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type LeftPadFunction = PadFunction<true>;
+pub type RightPadFunction = PadFunction<false>;
@@ -0,0 +1,146 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::strings::string_common::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_extract(str, pattern[, group]) returns the captured group of the first match,
+/// or an empty string if nothing matches. The group is 0 for the whole match, by default
+/// it's the first captured group if the pattern has one, otherwise the whole match.
+#[derive(Clone)]
+pub struct RegexpExtractFunction {
+    display_name: String,
+}
+
+impl RegexpExtractFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpExtractFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+/// The group extracted when the group argument is omitted.
+pub(crate) fn default_group(regex: &Regex) -> usize {
+    match regex.captures_len() > 1 {
+        true => 1,
+        false => 0,
+    }
+}
+
+pub(crate) fn check_group(regex: &Regex, group: i64) -> Result<usize> {
+    match group >= 0 && (group as usize) < regex.captures_len() {
+        true => Ok(group as usize),
+        false => Err(ErrorCode::BadArguments(format!(
+            "Group {} is out of the {} captured groups of the pattern {}",
+            group,
+            regex.captures_len() - 1,
+            regex
+        ))),
+    }
+}
+
+impl Function for RegexpExtractFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        if let Some(group) = args.get(2) {
+            check_integer_argument(group)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::String,
+            DataType::Int64,
+        ])?;
+        let (values, patterns) = (series[0].string()?, series[1].string()?);
+        let groups = match series.get(2) {
+            Some(groups) => Some(groups.i64()?),
+            None => None,
+        };
+
+        let mut regexps = RegexpCache::default();
+        let mut builder = StringArrayBuilder::with_capacity(values.len());
+        for row in 0..values.len() {
+            let (value, pattern) = match (string_at(values, row), string_at(patterns, row)) {
+                (Some(value), Some(pattern)) => (value, pattern),
+                _ => {
+                    builder.append_null();
+                    continue;
+                }
+            };
+
+            let regex = regexps.get(pattern)?;
+            let group = match groups {
+                Some(groups) => match i64_at(groups, row) {
+                    Some(group) => check_group(regex, group)?,
+                    None => {
+                        builder.append_null();
+                        continue;
+                    }
+                },
+                None => default_group(regex),
+            };
+
+            let extracted = regex
+                .captures(value)
+                .and_then(|captures| captures.get(group))
+                .map_or(&[][..], |m| m.as_bytes());
+            builder.append_value(extracted);
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpExtractFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
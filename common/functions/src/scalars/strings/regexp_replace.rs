@@ -0,0 +1,105 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::strings::string_common::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_replace(str, pattern, replacement) replaces all the matches of the regular expression,
+/// `$N` or `${name}` in the replacement refers to the captured groups.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpReplaceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            check_string_argument(arg)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::String,
+            DataType::String,
+        ])?;
+        let (values, patterns, replacements) = (
+            series[0].string()?,
+            series[1].string()?,
+            series[2].string()?,
+        );
+
+        let mut regexps = RegexpCache::default();
+        let mut builder = StringArrayBuilder::with_capacity(values.len());
+        for row in 0..values.len() {
+            match (
+                string_at(values, row),
+                string_at(patterns, row),
+                string_at(replacements, row),
+            ) {
+                (Some(value), Some(pattern), Some(replacement)) => {
+                    let regex = regexps.get(pattern)?;
+                    builder.append_value(regex.replace_all(value, replacement));
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::strings::string_common::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_substr(str, pattern[, pos[, occurrence]]) returns the `occurrence`-th match of the
+/// regular expression, searching from the 1-based byte position `pos`. Returns NULL if there is
+/// no such match.
+#[derive(Clone)]
+pub struct RegexpSubstrFunction {
+    display_name: String,
+}
+
+impl RegexpSubstrFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpSubstrFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpSubstrFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 4))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        for arg in &args[2..] {
+            check_integer_argument(arg)?;
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::String,
+            DataType::Int64,
+            DataType::Int64,
+        ])?;
+        let (values, patterns) = (series[0].string()?, series[1].string()?);
+        let positions = match series.get(2) {
+            Some(positions) => Some(positions.i64()?),
+            None => None,
+        };
+        let occurrences = match series.get(3) {
+            Some(occurrences) => Some(occurrences.i64()?),
+            None => None,
+        };
+
+        let mut regexps = RegexpCache::default();
+        let mut builder = StringArrayBuilder::with_capacity(values.len());
+        for row in 0..values.len() {
+            let pos = positions.map_or(Some(1), |positions| i64_at(positions, row));
+            let occurrence = occurrences.map_or(Some(1), |occurrences| i64_at(occurrences, row));
+
+            match (
+                string_at(values, row),
+                string_at(patterns, row),
+                pos,
+                occurrence,
+            ) {
+                (Some(value), Some(pattern), Some(pos), Some(occurrence)) => {
+                    let regex = regexps.get(pattern)?;
+                    let matched = match pos >= 1 && occurrence >= 1 {
+                        true => value
+                            .get(pos as usize - 1..)
+                            .and_then(|rest| regex.find_iter(rest).nth(occurrence as usize - 1)),
+                        false => None,
+                    };
+                    builder.append_option(matched.map(|m| m.as_bytes()));
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpSubstrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_integer_argument;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::find;
+use crate::scalars::strings::string_common::i64_at;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// split_part(str, delimiter, n) splits the string on the delimiter and returns the n-th
+/// (1-based) part, or an empty string if there are fewer parts.
+#[derive(Clone)]
+pub struct SplitPartFunction {
+    display_name: String,
+}
+
+impl SplitPartFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitPartFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn split_part<'a>(value: &'a [u8], delimiter: &[u8], n: i64) -> &'a [u8] {
+        if n < 1 {
+            return &[];
+        }
+
+        if delimiter.is_empty() {
+            return match n {
+                1 => value,
+                _ => &[],
+            };
+        }
+
+        let mut rest = value;
+        for _ in 1..n {
+            match find(rest, delimiter) {
+                Some(offset) => rest = &rest[offset + delimiter.len()..],
+                None => return &[],
+            }
+        }
+
+        match find(rest, delimiter) {
+            Some(offset) => &rest[..offset],
+            None => rest,
+        }
+    }
+}
+
+impl Function for SplitPartFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        check_integer_argument(&args[2])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[
+            DataType::String,
+            DataType::String,
+            DataType::Int64,
+        ])?;
+        let (values, delimiters, parts) =
+            (series[0].string()?, series[1].string()?, series[2].i64()?);
+
+        let opt_iter = (0..values.len()).map(|row| {
+            match (
+                string_at(values, row),
+                string_at(delimiters, row),
+                i64_at(parts, row),
+            ) {
+                (Some(value), Some(delimiter), Some(n)) => {
+                    Some(Self::split_part(value, delimiter, n))
+                }
+                _ => None,
+            }
+        });
+
+        let column: DataColumn = DFStringArray::new_from_opt_iter(opt_iter).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for SplitPartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::strings::string_common::string_at;
+use crate::scalars::Function;
+
+/// startsWith(str, prefix) and endsWith(str, suffix).
+#[derive(Clone)]
+pub struct StartsEndsWithFunction<const IS_STARTS: bool> {
+    display_name: String,
+}
+
+impl<const IS_STARTS: bool> StartsEndsWithFunction<IS_STARTS> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsEndsWithFunction::<IS_STARTS> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<const IS_STARTS: bool> Function for StartsEndsWithFunction<IS_STARTS> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        check_string_argument(&args[1])?;
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String, DataType::String])?;
+        let (values, patterns) = (series[0].string()?, series[1].string()?);
+
+        let opt_iter =
+            (0..values.len()).map(
+                |row| match (string_at(values, row), string_at(patterns, row)) {
+                    (Some(value), Some(pattern)) => match IS_STARTS {
+                        true => Some(value.starts_with(pattern)),
+                        false => Some(value.ends_with(pattern)),
+                    },
+                    _ => None,
+                },
+            );
+
+        let column: DataColumn = DFBooleanArray::new_from_opt_iter(opt_iter).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<const IS_STARTS: bool> fmt::Display for StartsEndsWithFunction<IS_STARTS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type StartsWithFunction = StartsEndsWithFunction<true>;
+pub type EndsWithFunction = StartsEndsWithFunction<false>;
@@ -13,13 +13,72 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::Base64DecodeFunction;
+use crate::scalars::Base64EncodeFunction;
+use crate::scalars::ConcatFunction;
+use crate::scalars::ConcatWsFunction;
+use crate::scalars::EndsWithFunction;
+use crate::scalars::ExtractAllFunction;
+use crate::scalars::FormatFunction;
+use crate::scalars::HexFunction;
+use crate::scalars::LTrimFunction;
+use crate::scalars::LeftFunction;
+use crate::scalars::LeftPadFunction;
+use crate::scalars::LengthFunction;
+use crate::scalars::LocateFunction;
+use crate::scalars::LowerFunction;
+use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpExtractFunction;
+use crate::scalars::RegexpReplaceFunction;
+use crate::scalars::RegexpSubstrFunction;
+use crate::scalars::RepeatFunction;
+use crate::scalars::ReplaceFunction;
+use crate::scalars::ReverseFunction;
+use crate::scalars::RightFunction;
+use crate::scalars::RightPadFunction;
+use crate::scalars::SplitPartFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::SubstringFunction;
+use crate::scalars::TrimFunction;
+use crate::scalars::UnhexFunction;
+use crate::scalars::UpperFunction;
 
 #[derive(Clone)]
 pub struct StringFunction;
 
 impl StringFunction {
     pub fn register(factory: &mut FunctionFactory) {
-        factory.register("substring", SubstringFunction::desc())
+        factory.register("substring", SubstringFunction::desc());
+        factory.register("length", LengthFunction::desc());
+        factory.register("lower", LowerFunction::desc());
+        factory.register("lcase", LowerFunction::desc());
+        factory.register("upper", UpperFunction::desc());
+        factory.register("ucase", UpperFunction::desc());
+        factory.register("concat", ConcatFunction::desc());
+        factory.register("concat_ws", ConcatWsFunction::desc());
+        factory.register("trim", TrimFunction::desc());
+        factory.register("ltrim", LTrimFunction::desc());
+        factory.register("rtrim", RTrimFunction::desc());
+        factory.register("replace", ReplaceFunction::desc());
+        factory.register("reverse", ReverseFunction::desc());
+        factory.register("repeat", RepeatFunction::desc());
+        factory.register("lpad", LeftPadFunction::desc());
+        factory.register("rpad", RightPadFunction::desc());
+        factory.register("locate", LocateFunction::desc());
+        factory.register("position", LocateFunction::desc());
+        factory.register("left", LeftFunction::desc());
+        factory.register("right", RightFunction::desc());
+        factory.register("split_part", SplitPartFunction::desc());
+        factory.register("startsWith", StartsWithFunction::desc());
+        factory.register("endsWith", EndsWithFunction::desc());
+        factory.register("format", FormatFunction::desc());
+        factory.register("hex", HexFunction::desc());
+        factory.register("unhex", UnhexFunction::desc());
+        factory.register("base64Encode", Base64EncodeFunction::desc());
+        factory.register("base64Decode", Base64DecodeFunction::desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("regexp_extract", RegexpExtractFunction::desc());
+        factory.register("regexp_substr", RegexpSubstrFunction::desc());
+        factory.register("extractAll", ExtractAllFunction::desc());
     }
 }
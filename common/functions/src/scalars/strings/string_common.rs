@@ -0,0 +1,131 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::is_integer;
+use common_datavalues::is_numeric;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+/// The upper bound of the strings built by the functions, e.g. `repeat` and `lpad`.
+pub(crate) const MAX_STRING_BYTES: usize = 1 << 30;
+
+/// Checks that the argument can be used as a string: strings, numbers and NULL are accepted,
+/// the numbers are casted to their string representation.
+pub(crate) fn check_string_argument(arg: &DataType) -> Result<()> {
+    if *arg == DataType::String || *arg == DataType::Null || is_numeric(arg) {
+        Ok(())
+    } else {
+        Err(ErrorCode::IllegalDataType(format!(
+            "Expected string or numeric type, but got {}",
+            arg
+        )))
+    }
+}
+
+/// Checks that the argument is an integer (or NULL).
+pub(crate) fn check_integer_argument(arg: &DataType) -> Result<()> {
+    if *arg == DataType::Null || is_integer(arg) {
+        Ok(())
+    } else {
+        Err(ErrorCode::IllegalDataType(format!(
+            "Expected integer type, but got {}",
+            arg
+        )))
+    }
+}
+
+/// Casts the arguments to `types` and returns them as arrays of the same length.
+/// If all the arguments are constant only one row is evaluated, the result should be resized
+/// back with `DataColumn::resize_constant`.
+pub(crate) fn cast_arguments(
+    columns: &DataColumnsWithField,
+    types: &[DataType],
+) -> Result<Vec<Series>> {
+    let all_constant = columns
+        .iter()
+        .all(|c| matches!(c.column(), DataColumn::Constant(_, _)));
+
+    columns
+        .iter()
+        .zip(types.iter())
+        .map(|(column, data_type)| {
+            let array = match all_constant {
+                true => column.column().to_minimal_array()?,
+                false => column.column().to_array()?,
+            };
+            array.cast_with_type(data_type)
+        })
+        .collect()
+}
+
+/// Returns the string at `row`, None if it is NULL.
+#[inline]
+pub(crate) fn string_at(array: &DFStringArray, row: usize) -> Option<&[u8]> {
+    match array.is_null(row) {
+        true => None,
+        false => Some(array.inner().value(row)),
+    }
+}
+
+/// Returns the integer at `row`, None if it is NULL.
+#[inline]
+pub(crate) fn i64_at(array: &DFInt64Array, row: usize) -> Option<i64> {
+    match array.is_null(row) {
+        true => None,
+        false => Some(array.inner().value(row)),
+    }
+}
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`.
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Compiles the regular expressions of a block, every distinct pattern is compiled only once,
+/// so a constant pattern costs a single compilation.
+#[derive(Default)]
+pub(crate) struct RegexpCache {
+    regexps: HashMap<Vec<u8>, Regex>,
+}
+
+impl RegexpCache {
+    pub(crate) fn get(&mut self, pattern: &[u8]) -> Result<&Regex> {
+        if !self.regexps.contains_key(pattern) {
+            let pattern_str = std::str::from_utf8(pattern).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Unable to convert the regular expression to string: {}",
+                    e
+                ))
+            })?;
+            let regex = Regex::new(pattern_str).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Unable to build regex from the pattern {}: {}",
+                    pattern_str, e
+                ))
+            })?;
+            self.regexps.insert(pattern.to_vec(), regex);
+        }
+        Ok(&self.regexps[pattern])
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::string_common::cast_arguments;
+use crate::scalars::strings::string_common::check_string_argument;
+use crate::scalars::Function;
+
+/// A function mapping every string to another string, NULL stays NULL.
+#[derive(Clone, Debug)]
+pub struct StringUnaryFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+pub trait StringUnaryOperator {
+    /// Whether a non-NULL input may produce NULL, e.g. a malformed input of a decoder.
+    const MAYBE_NULL: bool;
+
+    /// Writes the result of `value` into the (cleared) `buffer`,
+    /// returns false if the result is NULL.
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool;
+}
+
+#[derive(Clone, Debug)]
+pub struct Lower;
+
+impl StringUnaryOperator for Lower {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().map(u8::to_ascii_lowercase));
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Upper;
+
+impl StringUnaryOperator for Upper {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().map(u8::to_ascii_uppercase));
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Trim;
+
+impl StringUnaryOperator for Trim {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(trim_end(trim_start(value)));
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LTrim;
+
+impl StringUnaryOperator for LTrim {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(trim_start(value));
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RTrim;
+
+impl StringUnaryOperator for RTrim {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend_from_slice(trim_end(value));
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Reverse;
+
+impl StringUnaryOperator for Reverse {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        buffer.extend(value.iter().rev());
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Unhex;
+
+impl StringUnaryOperator for Unhex {
+    const MAYBE_NULL: bool = true;
+
+    // An odd number of digits is read as if it had a leading zero, like MySQL does.
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let (head, tail) = value.split_at(value.len() % 2);
+        if let Some(digit) = head.first() {
+            match hex_digit(*digit) {
+                Some(v) => buffer.push(v),
+                None => return false,
+            }
+        }
+
+        for pair in tail.chunks_exact(2) {
+            match (hex_digit(pair[0]), hex_digit(pair[1])) {
+                (Some(high), Some(low)) => buffer.push(high << 4 | low),
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Base64Encode;
+
+impl StringUnaryOperator for Base64Encode {
+    const MAYBE_NULL: bool = false;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        let mut encoded = String::new();
+        base64::encode_config_buf(value, base64::STANDARD, &mut encoded);
+        buffer.extend_from_slice(encoded.as_bytes());
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Base64Decode;
+
+impl StringUnaryOperator for Base64Decode {
+    const MAYBE_NULL: bool = true;
+
+    fn apply(value: &[u8], buffer: &mut Vec<u8>) -> bool {
+        base64::decode_config_buf(value, base64::STANDARD, buffer).is_ok()
+    }
+}
+
+fn trim_start(value: &[u8]) -> &[u8] {
+    let start = value.iter().position(|c| *c != b' ').unwrap_or(value.len());
+    &value[start..]
+}
+
+fn trim_end(value: &[u8]) -> &[u8] {
+    let end = value.iter().rposition(|c| *c != b' ').map_or(0, |p| p + 1);
+    &value[..end]
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl<T> StringUnaryFunction<T>
+where T: StringUnaryOperator + Clone + Sync + Send + 'static
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StringUnaryFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<T> Function for StringUnaryFunction<T>
+where T: StringUnaryOperator + Clone + Sync + Send
+{
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_string_argument(&args[0])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(T::MAYBE_NULL)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = cast_arguments(columns, &[DataType::String])?;
+        let array = series[0].string()?;
+
+        let mut buffer = Vec::new();
+        let mut builder = StringArrayBuilder::with_capacity(array.len());
+        for value in array {
+            match value {
+                Some(value) => {
+                    buffer.clear();
+                    match T::apply(value, &mut buffer) {
+                        true => builder.append_value(&buffer),
+                        false => builder.append_null(),
+                    }
+                }
+                None => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<T> fmt::Display for StringUnaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type LowerFunction = StringUnaryFunction<Lower>;
+pub type UpperFunction = StringUnaryFunction<Upper>;
+pub type TrimFunction = StringUnaryFunction<Trim>;
+pub type LTrimFunction = StringUnaryFunction<LTrim>;
+pub type RTrimFunction = StringUnaryFunction<RTrim>;
+pub type ReverseFunction = StringUnaryFunction<Reverse>;
+pub type UnhexFunction = StringUnaryFunction<Unhex>;
+pub type Base64EncodeFunction = StringUnaryFunction<Base64Encode>;
+pub type Base64DecodeFunction = StringUnaryFunction<Base64Decode>;
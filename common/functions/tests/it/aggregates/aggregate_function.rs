@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::aggregates::*;
@@ -229,6 +230,139 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::UInt8(Some(3)),
             error: "",
         },
+        Test {
+            name: "uniqHLL12-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqHLL12",
+            func_name: "uniqHLL12",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "approx_count_distinct-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "uniqCombined-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqCombined",
+            func_name: "uniqCombined",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.25))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.75)),
+            error: "",
+        },
+        Test {
+            name: "quantile-level-out-of-range",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(1.5))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Null,
+            error: "Code: 6, displayText = quantile expect the level to be in [0, 1], but got 1.5.",
+        },
+        Test {
+            name: "quantiles-passed",
+            eval_nums: 1,
+            params: vec![
+                DataValue::Float64(Some(0.25)),
+                DataValue::Float64(Some(0.5)),
+            ],
+            args: vec![args[0].clone()],
+            display: "quantiles",
+            func_name: "quantiles",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Float64(Some(1.75)),
+                    DataValue::Float64(Some(2.5)),
+                ]),
+                DataType::Float64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "quantiles-without-levels",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "quantiles",
+            func_name: "quantiles",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Null,
+            error: "Code: 28, displayText = quantiles expect to have at least one parameter.",
+        },
+        Test {
+            name: "quantileExact-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "quantileExact",
+            func_name: "quantileExact",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(3)),
+            error: "",
+        },
+        Test {
+            name: "quantilesExact-passed",
+            eval_nums: 1,
+            params: vec![DataValue::UInt64(Some(0)), DataValue::UInt64(Some(1))],
+            args: vec![args[0].clone()],
+            display: "quantilesExact",
+            func_name: "quantilesExact",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(4))]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "medianTDigest-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "medianTDigest",
+            func_name: "medianTDigest",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -267,6 +401,59 @@ fn test_aggregate_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_aggregate_decimal() -> Result<()> {
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("d", DataType::Decimal(10, 2), true)];
+
+    let tests = vec![
+        (
+            "sum",
+            Series::new(vec![Some(125i64), Some(126), None, Some(-30), Some(210)]),
+            DataValue::Int64(Some(431)),
+        ),
+        (
+            "avg",
+            Series::new(vec![Some(125i64), Some(126), None, Some(-30), Some(210)]),
+            DataValue::Int64(Some(108)),
+        ),
+        (
+            "avg",
+            Series::new(vec![-125i64, -126]),
+            DataValue::Int64(Some(-126)),
+        ),
+        (
+            "avg",
+            Series::new(vec![None::<i64>]),
+            DataValue::Int64(None),
+        ),
+    ];
+
+    for (name, array, expect) in tests {
+        let arena = Bump::new();
+        let func = factory.get(name, vec![], args.clone())?;
+        assert_eq!(func.return_type()?, DataType::Decimal(18, 2), "{}", name);
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &[array.clone()], array.len())?;
+        assert_eq!(func.merge_result(addr.into())?, expect, "{}", name);
+    }
+
+    // The sum out of the range of the decimal is an error instead of wrapping around.
+    let arena = Bump::new();
+    let max = 999_999_999_999_999_999i64;
+    for name in ["sum", "avg"] {
+        let func = factory.get(name, vec![], args.clone())?;
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        let array = Series::new(vec![max; 10]);
+        let result = func.accumulate(addr.into(), &[array], 10);
+        assert!(result.is_err(), "{}", name);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_aggregate_function_with_grpup_by() -> Result<()> {
     struct Test {
@@ -694,6 +881,61 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::Float64(Some(f64::INFINITY)),
             error: "",
         },
+        Test {
+            name: "uniqHLL12-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqHLL12",
+            func_name: "uniqHLL12",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
+        Test {
+            name: "uniqCombined-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqCombined",
+            func_name: "uniqCombined",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "quantileExact-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.9))],
+            args: vec![args[0].clone()],
+            display: "quantileExact",
+            func_name: "quantileExact",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(None),
+            error: "",
+        },
+        Test {
+            name: "quantileTDigest-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(0.9))],
+            args: vec![args[0].clone()],
+            display: "quantileTDigest",
+            func_name: "quantileTDigest",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -776,3 +1018,134 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_quantile_state_serialization() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("a", DataType::UInt32, false)];
+
+    let values: Vec<u32> = (1..=10000).rev().collect();
+    let (left, right) = values.split_at(3000);
+    let halves = vec![Series::new(left.to_vec()), Series::new(right.to_vec())];
+
+    let tests = vec![
+        ("quantile", 0.9, 9000.1),
+        ("quantileExact", 0.9, 9001.0),
+        ("quantileTDigest", 0.9, 9000.5),
+        ("quantileTDigest", 0.99, 9900.5),
+        ("quantileTDigest", 0.001, 10.5),
+    ];
+
+    for (name, level, expect) in tests {
+        let func = factory.get(name, vec![DataValue::Float64(Some(level))], args.clone())?;
+
+        // Accumulate each half on its own state, then ship it like a partial aggregation does.
+        let mut bytes = BytesMut::new();
+        for series in halves.iter() {
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            func.accumulate(addr.into(), &[series.clone()], series.len())?;
+            func.serialize(addr.into(), &mut bytes)?;
+        }
+
+        let mut reader: &[u8] = &bytes;
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.deserialize(addr1.into(), &mut reader)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.deserialize(addr2.into(), &mut reader)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+        let result = func.merge_result(addr1.into())?.as_f64()?;
+        assert!(
+            (result - expect).abs() <= expect * 0.01,
+            "{}({}): expect {}, got {}",
+            name,
+            level,
+            expect,
+            result
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_approx_count_distinct_state_serialization() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("a", DataType::UInt32, false)];
+
+    // Each chunk overlaps with the next one, there are 100000 distinct values in total.
+    let chunks: Vec<Series> = (0..4)
+        .map(|i| {
+            let end = ((i + 1) * 25000 + 1000).min(100000);
+            Series::new((i * 25000..end).collect::<Vec<u32>>())
+        })
+        .collect();
+
+    for name in ["uniqHLL12", "uniqCombined"] {
+        let func = factory.get(name, vec![], args.clone())?;
+
+        let mut bytes = BytesMut::new();
+        for chunk in chunks.iter() {
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            func.accumulate(addr.into(), &[chunk.clone()], chunk.len())?;
+            func.serialize(addr.into(), &mut bytes)?;
+        }
+        // The sketch has a fixed size, whatever the number of values is.
+        assert!(bytes.len() < 4 * 4200, "{}: {}", name, bytes.len());
+
+        let mut reader: &[u8] = &bytes;
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        for _ in chunks.iter() {
+            let rhs = arena.alloc_layout(func.state_layout());
+            func.init_state(rhs.into());
+            func.deserialize(rhs.into(), &mut reader)?;
+            func.merge(addr.into(), rhs.into())?;
+        }
+
+        let result = func.merge_result(addr.into())?.as_u64()?;
+        assert!(
+            (97000..=103000).contains(&result),
+            "{}: expect about 100000, got {}",
+            name,
+            result
+        );
+    }
+
+    // Small states are exact and serialized compactly.
+    let func = factory.get("uniqCombined", vec![], args.clone())?;
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    let series = Series::new(vec![1u32, 2, 3, 2, 1]);
+    func.accumulate(addr.into(), &[series], 5)?;
+
+    let mut bytes = BytesMut::new();
+    func.serialize(addr.into(), &mut bytes)?;
+    assert!(bytes.len() < 32);
+
+    let rhs = arena.alloc_layout(func.state_layout());
+    func.init_state(rhs.into());
+    func.deserialize(rhs.into(), &mut &bytes[..])?;
+    func.merge(addr.into(), rhs.into())?;
+    assert_eq!(func.merge_result(addr.into())?, DataValue::UInt64(Some(3)));
+
+    // A dense sketch with a register beyond the max rank is rejected.
+    let func = factory.get("uniqHLL12", vec![], args)?;
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    let mut bytes = vec![0u8; 1 + 4096];
+    bytes[0] = 1;
+    bytes[100] = 64;
+    let err = func.deserialize(addr.into(), &mut &bytes[..]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Code: 46, displayText = Invalid HyperLogLog register: 64."
+    );
+    Ok(())
+}
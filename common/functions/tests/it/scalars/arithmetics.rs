@@ -455,3 +455,214 @@ fn test_arithmetic_date_interval() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<(DataColumn, DataType)>,
+        expect_type: DataType,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    // 12.34, -0.05, NULL as Decimal(10, 2)
+    let decimal = || -> (DataColumn, DataType) {
+        (
+            Series::new(vec![Some(1234_i64), Some(-5), None]).into(),
+            DataType::Decimal(10, 2),
+        )
+    };
+
+    let tests = vec![
+        Test {
+            name: "add-decimal-passed",
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (
+                    Series::new(vec![Some(1_i64), Some(2), Some(3)]).into(),
+                    DataType::Decimal(5, 3),
+                ),
+            ],
+            expect_type: DataType::Decimal(12, 3),
+            expect: Series::new(vec![Some(12341_i64), Some(-48), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "minus-decimal-int-passed",
+            func: ArithmeticMinusFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (Series::new(vec![1_i32, 2, 3]).into(), DataType::Int32),
+            ],
+            expect_type: DataType::Decimal(13, 2),
+            expect: Series::new(vec![Some(1134_i64), Some(-205), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "mul-decimal-passed",
+            func: ArithmeticMulFunction::try_create_func("")?,
+            columns: vec![decimal(), decimal()],
+            expect_type: DataType::Decimal(18, 4),
+            expect: Series::new(vec![Some(1522756_i64), Some(25), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "div-decimal-passed",
+            func: ArithmeticDivFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (Series::new(vec![3_i8, 3, 3]).into(), DataType::Int8),
+            ],
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![Some(411_i64), Some(-2), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "mod-decimal-passed",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (Series::new(vec![5_i8, 5, 5]).into(), DataType::Int8),
+            ],
+            expect_type: DataType::Decimal(10, 2),
+            expect: Series::new(vec![Some(234_i64), Some(-5), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "add-decimal-float-passed",
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (
+                    Series::new(vec![0.5_f64, 0.5, 0.5]).into(),
+                    DataType::Float64,
+                ),
+            ],
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![Some(12.84_f64), Some(0.45), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "add-decimal-null-passed",
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (DataColumn::Constant(DataValue::Null, 3), DataType::Null),
+            ],
+            expect_type: DataType::Decimal(11, 2),
+            expect: Series::new(vec![None::<i64>, None, None]).into(),
+            error: "",
+        },
+        Test {
+            name: "negate-decimal-passed",
+            func: ArithmeticMinusFunction::try_create_func("")?,
+            columns: vec![decimal()],
+            expect_type: DataType::Decimal(10, 2),
+            expect: Series::new(vec![Some(-1234_i64), Some(5), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "div-decimal-zero-failed",
+            func: ArithmeticDivFunction::try_create_func("")?,
+            columns: vec![
+                decimal(),
+                (Series::new(vec![0_i8, 1, 1]).into(), DataType::Int8),
+            ],
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![0_i64]).into(),
+            error: "Division by zero",
+        },
+        Test {
+            name: "mul-decimal-overflow-failed",
+            func: ArithmeticMulFunction::try_create_func("")?,
+            columns: vec![
+                (
+                    Series::new(vec![i64::MAX / 10]).into(),
+                    DataType::Decimal(18, 0),
+                ),
+                (Series::new(vec![100_i64]).into(), DataType::Decimal(18, 0)),
+            ],
+            expect_type: DataType::Decimal(18, 0),
+            expect: Series::new(vec![0_i64]).into(),
+            error: "Decimal overflow",
+        },
+        Test {
+            name: "add-decimal-uint64-overflow-failed",
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            columns: vec![
+                (Series::new(vec![1_i64]).into(), DataType::Decimal(10, 2)),
+                (Series::new(vec![u64::MAX]).into(), DataType::UInt64),
+            ],
+            expect_type: DataType::Decimal(18, 2),
+            expect: Series::new(vec![0_i64]).into(),
+            error: "Value 18446744073709551615 is out of the range of Int64",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let rows = t.columns[0].0.len();
+        let args: Vec<DataType> = t.columns.iter().map(|(_, ty)| ty.clone()).collect();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|(c, ty)| {
+                DataColumnWithField::new(c.clone(), DataField::new("d", ty.clone(), true))
+            })
+            .collect();
+
+        assert_eq!(func.return_type(&args)?, t.expect_type, "case: {}", t.name);
+        match func.eval(&columns, rows) {
+            Ok(v) => {
+                assert_eq!(t.error, "", "case: {}", t.name);
+                assert_eq!(&v, &t.expect, "case: {}", t.name);
+            }
+            Err(e) => assert!(e.to_string().contains(t.error), "case: {}, {}", t.name, e),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_arithmetic_datetime64_minus() -> Result<()> {
+    // 2021-09-05 03:26:06.123 as DateTime64(3)
+    let lhs = DataColumnWithField::new(
+        Series::new(vec![1630812366123i64]).into(),
+        DataField::new("a", DataType::DateTime64(3, None), false),
+    );
+
+    let tests = vec![
+        (
+            "minus-datetime64-higher-precision-passed",
+            // 2021-09-05 03:26:05.000001 as DateTime64(6)
+            DataColumnWithField::new(
+                Series::new(vec![1630812365000001i64]).into(),
+                DataField::new("b", DataType::DateTime64(6, None), false),
+            ),
+            1122999i64,
+        ),
+        (
+            "minus-datetime32-passed",
+            // 2021-09-05 03:26:05 as DateTime32
+            DataColumnWithField::new(
+                Series::new(vec![1630812365u32]).into(),
+                DataField::new("b", DataType::DateTime32(None), false),
+            ),
+            1123i64,
+        ),
+    ];
+
+    let func = ArithmeticMinusFunction::try_create_func("")?;
+    for (name, rhs, expect) in tests {
+        let args = vec![lhs.data_type().clone(), rhs.data_type().clone()];
+        assert_eq!(func.return_type(&args)?, DataType::Int64, "{}", name);
+
+        let v = func.eval(&[lhs.clone(), rhs], 1)?;
+        let expect: DataColumn = Series::new(vec![expect]).into();
+        assert_eq!(&v, &expect, "{}", name);
+    }
+    Ok(())
+}
@@ -141,6 +141,32 @@ fn test_comparison_function() -> Result<()> {
             expect: Series::new(vec![false, false, false, true]),
             error: "",
         },
+        Test {
+            name: "regexp-passed",
+            display: "REGEXP",
+            nullable: false,
+            func: ComparisonRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "xyz", "a1"]).into(),
+                Series::new(vec!["^ab", "d$", "b", "[0-9]"]).into(),
+            ],
+            expect: Series::new(vec![true, true, false, true]),
+            error: "",
+        },
+        Test {
+            name: "not-regexp-passed",
+            display: "NOT REGEXP",
+            nullable: false,
+            func: ComparisonNotRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "xyz", "a1"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"^ab".to_vec())), 4),
+            ],
+            expect: Series::new(vec![false, false, true, true]),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -188,3 +214,264 @@ fn test_comparison_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_comparison_in_function() -> Result<()> {
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: Vec<DataValue>,
+    }
+
+    let rows = 4;
+    let constant = |v: i64| DataColumn::Constant(DataValue::Int64(Some(v)), rows);
+    let long_list = |first: i64| (first..first + 10).map(constant).collect::<Vec<_>>();
+    let subquery = |values: Vec<DataValue>| {
+        DataColumn::Constant(DataValue::List(Some(values), DataType::Int64), rows)
+    };
+
+    let tests = vec![
+        Test {
+            name: "in-short-list-passed",
+            display: "IN",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                constant(2),
+                constant(4),
+            ],
+            expect: vec![
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+            ],
+        },
+        Test {
+            name: "in-column-list-passed",
+            display: "IN",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                Series::new(vec![1i32, 0, 0, 4]).into(),
+                constant(3),
+            ],
+            expect: vec![
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(Some(true)),
+            ],
+        },
+        Test {
+            name: "not-in-short-list-passed",
+            display: "NOT IN",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                constant(2),
+                constant(4),
+            ],
+            expect: vec![
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(Some(false)),
+            ],
+        },
+        Test {
+            name: "in-long-list-passed",
+            display: "IN",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: [
+                vec![Series::new(vec![Some(1i64), Some(12), None, Some(3)]).into()],
+                long_list(3),
+            ]
+            .concat(),
+            expect: vec![
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(None),
+                DataValue::Boolean(Some(true)),
+            ],
+        },
+        Test {
+            name: "not-in-long-list-with-null-passed",
+            display: "NOT IN",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: [
+                vec![Series::new(vec![1i64, 12, 13, 3]).into()],
+                long_list(3),
+                vec![DataColumn::Constant(DataValue::Null, rows)],
+            ]
+            .concat(),
+            expect: vec![
+                DataValue::Boolean(None),
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(None),
+                DataValue::Boolean(Some(false)),
+            ],
+        },
+        Test {
+            name: "in-subquery-passed",
+            display: "IN",
+            func: ComparisonInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), Some(2), None, Some(4)]).into(),
+                subquery(vec![DataValue::Int64(Some(2)), DataValue::Int64(Some(4))]),
+            ],
+            expect: vec![
+                DataValue::Boolean(Some(false)),
+                DataValue::Boolean(Some(true)),
+                DataValue::Boolean(None),
+                DataValue::Boolean(Some(true)),
+            ],
+        },
+        Test {
+            name: "not-in-empty-subquery-passed",
+            display: "NOT IN",
+            func: ComparisonNotInFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), Some(2), None, Some(4)]).into(),
+                subquery(vec![]),
+            ],
+            expect: vec![DataValue::Boolean(Some(true)); 4],
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        // Display check.
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+
+        let v = func.eval(&columns, rows)?;
+        let args = t.columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        assert_eq!(func.return_type(&args)?, v.data_type(), "{}", t.name);
+        assert!(func.nullable(&DataSchema::empty())?, "{}", t.name);
+        assert_eq!(t.expect, v.to_values()?, "{}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_comparison_decimal() -> Result<()> {
+    // 1.50, -2.00, 3.25 as Decimal(5, 2)
+    let decimal = DataColumnWithField::new(
+        Series::new(vec![150i64, -200, 325]).into(),
+        DataField::new("a", DataType::Decimal(5, 2), false),
+    );
+
+    let tests = vec![
+        (
+            "eq-decimal-diff-scale-passed",
+            ComparisonEqFunction::try_create_func("")?,
+            DataColumnWithField::new(
+                Series::new(vec![1500i64, -2000, 3200]).into(),
+                DataField::new("b", DataType::Decimal(8, 3), false),
+            ),
+            vec![true, true, false],
+        ),
+        (
+            "gt-decimal-int-passed",
+            ComparisonGtFunction::try_create_func("")?,
+            DataColumnWithField::new(
+                Series::new(vec![1i32, -2, 3]).into(),
+                DataField::new("b", DataType::Int32, false),
+            ),
+            vec![true, false, true],
+        ),
+        (
+            "lt-decimal-float-passed",
+            ComparisonLtFunction::try_create_func("")?,
+            DataColumnWithField::new(
+                Series::new(vec![1.5f64, -1.5, 3.5]).into(),
+                DataField::new("b", DataType::Float64, false),
+            ),
+            vec![false, true, true],
+        ),
+    ];
+
+    for (name, func, column, expect) in tests {
+        let v = func.eval(&[decimal.clone(), column], 3)?;
+        assert_eq!(
+            v.to_array()?.bool()?.collect_values(),
+            expect.into_iter().map(Some).collect::<Vec<_>>(),
+            "{}",
+            name
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_comparison_in_decimal() -> Result<()> {
+    // 1.00, 0.01, 1.50 as Decimal(5, 2)
+    let decimal = DataColumnWithField::new(
+        Series::new(vec![100i64, 1, 150]).into(),
+        DataField::new("a", DataType::Decimal(5, 2), false),
+    );
+    let integer = |v: u8| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt8(Some(v)), 3),
+            DataField::new("b", DataType::UInt8, false),
+        )
+    };
+    let subquery = |values: Vec<DataValue>| {
+        let column = DataColumn::Constant(DataValue::List(Some(values), DataType::Int64), 3);
+        let field = DataField::new("b", column.data_type(), false);
+        DataColumnWithField::new(column, field)
+    };
+
+    let tests = vec![
+        (
+            "in-decimal-int-list-passed",
+            ComparisonInFunction::try_create_func("")?,
+            vec![decimal.clone(), integer(1), integer(2)],
+            vec![true, false, false],
+        ),
+        (
+            "in-decimal-decimal-list-passed",
+            ComparisonInFunction::try_create_func("")?,
+            vec![
+                decimal.clone(),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(15)), 3),
+                    DataField::new("b", DataType::Decimal(3, 1), false),
+                ),
+            ],
+            vec![false, false, true],
+        ),
+        (
+            "not-in-decimal-int-list-passed",
+            ComparisonNotInFunction::try_create_func("")?,
+            vec![decimal.clone(), integer(1)],
+            vec![false, true, true],
+        ),
+        (
+            "in-decimal-int-subquery-passed",
+            ComparisonInFunction::try_create_func("")?,
+            vec![decimal.clone(), subquery(vec![DataValue::Int64(Some(1))])],
+            vec![true, false, false],
+        ),
+    ];
+
+    for (name, func, columns, expect) in tests {
+        let v = func.eval(&columns, 3)?;
+        assert_eq!(
+            v.to_array()?.bool()?.collect_values(),
+            expect.into_iter().map(Some).collect::<Vec<_>>(),
+            "{}",
+            name
+        );
+    }
+    Ok(())
+}
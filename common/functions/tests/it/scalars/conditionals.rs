@@ -87,3 +87,176 @@ fn test_if_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_case_coalesce_nullif_functions() -> Result<()> {
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: Vec<DataValue>,
+    }
+
+    let rows = 4;
+    let tests = vec![
+        Test {
+            name: "case-passed",
+            display: "CASE",
+            func: CaseFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![true, false, false, true]).into(),
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                Series::new(vec![Some(true), Some(true), None, Some(false)]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(10)), rows),
+                DataColumn::Constant(DataValue::Null, rows),
+            ],
+            expect: vec![
+                DataValue::Int64(Some(1)),
+                DataValue::Int64(Some(10)),
+                DataValue::Int64(None),
+                DataValue::Int64(Some(4)),
+            ],
+        },
+        Test {
+            name: "coalesce-passed",
+            display: "COALESCE",
+            func: CoalesceFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i32), None, None, None]).into(),
+                Series::new(vec![Some(2i32), Some(3), None, None]).into(),
+                Series::new(vec![Some(5i32), Some(6), Some(7), None]).into(),
+            ],
+            expect: vec![
+                DataValue::Int32(Some(1)),
+                DataValue::Int32(Some(3)),
+                DataValue::Int32(Some(7)),
+                DataValue::Int32(None),
+            ],
+        },
+        Test {
+            name: "ifnull-passed",
+            display: "IFNULL",
+            func: IfNullFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![Some(1i32), None, Some(3), None]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(0)), rows),
+            ],
+            expect: vec![
+                DataValue::Int32(Some(1)),
+                DataValue::Int32(Some(0)),
+                DataValue::Int32(Some(3)),
+                DataValue::Int32(Some(0)),
+            ],
+        },
+        Test {
+            name: "nullif-passed",
+            display: "NULLIF",
+            func: NullIfFunction::try_create_func("")?,
+            columns: vec![
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(2)), rows),
+            ],
+            expect: vec![
+                DataValue::Int32(Some(1)),
+                DataValue::Int32(None),
+                DataValue::Int32(Some(3)),
+                DataValue::Int32(Some(4)),
+            ],
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        // Display check.
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+
+        let v = func.eval(&columns, rows)?;
+        let args = t.columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        assert_eq!(func.return_type(&args)?, v.data_type(), "{}", t.name);
+        assert_eq!(t.expect, v.to_values()?, "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_conditionals_decimal() -> Result<()> {
+    let rows = 3;
+    // 1.50, NULL, -2.00 as Decimal(10, 2)
+    let decimal = DataColumnWithField::new(
+        Series::new(vec![Some(150i64), None, Some(-200)]).into(),
+        DataField::new("d", DataType::Decimal(10, 2), true),
+    );
+    let one = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::UInt8(Some(1)), rows),
+        DataField::new("one", DataType::UInt8, false),
+    );
+    let null = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Null, rows),
+        DataField::new("null", DataType::Null, true),
+    );
+    let cond = DataColumnWithField::new(
+        Series::new(vec![true, false, false]).into(),
+        DataField::new("cond", DataType::Boolean, false),
+    );
+
+    // The results are the scaled values of Decimal(10, 2).
+    let tests = vec![
+        (
+            "coalesce-decimal-int-passed",
+            CoalesceFunction::try_create_func("")?,
+            vec![decimal.clone(), one.clone()],
+            vec![Some(150i64), Some(100), Some(-200)],
+        ),
+        (
+            "coalesce-decimal-null-passed",
+            CoalesceFunction::try_create_func("")?,
+            vec![decimal.clone(), null.clone()],
+            vec![Some(150i64), None, Some(-200)],
+        ),
+        (
+            "ifnull-decimal-int-passed",
+            IfNullFunction::try_create_func("")?,
+            vec![decimal.clone(), one.clone()],
+            vec![Some(150i64), Some(100), Some(-200)],
+        ),
+        (
+            "case-decimal-int-passed",
+            CaseFunction::try_create_func("")?,
+            vec![cond.clone(), decimal.clone(), one.clone()],
+            vec![Some(150i64), Some(100), Some(100)],
+        ),
+        (
+            "if-int-decimal-passed",
+            IfFunction::try_create_func("")?,
+            vec![cond.clone(), one.clone(), decimal.clone()],
+            vec![Some(100i64), None, Some(-200)],
+        ),
+    ];
+
+    for (name, func, columns, expect) in tests {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            func.return_type(&args)?,
+            DataType::Decimal(10, 2),
+            "{}",
+            name
+        );
+
+        let v = func.eval(&columns, rows)?;
+        assert_eq!(v.to_array()?.i64()?.collect_values(), expect, "{}", name);
+    }
+
+    Ok(())
+}
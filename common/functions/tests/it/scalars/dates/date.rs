@@ -50,6 +50,39 @@ fn test_round_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_round_function_with_timezone() -> Result<()> {
+    // 2021-09-05 03:26:06 UTC is 08:56:06 in Asia/Kolkata, which is UTC+05:30.
+    let func = RoundFunction::try_create("toStartOfHour", 60 * 60)?;
+
+    let tz = Some("Asia/Kolkata".to_string());
+    let field = DataField::new("a", DataType::DateTime32(tz.clone()), false);
+    let columns = vec![DataColumnWithField::new(
+        Series::new(vec![1630812366u32]).into(),
+        field,
+    )];
+    assert_eq!(
+        func.return_type(&[DataType::DateTime32(tz)])?,
+        DataType::DateTime32(Some("Asia/Kolkata".to_string()))
+    );
+    let expect: DataColumn = Series::new(vec![1630809000u32]).into();
+    assert_eq!(&func.eval(&columns, 1)?, &expect);
+
+    // The timezone argument takes precedence over the timezone of the type.
+    let field = DataField::new("a", DataType::DateTime64(3, None), false);
+    let tz_field = DataField::new("tz", DataType::String, false);
+    let columns = vec![
+        DataColumnWithField::new(Series::new(vec![1630812366123i64]).into(), field),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"Asia/Kolkata".to_vec())), 1),
+            tz_field,
+        ),
+    ];
+    let expect: DataColumn = Series::new(vec![1630809000000i64]).into();
+    assert_eq!(&func.eval(&columns, 1)?, &expect);
+    Ok(())
+}
+
 #[test]
 fn test_to_start_of_function() -> Result<()> {
     let test = Test {
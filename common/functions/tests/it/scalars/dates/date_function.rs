@@ -617,6 +617,39 @@ fn test_tohour_function() -> Result<()> {
             expect: Series::new(vec![10u8]).into(),
             error: "",
         },
+        Test {
+            name: "test_tohour_datetime_with_timezone",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToHourFunction::try_create("d")?,
+            columns: vec![Series::new(vec![1634551542u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![18u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_tohour_datetime_timezone_argument",
+            display: "a()",
+            arg_names: vec!["a", "tz"],
+            func: ToHourFunction::try_create("a")?,
+            columns: vec![
+                Series::new(vec![1634551542u32]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"Asia/Shanghai".to_vec())), 1),
+            ],
+            nullable: false,
+            expect: Series::new(vec![18u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_tohour_datetime64",
+            display: "e()",
+            arg_names: vec!["e"],
+            func: ToHourFunction::try_create("e")?,
+            columns: vec![Series::new(vec![1634551542123i64]).into()],
+            nullable: false,
+            expect: Series::new(vec![10u8]).into(),
+            error: "",
+        },
     ];
 
     do_test(tests)
@@ -852,6 +885,13 @@ fn do_test(tests: Vec<Test>) -> Result<()> {
         DataField::new("a", DataType::DateTime32(None), false),
         DataField::new("b", DataType::Date32, false),
         DataField::new("c", DataType::Date16, false),
+        DataField::new(
+            "d",
+            DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            false,
+        ),
+        DataField::new("e", DataType::DateTime64(3, None), false),
+        DataField::new("tz", DataType::String, false),
     ]);
 
     for t in tests {
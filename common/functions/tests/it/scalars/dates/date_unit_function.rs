@@ -0,0 +1,163 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueArithmeticOperator;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+fn unit_column(unit: &str) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(unit.as_bytes().to_vec())), 1),
+        DataField::new("unit", DataType::String, false),
+    )
+}
+
+// 2021-09-05 03:26:06 UTC, it's Sunday.
+fn datetime_column() -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(vec![1630812366u32]).into(),
+        DataField::new("a", DataType::DateTime32(None), false),
+    )
+}
+
+fn date16_column(days: u16) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(vec![days]).into(),
+        DataField::new("b", DataType::Date16, false),
+    )
+}
+
+#[test]
+fn test_date_trunc_function() -> Result<()> {
+    let func = DateTruncFunction::try_create("date_trunc")?;
+    let tests = vec![
+        ("second", 1630812366u32),
+        ("minute", 1630812360),
+        ("hour", 1630810800),
+        ("day", 1630800000),
+        // Monday, 2021-08-30
+        ("week", 1630281600),
+        ("month", 1630454400),
+        ("quarter", 1625097600),
+        ("year", 1609459200),
+    ];
+    for (unit, expect) in tests {
+        let columns = vec![unit_column(unit), datetime_column()];
+        let expect: DataColumn = Series::new(vec![expect]).into();
+        assert_eq!(
+            func.eval(&columns, 1)?.to_array()?,
+            expect.to_array()?,
+            "{}",
+            unit
+        );
+    }
+
+    // The day starts at the midnight of the timezone.
+    let columns = vec![
+        unit_column("day"),
+        datetime_column(),
+        unit_column("Asia/Shanghai"),
+    ];
+    let expect: DataColumn = Series::new(vec![1630771200u32]).into();
+    assert_eq!(&func.eval(&columns, 1)?, &expect);
+
+    // The date type is kept.
+    let columns = vec![unit_column("month"), date16_column(18875)];
+    assert_eq!(
+        func.return_type(&[DataType::String, DataType::Date16])?,
+        DataType::Date16
+    );
+    let expect: DataColumn = Series::new(vec![18871u16]).into();
+    assert_eq!(func.eval(&columns, 1)?.to_array()?, expect.to_array()?);
+
+    let columns = vec![unit_column("fortnight"), datetime_column()];
+    assert!(func.eval(&columns, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_diff_function() -> Result<()> {
+    let func = DateDiffFunction::try_create("date_diff")?;
+    // 2021-01-31 and 2021-02-01
+    let tests = vec![
+        ("day", 18658u16, 18659u16, 1i64),
+        ("month", 18658, 18659, 1),
+        ("year", 18658, 18659, 0),
+        ("day", 18659, 18658, -1),
+        // 2021-09-05 is Sunday and 2021-09-06 is Monday
+        ("week", 18875, 18876, 1),
+        ("week", 18876, 18882, 0),
+        ("hour", 18875, 18876, 24),
+    ];
+    for (unit, start, end, expect) in tests {
+        let columns = vec![unit_column(unit), date16_column(start), date16_column(end)];
+        let expect: DataColumn = Series::new(vec![expect]).into();
+        assert_eq!(&func.eval(&columns, 1)?, &expect, "{}", unit);
+    }
+
+    // The datetime is compared with the date.
+    let columns = vec![
+        unit_column("second"),
+        date16_column(18875),
+        datetime_column(),
+    ];
+    let expect: DataColumn = Series::new(vec![12366i64]).into();
+    assert_eq!(&func.eval(&columns, 1)?, &expect);
+    Ok(())
+}
+
+#[test]
+fn test_date_add_function() -> Result<()> {
+    let add = DateAddFunction::try_create("date_add", DataValueArithmeticOperator::Plus)?;
+    let sub = DateAddFunction::try_create("date_sub", DataValueArithmeticOperator::Minus)?;
+    let n = |v: i64| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int64(Some(v)), 1),
+            DataField::new("n", DataType::Int64, false),
+        )
+    };
+
+    let columns = vec![unit_column("day"), n(2), datetime_column()];
+    assert_eq!(
+        add.return_type(&[
+            DataType::String,
+            DataType::Int64,
+            DataType::DateTime32(None)
+        ])?,
+        DataType::DateTime32(None)
+    );
+    let expect: DataColumn = Series::new(vec![1630812366u32 + 2 * 24 * 3600]).into();
+    assert_eq!(add.eval(&columns, 1)?.to_array()?, expect.to_array()?);
+
+    let columns = vec![unit_column("hour"), n(1), datetime_column()];
+    let expect: DataColumn = Series::new(vec![1630812366u32 - 3600]).into();
+    assert_eq!(sub.eval(&columns, 1)?.to_array()?, expect.to_array()?);
+
+    // 2021-01-31 + 1 month is 2021-02-28
+    let columns = vec![unit_column("month"), n(1), date16_column(18658)];
+    let expect: DataColumn = Series::new(vec![18686u16]).into();
+    assert_eq!(add.eval(&columns, 1)?.to_array()?, expect.to_array()?);
+
+    // DATE_ADD(datetime, INTERVAL 1 DAY)
+    let interval = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Int64(Some(24 * 3600 * 1000)), 1),
+        DataField::new("i", DataType::Interval(IntervalUnit::DayTime), false),
+    );
+    let columns = vec![datetime_column(), interval];
+    let expect: DataColumn = Series::new(vec![1630812366u32 + 24 * 3600]).into();
+    assert_eq!(add.eval(&columns, 1)?.to_array()?, expect.to_array()?);
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+fn string_column(name: &str, value: &str) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), 1),
+        DataField::new(name, DataType::String, false),
+    )
+}
+
+#[test]
+fn test_format_datetime_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        style: DateFormatStyle,
+        columns: Vec<DataColumnWithField>,
+        expect: &'static str,
+    }
+
+    // 2021-09-05 03:26:06 UTC, it's Sunday.
+    let datetime = DataColumnWithField::new(
+        Series::new(vec![1630812366u32]).into(),
+        DataField::new("a", DataType::DateTime32(None), false),
+    );
+    let datetime64 = DataColumnWithField::new(
+        Series::new(vec![1630812366123i64]).into(),
+        DataField::new("b", DataType::DateTime64(3, None), false),
+    );
+    let date16 = DataColumnWithField::new(
+        Series::new(vec![18875u16]).into(),
+        DataField::new("c", DataType::Date16, false),
+    );
+
+    let tests = vec![
+        Test {
+            name: "strftime",
+            style: DateFormatStyle::Strftime,
+            columns: vec![datetime.clone(), string_column("f", "%Y-%m-%d %H:%M:%S")],
+            expect: "2021-09-05 03:26:06",
+        },
+        Test {
+            name: "strftime-with-timezone",
+            style: DateFormatStyle::Strftime,
+            columns: vec![
+                datetime.clone(),
+                string_column("f", "%F %T %z"),
+                string_column("tz", "Asia/Shanghai"),
+            ],
+            expect: "2021-09-05 11:26:06 +0800",
+        },
+        Test {
+            name: "strftime-date16",
+            style: DateFormatStyle::Strftime,
+            columns: vec![date16, string_column("f", "%Y%m%d")],
+            expect: "20210905",
+        },
+        Test {
+            name: "mysql",
+            style: DateFormatStyle::MySQL,
+            columns: vec![datetime, string_column("f", "%W %M %D %Y %h:%i:%s %p %%")],
+            expect: "Sunday September 5th 2021 03:26:06 AM %",
+        },
+        Test {
+            name: "mysql-datetime64",
+            style: DateFormatStyle::MySQL,
+            columns: vec![datetime64, string_column("f", "%Y-%m-%d %T.%f")],
+            expect: "2021-09-05 03:26:06.123000",
+        },
+    ];
+
+    for t in tests {
+        let func = FormatDateTimeFunction::try_create("formatDateTime", t.style)?;
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type().clone()).collect();
+        assert_eq!(func.return_type(&args)?, DataType::String, "{}", t.name);
+
+        let result = func.eval(&t.columns, 1)?;
+        let expect: DataColumn = Series::new(vec![t.expect]).into();
+        assert_eq!(result, expect, "{}", t.name);
+    }
+
+    // Invalid format
+    let func = FormatDateTimeFunction::try_create("formatDateTime", DateFormatStyle::MySQL)?;
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![1630812366u32]).into(),
+            DataField::new("a", DataType::DateTime32(None), false),
+        ),
+        string_column("f", "%X"),
+    ];
+    let result = func.eval(&columns, 1);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "The format specifier %X is not supported"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_datetime_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        style: DateFormatStyle,
+        format: &'static str,
+        timezone: Option<&'static str>,
+        values: Vec<&'static str>,
+        expect: Vec<Option<u32>>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "strftime",
+            style: DateFormatStyle::Strftime,
+            format: "%Y-%m-%d %H:%M:%S",
+            timezone: None,
+            values: vec!["2021-09-05 03:26:06", "2021-09-05"],
+            expect: vec![Some(1630812366), None],
+        },
+        Test {
+            name: "mysql-date-only",
+            style: DateFormatStyle::MySQL,
+            format: "%Y-%m-%d",
+            timezone: None,
+            values: vec!["2021-09-05", "abc"],
+            expect: vec![Some(1630800000), None],
+        },
+        Test {
+            name: "mysql-with-timezone",
+            style: DateFormatStyle::MySQL,
+            format: "%d/%m/%Y %H:%i",
+            timezone: Some("Asia/Shanghai"),
+            values: vec!["05/09/2021 11:26"],
+            expect: vec![Some(1630812360)],
+        },
+        Test {
+            name: "mysql-12-hour",
+            style: DateFormatStyle::MySQL,
+            format: "%M %d %Y %r",
+            timezone: None,
+            values: vec!["September 05 2021 03:26:06 PM"],
+            expect: vec![Some(1630855566)],
+        },
+    ];
+
+    for t in tests {
+        let func = ParseDateTimeFunction::try_create("parseDateTime", t.style)?;
+        let mut columns = vec![
+            DataColumnWithField::new(
+                Series::new(t.values.clone()).into(),
+                DataField::new("s", DataType::String, false),
+            ),
+            string_column("f", t.format),
+        ];
+        if let Some(timezone) = t.timezone {
+            columns.push(string_column("tz", timezone));
+        }
+        let args: Vec<DataType> = columns.iter().map(|c| c.data_type().clone()).collect();
+        assert_eq!(
+            func.return_type(&args)?,
+            DataType::DateTime32(None),
+            "{}",
+            t.name
+        );
+
+        let result = func.eval(&columns, t.values.len())?;
+        let expect: DataColumn = Series::new(t.expect).into();
+        assert_eq!(result, expect, "{}", t.name);
+    }
+    Ok(())
+}
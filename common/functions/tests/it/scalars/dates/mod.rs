@@ -14,4 +14,6 @@
 
 mod date;
 mod date_function;
+mod date_unit_function;
+mod format_function;
 mod interval_function;
@@ -132,6 +132,141 @@ fn test_cast_function() -> Result<()> {
             expect: Series::new(vec!["2021-03-05 01:01:01", "2021-10-24 10:10:10"]),
             error: "",
         },
+        Test {
+            name: "cast-variant-to-int64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["1", "\"2\"", "true", "[1]", "2.5"]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::Int64),
+            expect: Series::new(vec![Some(1i64), Some(2), Some(1), None, Some(2)]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["\"x\"", "1", "{\"a\":null}"]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["x", "1", "{\"a\":null}"]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-variant-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["{ \"a\": [1, 2] }", " \"x\""]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Variant),
+            expect: Series::new(vec!["{\"a\":[1,2]}", "\"x\""]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["12.345", "-0.5", "7"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(6, 2)),
+            expect: Series::new(vec![1235i64, -50, 700]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1235i64, -50, 700]).into()],
+            column_types: vec![DataType::Decimal(6, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["12.35", "-0.50", "7.00"]),
+            error: "",
+        },
+        Test {
+            name: "cast-float64-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1.005f64, -2.5, 0.0]).into()],
+            column_types: vec![DataType::Float64],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(4, 1)),
+            expect: Series::new(vec![10i64, -25, 0]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-float64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1235i64, -50]).into()],
+            column_types: vec![DataType::Decimal(6, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::Float64),
+            expect: Series::new(vec![12.35f64, -0.5]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1235i64, -55]).into()],
+            column_types: vec![DataType::Decimal(6, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(5, 1)),
+            expect: Series::new(vec![124i64, -6]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-datetime32-with-timezone-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["2021-03-05 09:01:01"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create(
+                "cast".to_string(),
+                DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            ),
+            expect: Series::new(vec![1614906061u32]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-datetime64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![
+                Series::new(vec!["2021-03-05 01:01:01.123", "2021-10-24 10:10:10"]).into(),
+            ],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime64(3, None)),
+            expect: Series::new(vec![1614906061123i64, 1635070210000]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1614906061123i64]).into()],
+            column_types: vec![DataType::DateTime64(3, Some("Asia/Shanghai".to_string()))],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["2021-03-05 09:01:01.123"]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime32-to-datetime64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1614906061u32]).into()],
+            column_types: vec![DataType::DateTime32(None)],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime64(6, None)),
+            expect: Series::new(vec![1614906061000000i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-datetime32-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![1614906061999i64]).into()],
+            column_types: vec![DataType::DateTime64(3, None)],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime32(None)),
+            expect: Series::new(vec![1614906061u32]),
+            error: "",
+        },
     ];
 
     for t in tests {
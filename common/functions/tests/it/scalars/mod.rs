@@ -23,5 +23,7 @@ mod logics;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
+mod strings;
 mod tuples;
 mod udfs;
@@ -0,0 +1,179 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_semi_structured_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<(DataColumn, DataType)>,
+        expect_type: DataType,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let variant = |values: Vec<Option<&str>>| -> (DataColumn, DataType) {
+        (Series::new(values).into(), DataType::Variant)
+    };
+    let path = |path: &str, rows: usize| {
+        let column = DataColumn::Constant(DataValue::String(Some(path.as_bytes().to_vec())), rows);
+        (column, DataType::String)
+    };
+
+    let tests = vec![
+        Test {
+            name: "parse-json-passed",
+            func: ParseJsonFunction::try_create("parse_json")?,
+            columns: vec![(
+                Series::new(vec![Some("{ \"a\": 1 }"), Some(" [1, \"x\"] "), None]).into(),
+                DataType::String,
+            )],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec![Some("{\"a\":1}"), Some("[1,\"x\"]"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "parse-json-invalid-failed",
+            func: ParseJsonFunction::try_create("parse_json")?,
+            columns: vec![(Series::new(vec!["{a"]).into(), DataType::String)],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec![""]).into(),
+            error: "Unable to parse {a as JSON text",
+        },
+        Test {
+            name: "get-path-passed",
+            func: GetFunction::try_create("get")?,
+            columns: vec![
+                variant(vec![
+                    Some("{\"a\":{\"b\":[10,{\"c\":\"x\"}]}}"),
+                    Some("{\"a\":1}"),
+                    None,
+                ]),
+                path("a.b[1]", 3),
+            ],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec![Some("{\"c\":\"x\"}"), None, None]).into(),
+            error: "",
+        },
+        Test {
+            name: "get-quoted-key-passed",
+            func: GetFunction::try_create("get")?,
+            columns: vec![
+                variant(vec![Some("{\"a b\":[true]}")]),
+                path("[\"a b\"][0]", 1),
+            ],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec!["true"]).into(),
+            error: "",
+        },
+        Test {
+            name: "get-index-passed",
+            func: GetFunction::try_create("get")?,
+            columns: vec![
+                variant(vec![Some("[1,\"x\"]"), Some("[1]")]),
+                (Series::new(vec![1_i64, 1]).into(), DataType::Int64),
+            ],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec![Some("\"x\""), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "get-invalid-path-failed",
+            func: GetFunction::try_create("get")?,
+            columns: vec![variant(vec![Some("{}")]), path("a..b", 1)],
+            expect_type: DataType::Variant,
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Invalid variant path: a..b.",
+        },
+        Test {
+            name: "json-extract-path-text-passed",
+            func: JsonExtractPathTextFunction::try_create("json_extract_path_text")?,
+            columns: vec![
+                (
+                    Series::new(vec!["{\"a\":{\"b\":\"x y\"}}", "{\"a\":{\"b\":[1]}}", "{a"])
+                        .into(),
+                    DataType::String,
+                ),
+                path("a.b", 3),
+            ],
+            expect_type: DataType::String,
+            expect: Series::new(vec![Some("x y"), Some("[1]"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "json-typeof-passed",
+            func: JsonTypeofFunction::try_create("json_typeof")?,
+            columns: vec![variant(vec![
+                Some("null"),
+                Some("true"),
+                Some("1.5"),
+                Some("\"x\""),
+                Some("[]"),
+                Some("{}"),
+                None,
+            ])],
+            expect_type: DataType::String,
+            expect: Series::new(vec![
+                Some("null"),
+                Some("boolean"),
+                Some("number"),
+                Some("string"),
+                Some("array"),
+                Some("object"),
+                None,
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "json-typeof-wrong-type-failed",
+            func: JsonTypeofFunction::try_create("json_typeof")?,
+            columns: vec![(Series::new(vec![1_i64]).into(), DataType::Int64)],
+            expect_type: DataType::String,
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 7, displayText = Expected variant or string type, but got Int64.",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let rows = t.columns[0].0.len();
+        let args: Vec<DataType> = t.columns.iter().map(|(_, ty)| ty.clone()).collect();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|(c, ty)| {
+                DataColumnWithField::new(c.clone(), DataField::new("v", ty.clone(), true))
+            })
+            .collect();
+
+        match func
+            .return_type(&args)
+            .and_then(|_| func.eval(&columns, rows))
+        {
+            Ok(v) => {
+                assert_eq!(t.error, "", "case: {}", t.name);
+                assert_eq!(func.return_type(&args)?, t.expect_type, "case: {}", t.name);
+                assert_eq!(&v, &t.expect, "case: {}", t.name);
+            }
+            Err(e) => assert!(e.to_string().contains(t.error), "case: {}, {}", t.name, e),
+        }
+    }
+    Ok(())
+}
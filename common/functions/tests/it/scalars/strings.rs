@@ -14,11 +14,9 @@
 
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_functions::scalars::*;
 use pretty_assertions::assert_eq;
 
-use common_functions::scalars::Function;
-use common_functions::scalars::SubstringFunction;
-
 #[test]
 fn test_substring_function() -> Result<()> {
     #[allow(dead_code)]
@@ -143,3 +141,536 @@ fn test_substring_function() -> Result<()> {
     }
     Ok(())
 }
+
+struct StringTest {
+    name: &'static str,
+    func: Box<dyn Function>,
+    columns: Vec<DataColumn>,
+    expect: DataColumn,
+    error: &'static str,
+}
+
+fn test_string_functions(tests: Vec<StringTest>) -> Result<()> {
+    for t in tests {
+        let func = t.func;
+        let rows = t.columns[0].len();
+
+        let fields: Vec<DataField> = t
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| DataField::new(&format!("arg{}", i), c.data_type(), true))
+            .collect();
+        let args: Vec<DataType> = fields.iter().map(|f| f.data_type().clone()).collect();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .zip(fields.iter())
+            .map(|(c, f)| DataColumnWithField::new(c.clone(), f.clone()))
+            .collect();
+
+        match func
+            .return_type(&args)
+            .and_then(|_| func.eval(&columns, rows))
+        {
+            Ok(v) => {
+                assert_eq!(t.error, "", "case: {}", t.name);
+
+                // Type check.
+                let expect_type = func.return_type(&args)?;
+                let actual_type = v.data_type();
+                assert_eq!(expect_type, actual_type, "case: {}", t.name);
+                assert_eq!(&v, &t.expect, "case: {}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "case: {}", t.name),
+        }
+    }
+    Ok(())
+}
+
+fn constant_string(value: &str, rows: usize) -> DataColumn {
+    DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), rows)
+}
+
+#[test]
+fn test_length_function() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "length-passed",
+            func: LengthFunction::try_create("length")?,
+            columns: vec![Series::new(vec![Some("abc"), Some(""), None, Some("数据")]).into()],
+            expect: Series::new(vec![Some(3_u64), Some(0), None, Some(6)]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "length-constant-passed",
+            func: LengthFunction::try_create("length")?,
+            columns: vec![constant_string("abcd", 3)],
+            expect: DataColumn::Constant(DataValue::UInt64(Some(4)), 3),
+            error: "",
+        },
+        StringTest {
+            name: "length-number-passed",
+            func: LengthFunction::try_create("length")?,
+            columns: vec![Series::new(vec![123_i64, -1]).into()],
+            expect: Series::new(vec![3_u64, 2]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "length-boolean-failed",
+            func: LengthFunction::try_create("length")?,
+            columns: vec![Series::new(vec![true]).into()],
+            expect: Series::new(vec![0_u64]).into(),
+            error: "Code: 7, displayText = Expected string or numeric type, but got Boolean.",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_string_unary_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "lower-passed",
+            func: LowerFunction::try_create("lower")?,
+            columns: vec![Series::new(vec![Some("AbC"), None, Some("123-X")]).into()],
+            expect: Series::new(vec![Some("abc"), None, Some("123-x")]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "upper-passed",
+            func: UpperFunction::try_create("upper")?,
+            columns: vec![Series::new(vec![Some("AbC"), None, Some("123-x")]).into()],
+            expect: Series::new(vec![Some("ABC"), None, Some("123-X")]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "trim-passed",
+            func: TrimFunction::try_create("trim")?,
+            columns: vec![Series::new(vec![Some("  a b  "), Some("   "), None]).into()],
+            expect: Series::new(vec![Some("a b"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "ltrim-passed",
+            func: LTrimFunction::try_create("ltrim")?,
+            columns: vec![Series::new(vec![Some("  a b  "), Some("   "), None]).into()],
+            expect: Series::new(vec![Some("a b  "), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "rtrim-passed",
+            func: RTrimFunction::try_create("rtrim")?,
+            columns: vec![Series::new(vec![Some("  a b  "), Some("   "), None]).into()],
+            expect: Series::new(vec![Some("  a b"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "reverse-passed",
+            func: ReverseFunction::try_create("reverse")?,
+            columns: vec![Series::new(vec![Some("abc"), Some(""), None]).into()],
+            expect: Series::new(vec![Some("cba"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "reverse-constant-passed",
+            func: ReverseFunction::try_create("reverse")?,
+            columns: vec![constant_string("abc", 2)],
+            expect: constant_string("cba", 2),
+            error: "",
+        },
+        StringTest {
+            name: "unhex-passed",
+            func: UnhexFunction::try_create("unhex")?,
+            columns: vec![Series::new(vec![Some("616263"), Some("F"), Some("4g"), None]).into()],
+            expect: Series::new(vec![Some(&b"abc"[..]), Some(&b"\x0f"[..]), None, None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "base64Encode-passed",
+            func: Base64EncodeFunction::try_create("base64Encode")?,
+            columns: vec![Series::new(vec![Some("databend"), Some(""), None]).into()],
+            expect: Series::new(vec![Some("ZGF0YWJlbmQ="), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "base64Decode-passed",
+            func: Base64DecodeFunction::try_create("base64Decode")?,
+            columns: vec![Series::new(vec![Some("ZGF0YWJlbmQ="), Some("!!"), None]).into()],
+            expect: Series::new(vec![Some("databend"), None, None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_concat_function() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "concat-passed",
+            func: ConcatFunction::try_create("concat")?,
+            columns: vec![
+                Series::new(vec![Some("a"), Some("b"), None]).into(),
+                constant_string("-", 3),
+                Series::new(vec![Some(1_i64), None, Some(3)]).into(),
+            ],
+            expect: Series::new(vec![Some("a-1"), None, None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "concat-constant-passed",
+            func: ConcatFunction::try_create("concat")?,
+            columns: vec![constant_string("data", 2), constant_string("bend", 2)],
+            expect: constant_string("databend", 2),
+            error: "",
+        },
+        StringTest {
+            name: "concat_ws-passed",
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            columns: vec![
+                Series::new(vec![Some(","), Some(","), None]).into(),
+                Series::new(vec![Some("a"), None, Some("a")]).into(),
+                Series::new(vec![Some("b"), Some("b"), Some("b")]).into(),
+            ],
+            expect: Series::new(vec![Some("a,b"), Some("b"), None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_replace_repeat_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "replace-passed",
+            func: ReplaceFunction::try_create("replace")?,
+            columns: vec![
+                Series::new(vec![Some("abcabc"), Some("abc"), Some("abc"), None]).into(),
+                Series::new(vec![Some("b"), Some(""), None, Some("b")]).into(),
+                constant_string("XY", 4),
+            ],
+            expect: Series::new(vec![Some("aXYcaXYc"), Some("abc"), None, None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "repeat-passed",
+            func: RepeatFunction::try_create("repeat")?,
+            columns: vec![
+                Series::new(vec![Some("ab"), Some("ab"), Some("ab"), None]).into(),
+                Series::new(vec![Some(3_i32), Some(0), None, Some(2)]).into(),
+            ],
+            expect: Series::new(vec![Some("ababab"), Some(""), None, None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "repeat-too-many-times-failed",
+            func: RepeatFunction::try_create("repeat")?,
+            columns: vec![
+                Series::new(vec!["ab"]).into(),
+                Series::new(vec![u32::MAX]).into(),
+            ],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Too many times to repeat: 4294967295, the result must not exceed 1073741824 bytes.",
+        },
+        StringTest {
+            name: "repeat-string-times-failed",
+            func: RepeatFunction::try_create("repeat")?,
+            columns: vec![Series::new(vec!["ab"]).into(), Series::new(vec!["3"]).into()],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 7, displayText = Expected integer type, but got String.",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_pad_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "lpad-passed",
+            func: LeftPadFunction::try_create("lpad")?,
+            columns: vec![
+                Series::new(vec![Some("hi"), Some("hello"), Some("hi"), None]).into(),
+                Series::new(vec![Some(5_i64), Some(3), Some(4), Some(4)]).into(),
+                Series::new(vec![Some("ab"), Some("ab"), Some(""), Some("ab")]).into(),
+            ],
+            expect: Series::new(vec![Some("abahi"), Some("hel"), Some("hi"), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "rpad-passed",
+            func: RightPadFunction::try_create("rpad")?,
+            columns: vec![
+                Series::new(vec![Some("hi"), Some("hello"), None]).into(),
+                Series::new(vec![Some(5_i64), Some(3), Some(4)]).into(),
+                constant_string("ab", 3),
+            ],
+            expect: Series::new(vec![Some("hiaba"), Some("hel"), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "rpad-default-pad-passed",
+            func: RightPadFunction::try_create("rpad")?,
+            columns: vec![
+                Series::new(vec![Some("hi"), Some("hi")]).into(),
+                Series::new(vec![Some(4_u8), None]).into(),
+            ],
+            expect: Series::new(vec![Some("hi  "), None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_locate_function() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "locate-passed",
+            func: LocateFunction::try_create("locate")?,
+            columns: vec![
+                Series::new(vec![Some("bar"), Some("xbar"), Some(""), None]).into(),
+                constant_string("foobarbar", 4),
+            ],
+            expect: Series::new(vec![Some(4_u64), Some(0), Some(1), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "locate-with-pos-passed",
+            func: LocateFunction::try_create("locate")?,
+            columns: vec![
+                constant_string("bar", 4),
+                constant_string("foobarbar", 4),
+                Series::new(vec![Some(5_i64), Some(0), Some(10), None]).into(),
+            ],
+            expect: Series::new(vec![Some(7_u64), Some(0), Some(0), None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_left_right_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "left-passed",
+            func: LeftFunction::try_create("left")?,
+            columns: vec![
+                Series::new(vec![Some("foobar"), Some("foobar"), Some("foobar"), None]).into(),
+                Series::new(vec![Some(3_i64), Some(10), Some(-1), Some(1)]).into(),
+            ],
+            expect: Series::new(vec![Some("foo"), Some("foobar"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "right-passed",
+            func: RightFunction::try_create("right")?,
+            columns: vec![
+                Series::new(vec![Some("foobar"), Some("foobar"), Some("foobar")]).into(),
+                Series::new(vec![Some(3_i64), Some(10), None]).into(),
+            ],
+            expect: Series::new(vec![Some("bar"), Some("foobar"), None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_split_part_function() -> Result<()> {
+    let tests = vec![StringTest {
+        name: "split_part-passed",
+        func: SplitPartFunction::try_create("split_part")?,
+        columns: vec![
+            constant_string("a,,b,c", 5),
+            Series::new(vec![Some(","), Some(","), Some(","), Some(""), None]).into(),
+            Series::new(vec![Some(1_i64), Some(3), Some(5), Some(1), Some(1)]).into(),
+        ],
+        expect: Series::new(vec![Some("a"), Some("b"), Some(""), Some("a,,b,c"), None]).into(),
+        error: "",
+    }];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_starts_ends_with_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "startsWith-passed",
+            func: StartsWithFunction::try_create("startsWith")?,
+            columns: vec![
+                Series::new(vec![Some("databend"), Some("databend"), None]).into(),
+                Series::new(vec![Some("data"), Some("bend"), Some("data")]).into(),
+            ],
+            expect: Series::new(vec![Some(true), Some(false), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "endsWith-passed",
+            func: EndsWithFunction::try_create("endsWith")?,
+            columns: vec![
+                Series::new(vec![Some("databend"), Some("databend"), Some("databend")]).into(),
+                Series::new(vec![Some("data"), Some("bend"), None]).into(),
+            ],
+            expect: Series::new(vec![Some(false), Some(true), None]).into(),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_format_function() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "format-passed",
+            func: FormatFunction::try_create("format")?,
+            columns: vec![
+                constant_string("{} {{{1}}} {}", 3),
+                Series::new(vec![Some("a"), Some("b"), None]).into(),
+                Series::new(vec![Some(1_i64), Some(2), Some(3)]).into(),
+            ],
+            expect: Series::new(vec![Some("a {1} 1"), Some("b {2} 2"), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "format-out-of-arguments-failed",
+            func: FormatFunction::try_create("format")?,
+            columns: vec![constant_string("{2}", 1), constant_string("a", 1)],
+            expect: Series::new(vec![""]).into(),
+            error:
+                "Code: 6, displayText = Placeholder 2 is out of the 1 arguments of function format.",
+        },
+        StringTest {
+            name: "format-unclosed-failed",
+            func: FormatFunction::try_create("format")?,
+            columns: vec![constant_string("{", 1)],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Unclosed '{' in the pattern of function format.",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_hex_function() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "hex-string-passed",
+            func: HexFunction::try_create("hex")?,
+            columns: vec![Series::new(vec![Some("abc"), Some(""), None]).into()],
+            expect: Series::new(vec![Some("616263"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "hex-integer-passed",
+            func: HexFunction::try_create("hex")?,
+            columns: vec![Series::new(vec![Some(255_i32), Some(-1), None]).into()],
+            expect: Series::new(vec![Some("FF"), Some("FFFFFFFFFFFFFFFF"), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "hex-unsigned-passed",
+            func: HexFunction::try_create("hex")?,
+            columns: vec![Series::new(vec![u64::MAX]).into()],
+            expect: Series::new(vec!["FFFFFFFFFFFFFFFF"]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "hex-float-failed",
+            func: HexFunction::try_create("hex")?,
+            columns: vec![Series::new(vec![1.5_f64]).into()],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 7, displayText = Expected string or integer type, but got Float64.",
+        },
+    ];
+    test_string_functions(tests)
+}
+
+#[test]
+fn test_regexp_functions() -> Result<()> {
+    let tests = vec![
+        StringTest {
+            name: "regexp-replace-passed",
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            columns: vec![
+                Series::new(vec![Some("a1b22"), Some("xyz"), None]).into(),
+                constant_string("([0-9]+)", 3),
+                constant_string("<$1>", 3),
+            ],
+            expect: Series::new(vec![Some("a<1>b<22>"), Some("xyz"), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "regexp-extract-default-group-passed",
+            func: RegexpExtractFunction::try_create("regexp_extract")?,
+            columns: vec![
+                Series::new(vec![Some("id=12,id=34"), Some("none"), None]).into(),
+                constant_string("id=([0-9]+)", 3),
+            ],
+            expect: Series::new(vec![Some("12"), Some(""), None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "regexp-extract-group-passed",
+            func: RegexpExtractFunction::try_create("regexp_extract")?,
+            columns: vec![
+                Series::new(vec!["k1=v1", "k2=v2"]).into(),
+                constant_string("(\\w+)=(\\w+)", 2),
+                Series::new(vec![0_i64, 2]).into(),
+            ],
+            expect: Series::new(vec!["k1=v1", "v2"]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "regexp-extract-group-failed",
+            func: RegexpExtractFunction::try_create("regexp_extract")?,
+            columns: vec![
+                constant_string("abc", 1),
+                constant_string("(b)", 1),
+                Series::new(vec![2_i64]).into(),
+            ],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Group 2 is out of the 1 captured groups of the pattern (b).",
+        },
+        StringTest {
+            name: "regexp-substr-passed",
+            func: RegexpSubstrFunction::try_create("regexp_substr")?,
+            columns: vec![
+                Series::new(vec![Some("ab ac ad"), Some("ab ac ad"), Some("ab"), None]).into(),
+                constant_string("a.", 4),
+                Series::new(vec![1_i64, 2, 1, 1]).into(),
+                Series::new(vec![3_i64, 1, 2, 1]).into(),
+            ],
+            expect: Series::new(vec![Some("ad"), Some("ac"), None, None]).into(),
+            error: "",
+        },
+        StringTest {
+            name: "regexp-invalid-pattern-failed",
+            func: RegexpSubstrFunction::try_create("regexp_substr")?,
+            columns: vec![constant_string("abc", 1), constant_string("(", 1)],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Unable to build regex from the pattern (: regex parse error:\n    (\n    ^\nerror: unclosed group.",
+        },
+        StringTest {
+            name: "extract-all-passed",
+            func: ExtractAllFunction::try_create("extractAll")?,
+            columns: vec![constant_string("a=1, b=22", 1), constant_string("=([0-9]+)", 1)],
+            expect: DataColumn::Constant(
+                DataValue::List(
+                    Some(vec![
+                        DataValue::String(Some(b"1".to_vec())),
+                        DataValue::String(Some(b"22".to_vec())),
+                    ]),
+                    DataType::String,
+                ),
+                1,
+            ),
+            error: "",
+        },
+    ];
+    test_string_functions(tests)
+}
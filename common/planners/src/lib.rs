@@ -16,8 +16,10 @@ mod plan_aggregator_final;
 mod plan_aggregator_partial;
 mod plan_broadcast;
 mod plan_builder;
+mod plan_cte_scan;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_delete;
 mod plan_describe_table;
 mod plan_display;
 mod plan_display_indent;
@@ -39,10 +41,12 @@ mod plan_filter;
 mod plan_grant_privilege;
 mod plan_having;
 mod plan_insert_into;
+mod plan_join;
 mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
 mod plan_node;
+mod plan_optimize_table;
 mod plan_partition;
 mod plan_projection;
 mod plan_read_datasource;
@@ -58,18 +62,23 @@ mod plan_subqueries_set;
 mod plan_table_create;
 mod plan_table_drop;
 mod plan_truncate_table;
+mod plan_union;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
+mod plan_vacuum_table;
 mod plan_visitor;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
+pub use plan_cte_scan::CteScanPlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
@@ -80,11 +89,13 @@ pub use plan_expression::Expressions;
 pub use plan_expression_action::*;
 pub use plan_expression_chain::ExpressionChain;
 pub use plan_expression_column::col;
+pub use plan_expression_common::clone_with_replacement;
 pub use plan_expression_common::expand_aggregate_arg_exprs;
 pub use plan_expression_common::expand_wildcard;
 pub use plan_expression_common::expr_as_column_expr;
 pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
+pub use plan_expression_common::find_column_exprs;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
@@ -108,10 +119,13 @@ pub use plan_filter::FilterPlan;
 pub use plan_grant_privilege::GrantPrivilegePlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertIntoPlan;
+pub use plan_join::JoinPlan;
+pub use plan_join::JoinType;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
 pub use plan_node::PlanNode;
+pub use plan_optimize_table::OptimizeTablePlan;
 pub use plan_partition::Part;
 pub use plan_partition::Partitions;
 pub use plan_projection::ProjectionPlan;
@@ -132,7 +146,10 @@ pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_truncate_table::TruncateTablePlan;
+pub use plan_union::UnionPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
+pub use plan_vacuum_table::VacuumTablePlan;
 pub use plan_visitor::PlanVisitor;
@@ -15,11 +15,13 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::merge_types;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -34,6 +36,8 @@ use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::HavingPlan;
+use crate::JoinPlan;
+use crate::JoinType;
 use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
@@ -41,6 +45,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::UnionPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -218,6 +223,118 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply an equi-join, the current plan is the left(probe) side
+    pub fn join(
+        &self,
+        join_type: JoinType,
+        left_keys: &[Expression],
+        right_keys: &[Expression],
+        right: &PlanNode,
+    ) -> Result<Self> {
+        if left_keys.is_empty() || left_keys.len() != right_keys.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Join keys must be non-empty pairs, left keys: {:?}, right keys: {:?}",
+                left_keys, right_keys
+            )));
+        }
+
+        for expr in left_keys.iter().chain(right_keys.iter()) {
+            validate_expression(expr)?;
+        }
+
+        let left_schema = self.plan.schema();
+        let right_schema = right.schema();
+        for (left_key, right_key) in left_keys.iter().zip(right_keys.iter()) {
+            let left_type = left_key.to_data_type(&left_schema)?;
+            let right_type = right_key.to_data_type(&right_schema)?;
+            if left_type != right_type {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Join keys {:?} and {:?} have different types: {:?} and {:?}",
+                    left_key, right_key, left_type, right_type
+                )));
+            }
+        }
+
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            left_keys: left_keys.to_vec(),
+            right_keys: right_keys.to_vec(),
+            schema: JoinPlan::join_schema(join_type, &left_schema, &right_schema),
+            left: Arc::new(self.plan.clone()),
+            right: Arc::new(right.clone()),
+        })))
+    }
+
+    /// Apply a union all with the other plans.
+    /// The columns are matched by position, names come from the first plan and
+    /// the types are coerced to a common super type of all plans.
+    pub fn union(&self, others: &[PlanNode]) -> Result<Self> {
+        let schema = self.plan.schema();
+        for other in others {
+            if other.schema().fields().len() != schema.fields().len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Union inputs must have the same number of columns, but got {} and {}",
+                    schema.fields().len(),
+                    other.schema().fields().len()
+                )));
+            }
+        }
+
+        let mut fields = schema.fields().clone();
+        for other in others {
+            for (field, other_field) in fields.iter_mut().zip(other.schema().fields().iter()) {
+                let data_type = merge_types(field.data_type(), other_field.data_type())?;
+                let nullable = field.is_nullable() || other_field.is_nullable();
+                *field = DataField::new(field.name(), data_type, nullable);
+            }
+        }
+
+        let mut inputs = Vec::with_capacity(others.len() + 1);
+        for input in std::iter::once(&self.plan).chain(others.iter()) {
+            inputs.push(Arc::new(Self::coerce_to_fields(input, &fields)?));
+        }
+
+        Ok(Self::from(&PlanNode::Union(UnionPlan {
+            schema: DataSchemaRefExt::create(fields),
+            inputs,
+        })))
+    }
+
+    fn coerce_to_fields(plan: &PlanNode, fields: &[DataField]) -> Result<PlanNode> {
+        let schema = plan.schema();
+        let need_coerce = schema
+            .fields()
+            .iter()
+            .zip(fields.iter())
+            .any(|(f, target)| f.name() != target.name() || f.data_type() != target.data_type());
+
+        if !need_coerce {
+            return Ok(plan.clone());
+        }
+
+        let exprs = schema
+            .fields()
+            .iter()
+            .zip(fields.iter())
+            .map(|(f, target)| {
+                let expr = match f.data_type() == target.data_type() {
+                    true => col(f.name()),
+                    false => Expression::Cast {
+                        expr: Box::new(col(f.name())),
+                        data_type: target.data_type().clone(),
+                    },
+                };
+
+                match f.name() == target.name() && f.data_type() == target.data_type() {
+                    true => expr,
+                    false => Expression::Alias(target.name().clone(), Box::new(expr)),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self::from(plan).project(&exprs)?.build()
+    }
+
     pub fn sort(&self, exprs: &[Expression]) -> Result<Self> {
         Ok(Self::from(&PlanNode::Sort(SortPlan {
             order_by: exprs.to_vec(),
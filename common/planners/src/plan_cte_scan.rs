@@ -0,0 +1,38 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// Scan of a common table expression(CTE) which is referenced more than once in a query.
+/// All the scans with the same name share one evaluation of the query plan.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct CteScanPlan {
+    /// The unique name of the CTE in the query.
+    pub name: String,
+    pub query_plan: Arc<PlanNode>,
+}
+
+impl CteScanPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.query_plan.schema()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.query_plan = Arc::new(node.clone());
+    }
+}
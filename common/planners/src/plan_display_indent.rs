@@ -20,10 +20,12 @@ use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
+use crate::DeletePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::JoinPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -31,6 +33,7 @@ use crate::ReadDataSourcePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::UpdatePlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
@@ -63,14 +66,19 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::AggregatorFinal(plan) => Self::format_aggregator_final(f, plan),
             PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
+            PlanNode::Join(plan) => Self::format_join(f, plan),
+            PlanNode::Union(_) => write!(f, "Union"),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
+            PlanNode::CteScan(plan) => write!(f, "CteScan: name={}", plan.name),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
             PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+            PlanNode::Delete(plan) => Self::format_delete(f, plan),
+            PlanNode::Update(plan) => Self::format_update(f, plan),
             _ => {
                 let mut printed = true;
 
@@ -165,6 +173,14 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         )
     }
 
+    fn format_join(f: &mut Formatter, plan: &JoinPlan) -> fmt::Result {
+        write!(
+            f,
+            "Join: type={}, left_keys={:?}, right_keys={:?}",
+            plan.join_type, plan.left_keys, plan.right_keys
+        )
+    }
+
     fn format_sort(f: &mut Formatter, plan: &SortPlan) -> fmt::Result {
         write!(f, "Sort: ")?;
         for i in 0..plan.order_by.len() {
@@ -214,7 +230,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         )?;
 
         if let Some(p) = &plan.push_downs {
-            if p.limit.is_some() || p.projection.is_some() {
+            if p.limit.is_some()
+                || p.projection.is_some()
+                || !p.filters.is_empty()
+                || !p.prewhere.is_empty()
+            {
                 write!(f, ", push_downs: [")?;
                 let mut comma = false;
                 if p.projection.is_some() {
@@ -222,6 +242,24 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
                     comma = true;
                 }
 
+                if !p.filters.is_empty() {
+                    if comma {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "filters: {:?}", p.filters)?;
+                    comma = true;
+                }
+
+                if !p.prewhere.is_empty() {
+                    if comma {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "prewhere: {:?}", p.prewhere)?;
+                    comma = true;
+                }
+
                 if p.limit.is_some() {
                     if comma {
                         write!(f, ", ")?;
@@ -261,4 +299,26 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
         write!(f, " if_exists:{:}", plan.if_exists)
     }
+
+    fn format_delete(f: &mut Formatter, plan: &DeletePlan) -> fmt::Result {
+        write!(f, "Delete from {:}.{:}", plan.db_name, plan.tbl_name)?;
+        match &plan.selection {
+            Some(selection) => write!(f, ", selection: {:?}", selection),
+            None => write!(f, ", selection: all"),
+        }
+    }
+
+    fn format_update(f: &mut Formatter, plan: &UpdatePlan) -> fmt::Result {
+        write!(f, "Update {:}.{:} set ", plan.db_name, plan.tbl_name)?;
+        for (i, (column, value)) in plan.assignments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {:?}", column, value)?;
+        }
+        match &plan.selection {
+            Some(selection) => write!(f, ", selection: {:?}", selection),
+            None => write!(f, ", selection: all"),
+        }
+    }
 }
@@ -243,7 +243,7 @@ pub fn find_columns_not_satisfy_exprs(
 ///       cloning/replacement.
 /// * `Err(err)`: Any error returned by the function is returned as-is by
 ///       `clone_with_replacement()`.
-fn clone_with_replacement<F>(expr: &Expression, replacement_fn: &F) -> Result<Expression>
+pub fn clone_with_replacement<F>(expr: &Expression, replacement_fn: &F) -> Result<Expression>
 where F: Fn(&Expression) -> Result<Option<Expression>> {
     let replacement_opt = replacement_fn(expr)?;
 
@@ -21,6 +21,9 @@ pub struct Extras {
     pub projection: Option<Vec<usize>>,
     /// Optional filter expression plan
     pub filters: Vec<Expression>,
+    /// Optional prewhere expression plan, the rows are filtered on the columns of the
+    /// prewhere before the other columns are read
+    pub prewhere: Vec<Expression>,
     /// Optional limit to skip read
     pub limit: Option<usize>,
     /// Optional order_by expression plan
@@ -32,6 +35,7 @@ impl Extras {
         Extras {
             projection: None,
             filters: vec![],
+            prewhere: vec![],
             limit: None,
             order_by: vec![],
         }
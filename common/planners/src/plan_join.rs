@@ -0,0 +1,117 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+
+use crate::Expression;
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    /// The left rows having at least one match, only the left columns are output.
+    Semi,
+    /// The left rows without any match, only the left columns are output.
+    Anti,
+}
+
+impl JoinType {
+    /// Whether the rows of the left(probe) side without match are kept.
+    pub fn keep_unmatched_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Whether the rows of the right(build) side without match are kept.
+    pub fn keep_unmatched_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+
+    /// Whether the columns of the right(build) side are output.
+    pub fn output_right(&self) -> bool {
+        !matches!(self, JoinType::Semi | JoinType::Anti)
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "INNER"),
+            JoinType::Left => write!(f, "LEFT"),
+            JoinType::Right => write!(f, "RIGHT"),
+            JoinType::Full => write!(f, "FULL"),
+            JoinType::Semi => write!(f, "SEMI"),
+            JoinType::Anti => write!(f, "ANTI"),
+        }
+    }
+}
+
+/// Equi-join of two inputs.
+/// The left input is the probe side and the right input is the build side of the hash table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct JoinPlan {
+    pub join_type: JoinType,
+    /// Key expressions evaluated against the left input schema.
+    pub left_keys: Vec<Expression>,
+    /// Key expressions evaluated against the right input schema.
+    pub right_keys: Vec<Expression>,
+    /// Output schema: the left fields followed by the right fields,
+    /// or only the left fields for SEMI and ANTI join.
+    pub schema: DataSchemaRef,
+    pub left: Arc<PlanNode>,
+    pub right: Arc<PlanNode>,
+}
+
+impl JoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_inputs(&mut self, inputs: Vec<&PlanNode>) {
+        assert_eq!(inputs.len(), 2);
+        self.left = Arc::new(inputs[0].clone());
+        self.right = Arc::new(inputs[1].clone());
+        self.schema = Self::join_schema(self.join_type, &self.left.schema(), &self.right.schema());
+    }
+
+    /// The output schema of a join, the side that may be NULL-extended becomes nullable.
+    pub fn join_schema(
+        join_type: JoinType,
+        left: &DataSchemaRef,
+        right: &DataSchemaRef,
+    ) -> DataSchemaRef {
+        let extend_fields = |schema: &DataSchemaRef, nullable: bool| {
+            schema
+                .fields()
+                .iter()
+                .map(|f| {
+                    DataField::new(f.name(), f.data_type().clone(), nullable || f.is_nullable())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut fields = extend_fields(left, join_type.keep_unmatched_right());
+        if join_type.output_right() {
+            fields.extend(extend_fields(right, join_type.keep_unmatched_left()));
+        }
+        DataSchemaRefExt::create(fields)
+    }
+}
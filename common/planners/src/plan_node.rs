@@ -26,6 +26,8 @@ use crate::AlterUserPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -36,9 +38,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
@@ -48,7 +52,10 @@ use crate::ShowCreateTablePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -63,10 +70,13 @@ pub enum PlanNode {
     AggregatorFinal(AggregatorFinalPlan),
     Filter(FilterPlan),
     Having(HavingPlan),
+    Join(JoinPlan),
+    Union(UnionPlan),
     Sort(SortPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
     ReadSource(ReadDataSourcePlan),
+    CteScan(CteScanPlan),
     Select(SelectPlan),
     Explain(ExplainPlan),
     CreateDatabase(CreateDatabasePlan),
@@ -75,9 +85,13 @@ pub enum PlanNode {
     DescribeTable(DescribeTablePlan),
     DropTable(DropTablePlan),
     TruncateTable(TruncateTablePlan),
+    OptimizeTable(OptimizeTablePlan),
+    VacuumTable(VacuumTablePlan),
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     InsertInto(InsertIntoPlan),
+    Delete(DeletePlan),
+    Update(UpdatePlan),
     ShowCreateTable(ShowCreateTablePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Kill(KillPlan),
@@ -100,9 +114,12 @@ impl PlanNode {
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
             PlanNode::Having(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
+            PlanNode::Union(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
+            PlanNode::CteScan(v) => v.schema(),
             PlanNode::Select(v) => v.schema(),
             PlanNode::Explain(v) => v.schema(),
             PlanNode::CreateDatabase(v) => v.schema(),
@@ -111,10 +128,14 @@ impl PlanNode {
             PlanNode::DropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::OptimizeTable(v) => v.schema(),
+            PlanNode::VacuumTable(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
             PlanNode::InsertInto(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Kill(v) => v.schema(),
@@ -136,9 +157,12 @@ impl PlanNode {
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
             PlanNode::Having(_) => "HavingPlan",
+            PlanNode::Join(_) => "JoinPlan",
+            PlanNode::Union(_) => "UnionPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
+            PlanNode::CteScan(_) => "CteScanPlan",
             PlanNode::Select(_) => "SelectPlan",
             PlanNode::Explain(_) => "ExplainPlan",
             PlanNode::CreateDatabase(_) => "CreateDatabasePlan",
@@ -147,10 +171,14 @@ impl PlanNode {
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
+            PlanNode::VacuumTable(_) => "VacuumTablePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
             PlanNode::InsertInto(_) => "InsertIntoPlan",
+            PlanNode::Delete(_) => "DeletePlan",
+            PlanNode::Update(_) => "UpdatePlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Kill(_) => "KillQuery",
@@ -170,7 +198,10 @@ impl PlanNode {
             PlanNode::AggregatorFinal(v) => vec![v.input.clone()],
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
+            PlanNode::Join(v) => vec![v.left.clone(), v.right.clone()],
+            PlanNode::Union(v) => v.inputs.clone(),
             PlanNode::Limit(v) => vec![v.input.clone()],
+            PlanNode::CteScan(v) => vec![v.query_plan.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
@@ -198,7 +229,10 @@ impl PlanNode {
             PlanNode::AggregatorFinal(v) => v.set_input(inputs[0]),
             PlanNode::Filter(v) => v.set_input(inputs[0]),
             PlanNode::Having(v) => v.set_input(inputs[0]),
+            PlanNode::Join(v) => v.set_inputs(inputs),
+            PlanNode::Union(v) => v.set_inputs(inputs),
             PlanNode::Limit(v) => v.set_input(inputs[0]),
+            PlanNode::CteScan(v) => v.set_input(inputs[0]),
             PlanNode::Explain(v) => v.set_input(inputs[0]),
             PlanNode::Select(v) => v.set_input(inputs[0]),
             PlanNode::Sort(v) => v.set_input(inputs[0]),
@@ -29,6 +29,8 @@ use crate::AlterUserPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -41,9 +43,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -55,7 +59,10 @@ use crate::ShowCreateTablePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -88,6 +95,7 @@ pub trait PlanRewriter {
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
+            PlanNode::CteScan(plan) => self.rewrite_cte_scan(plan),
             PlanNode::Select(plan) => self.rewrite_select(plan),
             PlanNode::Explain(plan) => self.rewrite_explain(plan),
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
@@ -98,14 +106,20 @@ pub trait PlanRewriter {
             PlanNode::Broadcast(plan) => self.rewrite_broadcast(plan),
             PlanNode::Remote(plan) => self.rewrite_remote(plan),
             PlanNode::Having(plan) => self.rewrite_having(plan),
+            PlanNode::Join(plan) => self.rewrite_join(plan),
+            PlanNode::Union(plan) => self.rewrite_union(plan),
             PlanNode::Expression(plan) => self.rewrite_expression(plan),
             PlanNode::DescribeTable(plan) => self.rewrite_describe_table(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
             PlanNode::InsertInto(plan) => self.rewrite_insert_into(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
+            PlanNode::VacuumTable(plan) => self.rewrite_vacuum_table(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
@@ -262,6 +276,32 @@ pub trait PlanRewriter {
         PlanBuilder::from(&new_input).having(new_predicate)?.build()
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // The build side is executed like a subquery, so we rewrite it as a subquery
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_subquery_plan(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
+    fn rewrite_union(&mut self, plan: &UnionPlan) -> Result<PlanNode> {
+        // Each input of union is an independent pipeline, rewrite it as a subquery
+        let mut new_inputs = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            new_inputs.push(self.rewrite_subquery_plan(input.as_ref())?);
+        }
+
+        PlanBuilder::from(&new_inputs[0])
+            .union(&new_inputs[1..])?
+            .build()
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
@@ -286,6 +326,14 @@ pub trait PlanRewriter {
         Ok(PlanNode::ReadSource(plan.clone()))
     }
 
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        // The query of CTE is evaluated once like a subquery, so we rewrite it as a subquery
+        Ok(PlanNode::CteScan(CteScanPlan {
+            name: plan.name.clone(),
+            query_plan: Arc::new(self.rewrite_subquery_plan(plan.query_plan.as_ref())?),
+        }))
+    }
+
     fn rewrite_select(&mut self, plan: &SelectPlan) -> Result<PlanNode> {
         Ok(PlanNode::Select(SelectPlan {
             input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
@@ -331,6 +379,14 @@ pub trait PlanRewriter {
         Ok(PlanNode::InsertInto(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
     fn rewrite_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
@@ -339,6 +395,14 @@ pub trait PlanRewriter {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_optimize_table(&mut self, plan: &OptimizeTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::OptimizeTable(plan.clone()))
+    }
+
+    fn rewrite_vacuum_table(&mut self, plan: &VacuumTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::VacuumTable(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
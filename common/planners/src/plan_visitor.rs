@@ -22,6 +22,8 @@ use crate::AlterUserPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
@@ -33,9 +35,11 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertIntoPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::OptimizeTablePlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
@@ -46,7 +50,10 @@ use crate::ShowCreateTablePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnionPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -101,6 +108,7 @@ pub trait PlanVisitor {
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
+            PlanNode::CteScan(plan) => self.visit_cte_scan(plan),
             PlanNode::Select(plan) => self.visit_select(plan),
             PlanNode::Explain(plan) => self.visit_explain(plan),
             PlanNode::CreateDatabase(plan) => self.visit_create_database(plan),
@@ -109,14 +117,20 @@ pub trait PlanVisitor {
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
+            PlanNode::VacuumTable(plan) => self.visit_vacuum_table(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan),
             PlanNode::Remote(plan) => self.visit_remote(plan),
             PlanNode::Having(plan) => self.visit_having(plan),
+            PlanNode::Join(plan) => self.visit_join(plan),
+            PlanNode::Union(plan) => self.visit_union(plan),
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::InsertInto(plan) => self.visit_insert_into(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
@@ -205,6 +219,21 @@ pub trait PlanVisitor {
         self.visit_expr(&plan.predicate)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_subquery_plan(plan.right.as_ref())?;
+        self.visit_exprs(&plan.left_keys)?;
+        self.visit_exprs(&plan.right_keys)
+    }
+
+    fn visit_union(&mut self, plan: &UnionPlan) -> Result<()> {
+        // Each input of union is an independent pipeline, visit it as a subquery
+        for input in &plan.inputs {
+            self.visit_subquery_plan(input.as_ref())?;
+        }
+        Ok(())
+    }
+
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())?;
         self.visit_exprs(&plan.order_by)
@@ -222,6 +251,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan) -> Result<()> {
+        self.visit_subquery_plan(plan.query_plan.as_ref())
+    }
+
     fn visit_select(&mut self, plan: &SelectPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -282,6 +315,22 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_optimize_table(&mut self, _: &OptimizeTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_vacuum_table(&mut self, _: &VacuumTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
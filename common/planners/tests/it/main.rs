@@ -21,8 +21,10 @@ mod plan_expression;
 mod plan_extras;
 mod plan_filter;
 mod plan_having;
+mod plan_join;
 mod plan_limit;
 mod plan_projection;
 mod plan_rewriter;
 mod plan_select;
+mod plan_union;
 mod test;
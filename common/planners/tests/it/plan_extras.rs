@@ -19,7 +19,8 @@ use pretty_assertions::assert_eq;
 #[test]
 fn test_plan_extras() -> Result<()> {
     let extras = Extras::default();
-    let expect = "Extras { projection: None, filters: [], limit: None, order_by: [] }";
+    let expect =
+        "Extras { projection: None, filters: [], prewhere: [], limit: None, order_by: [] }";
     let actual = format!("{:?}", extras);
     assert_eq!(expect, actual);
     Ok(())
@@ -0,0 +1,60 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::test::Test;
+
+#[test]
+fn test_join_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let right = PlanBuilder::from(&source)
+        .project(&[col("number").alias("b")])?
+        .build()?;
+
+    let plan = PlanBuilder::from(&source)
+        .join(JoinType::Left, &[col("number")], &[col("b")], &right)?
+        .build()?;
+
+    let expect = "\
+    Join: type=LEFT, left_keys=[number], right_keys=[b]\
+    \n  ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]\
+    \n  Projection: number as b:UInt64\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The build side of a left join is NULL-extended.
+    let schema = plan.schema();
+    assert_eq!(2, schema.fields().len());
+    assert!(!schema.field_with_name("number")?.is_nullable());
+    assert!(schema.field_with_name("b")?.is_nullable());
+    assert_eq!(&DataType::UInt64, schema.field_with_name("b")?.data_type());
+    Ok(())
+}
+
+#[test]
+fn test_join_plan_with_mismatched_keys() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let right = PlanBuilder::from(&source)
+        .project(&[col("number").alias("b")])?
+        .build()?;
+
+    let result = PlanBuilder::from(&source).join(JoinType::Inner, &[col("number")], &[], &right);
+    assert!(result.is_err());
+    Ok(())
+}
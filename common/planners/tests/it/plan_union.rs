@@ -0,0 +1,64 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::test::Test;
+
+#[test]
+fn test_union_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let other = PlanBuilder::from(&source)
+        .project(&[Expression::Cast {
+            expr: Box::new(col("number")),
+            data_type: DataType::UInt32,
+        }
+        .alias("b")])?
+        .build()?;
+
+    let plan = PlanBuilder::from(&source).union(&[other])?.build()?;
+
+    let expect = "\
+    Union\
+    \n  ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]\
+    \n  Projection: cast(b as UInt64) as number:UInt64\
+    \n    Projection: cast(number as UInt32) as b:UInt32\
+    \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The names come from the first input, the types are coerced to the super type.
+    let schema = plan.schema();
+    assert_eq!(1, schema.fields().len());
+    assert_eq!("number", schema.field(0).name());
+    assert_eq!(&DataType::UInt64, schema.field(0).data_type());
+    Ok(())
+}
+
+#[test]
+fn test_union_plan_with_mismatched_columns() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let other = PlanBuilder::from(&source)
+        .project(&[col("number"), col("number").alias("b")])?
+        .build()?;
+
+    let result = PlanBuilder::from(&source).union(&[other]);
+    let actual = format!("{}", result.err().unwrap());
+    let expect = "Code: 6, displayText = Union inputs must have the same number of columns, but got 1 and 2.";
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -64,13 +64,10 @@ impl ParquetSource {
             metadata: None,
         }
     }
-}
 
-#[async_trait]
-impl Source for ParquetSource {
-    async fn read(&mut self) -> Result<Option<DataBlock>> {
-        let metadata = match self.metadata.clone() {
-            Some(m) => m,
+    async fn metadata(&mut self) -> Result<FileMetaData> {
+        match self.metadata.clone() {
+            Some(m) => Ok(m),
             None => {
                 let mut reader = self
                     .data_accessor
@@ -81,9 +78,27 @@ impl Source for ParquetSource {
                 self.metadata = Some(m.clone());
                 self.row_groups = m.row_groups.len();
                 self.row_group = 0;
-                m
+                Ok(m)
             }
-        };
+        }
+    }
+
+    /// Skips the next row group without reading any of its columns,
+    /// returns false if there are no more row groups.
+    pub async fn skip(&mut self) -> Result<bool> {
+        self.metadata().await?;
+        if self.row_group >= self.row_groups {
+            return Ok(false);
+        }
+        self.row_group += 1;
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl Source for ParquetSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        let metadata = self.metadata().await?;
 
         if self.row_group >= self.row_groups {
             return Ok(None);
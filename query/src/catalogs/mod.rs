@@ -16,6 +16,7 @@
 pub use catalog::Catalog;
 pub use database::Database;
 pub use impls::in_memory_meta::InMemoryMetas;
+pub use table::NavigationPoint;
 pub use table::Table;
 pub use table::TablePtr;
 pub use table::ToReadDataSourcePlan;
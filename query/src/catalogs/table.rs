@@ -16,19 +16,25 @@ use std::any::Any;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_planners::DeletePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::InsertIntoPlan;
+use common_planners::OptimizeTablePlan;
 use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
 use common_streams::SendableDataBlockStream;
 
 use crate::sessions::DatabendQueryContextRef;
@@ -110,6 +116,61 @@ pub trait Table: Sync + Send {
             self.name()
         )))
     }
+
+    async fn delete(&self, _ctx: DatabendQueryContextRef, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented",
+            self.name()
+        )))
+    }
+
+    async fn update(&self, _ctx: DatabendQueryContextRef, _update_plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} is not implemented",
+            self.name()
+        )))
+    }
+
+    async fn optimize(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _optimize_plan: OptimizeTablePlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "optimize for table {} is not implemented",
+            self.name()
+        )))
+    }
+
+    async fn vacuum(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _vacuum_plan: VacuumTablePlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "vacuum for table {} is not implemented",
+            self.name()
+        )))
+    }
+
+    /// Get an instance of the table, which reads the data as of the given point of history
+    fn navigate_to(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        Err(ErrorCode::UnImplement(format!(
+            "time travel for table {} is not implemented",
+            self.name()
+        )))
+    }
+}
+
+/// A point of a table's history, which time travel queries navigate to
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavigationPoint {
+    SnapshotID(String),
+    TimePoint(DateTime<Utc>),
 }
 
 pub type TablePtr = Arc<dyn Table>;
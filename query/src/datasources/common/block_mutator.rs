@@ -0,0 +1,181 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+
+enum Mutation {
+    Delete,
+    // Evaluates the values of all the columns of the updated rows
+    Update(ExpressionExecutor),
+}
+
+/// Applies a DELETE or an UPDATE to the blocks of a table, it is shared by the engines,
+/// no matter they rewrite the mutated blocks or mutate them in place.
+pub struct BlockMutator {
+    selection: Option<ExpressionExecutor>,
+    mutation: Mutation,
+}
+
+impl BlockMutator {
+    pub fn try_create_delete(
+        schema: DataSchemaRef,
+        selection: Option<&Expression>,
+    ) -> Result<BlockMutator> {
+        Ok(BlockMutator {
+            selection: Self::selection_executor(&schema, selection)?,
+            mutation: Mutation::Delete,
+        })
+    }
+
+    pub fn try_create_update(
+        schema: DataSchemaRef,
+        assignments: &[(String, Expression)],
+        selection: Option<&Expression>,
+    ) -> Result<BlockMutator> {
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                match assignments
+                    .iter()
+                    .find(|(column, _)| column == field.name())
+                {
+                    None => Expression::Column(field.name().clone()),
+                    Some((column, value)) => Expression::Alias(
+                        column.clone(),
+                        Box::new(Expression::Cast {
+                            expr: Box::new(value.clone()),
+                            data_type: field.data_type().clone(),
+                        }),
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let executor = ExpressionExecutor::try_create(
+            "update executor",
+            schema.clone(),
+            schema.clone(),
+            exprs,
+            true,
+        )?;
+        executor.validate()?;
+
+        Ok(BlockMutator {
+            selection: Self::selection_executor(&schema, selection)?,
+            mutation: Mutation::Update(executor),
+        })
+    }
+
+    /// Returns the mutated block, or `None` if none of the rows matches the selection.
+    pub fn mutate(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        if block.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        // `None` if all the rows match
+        let matched = match &self.selection {
+            None => None,
+            Some(executor) => {
+                let matched = executor
+                    .execute(block)?
+                    .column(0)
+                    .to_array()?
+                    .cast_with_type(&DataType::Boolean)?;
+                // the rows which the selection evaluates to NULL do not match
+                let matched = matched
+                    .bool()?
+                    .into_iter()
+                    .map(|v| v == Some(true))
+                    .collect::<Vec<_>>();
+                match matched.iter().filter(|v| **v).count() {
+                    0 => return Ok(None),
+                    n if n == matched.len() => None,
+                    _ => Some(matched),
+                }
+            }
+        };
+
+        match (&self.mutation, matched) {
+            (Mutation::Delete, None) => {
+                Ok(Some(DataBlock::empty_with_schema(block.schema().clone())))
+            }
+            (Mutation::Delete, Some(matched)) => {
+                let unmatched = Series::new(matched.iter().map(|v| !v).collect::<Vec<_>>());
+                Ok(Some(DataBlock::filter_block(block, unmatched)?))
+            }
+            (Mutation::Update(executor), None) => Ok(Some(executor.execute(block)?)),
+            (Mutation::Update(executor), Some(matched)) => {
+                let updated = DataBlock::filter_block(block, Series::new(matched.clone()))?;
+                let updated = executor.execute(&updated)?;
+
+                // Scatter the updated rows back to their positions in the block, the position
+                // of an unmatched row is any valid one as its original value is taken.
+                let mut next = 0;
+                let positions = matched
+                    .iter()
+                    .map(|matched| match matched {
+                        true => {
+                            next += 1;
+                            next - 1
+                        }
+                        false => 0,
+                    })
+                    .collect::<Vec<_>>();
+                let predicate = Series::new(matched);
+
+                let columns = updated
+                    .columns()
+                    .iter()
+                    .zip(block.columns().iter())
+                    .map(|(updated_column, column)| {
+                        let mut positions = positions.iter().copied();
+                        let updated_array = updated_column.to_array()?;
+                        let updated_array =
+                            unsafe { updated_array.take_iter_unchecked(&mut positions) }?;
+                        let array = updated_array.if_then_else(&column.to_array()?, &predicate)?;
+                        Ok(DataColumn::Array(array))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Some(DataBlock::create(updated.schema().clone(), columns)))
+            }
+        }
+    }
+
+    fn selection_executor(
+        schema: &DataSchemaRef,
+        selection: Option<&Expression>,
+    ) -> Result<Option<ExpressionExecutor>> {
+        match selection {
+            None => Ok(None),
+            Some(selection) => {
+                let selection_field = selection.to_data_field(schema)?;
+                let executor = ExpressionExecutor::try_create(
+                    "selection executor",
+                    schema.clone(),
+                    DataSchemaRefExt::create(vec![selection_field]),
+                    vec![selection.clone()],
+                    false,
+                )?;
+                executor.validate()?;
+                Ok(Some(executor))
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::assert_blocks_sorted_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::add;
+use common_planners::col;
+use common_planners::lit;
+
+use crate::datasources::common::BlockMutator;
+
+fn test_block() -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1i32, 2, 3]),
+        Series::new(vec!["x", "y", "z"]),
+    ])
+}
+
+#[test]
+fn test_block_mutator_delete() -> Result<()> {
+    let block = test_block();
+    let schema = block.schema().clone();
+
+    // none of the rows matches
+    let mutator = BlockMutator::try_create_delete(schema.clone(), Some(&col("a").gt(lit(5i32))))?;
+    assert!(mutator.mutate(&block)?.is_none());
+
+    let mutator = BlockMutator::try_create_delete(schema.clone(), Some(&col("a").eq(lit(2i32))))?;
+    let mutated = mutator.mutate(&block)?.unwrap();
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 3 | z |",
+        "+---+---+",
+    ];
+    assert_blocks_sorted_eq(expected, &[mutated]);
+
+    // all the rows match
+    let mutator = BlockMutator::try_create_delete(schema, None)?;
+    let mutated = mutator.mutate(&block)?.unwrap();
+    assert_eq!(mutated.num_rows(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_block_mutator_update() -> Result<()> {
+    let block = test_block();
+    let schema = block.schema().clone();
+
+    // none of the rows matches
+    let assignments = vec![("b".to_string(), lit("w".as_bytes()))];
+    let selection = col("a").gt(lit(5i32));
+    let mutator = BlockMutator::try_create_update(schema.clone(), &assignments, Some(&selection))?;
+    assert!(mutator.mutate(&block)?.is_none());
+
+    // the rows are updated in place, the order of the rows is kept
+    let selection = col("a").lt(lit(3i32));
+    let mutator = BlockMutator::try_create_update(schema.clone(), &assignments, Some(&selection))?;
+    let mutated = mutator.mutate(&block)?.unwrap();
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | w |",
+        "| 2 | w |",
+        "| 3 | z |",
+        "+---+---+",
+    ];
+    assert_blocks_eq(expected, &[mutated]);
+
+    // the new values are cast to the types of the columns
+    let assignments = vec![("a".to_string(), add(col("a"), lit(1i64)))];
+    let mutator = BlockMutator::try_create_update(schema, &assignments, None)?;
+    let mutated = mutator.mutate(&block)?.unwrap();
+    assert_eq!(mutated.schema(), block.schema());
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 2 | x |",
+        "| 3 | y |",
+        "| 4 | z |",
+        "+---+---+",
+    ];
+    assert_blocks_sorted_eq(expected, &[mutated]);
+
+    Ok(())
+}
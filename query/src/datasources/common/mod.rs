@@ -13,13 +13,17 @@
 // limitations under the License.
 //
 
+pub use block_mutator::BlockMutator;
 pub use line::count_lines;
 pub use part::generate_parts;
 
+#[cfg(test)]
+mod block_mutator_test;
 #[cfg(test)]
 mod line_test;
 #[cfg(test)]
 mod part_test;
 
+mod block_mutator;
 mod line;
 mod part;
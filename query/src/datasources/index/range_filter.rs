@@ -37,9 +37,15 @@ pub struct RangeFilter {
 }
 
 impl RangeFilter {
-    pub fn try_create(expr: &Expression, schema: DataSchemaRef) -> Result<Self> {
+    /// Creates the range filter of the conjunction of the exprs,
+    /// a block can be pruned as soon as any of them is proved to be false.
+    pub fn try_create(exprs: &[Expression], schema: DataSchemaRef) -> Result<Self> {
         let mut stat_columns: StatColumns = Vec::new();
-        let varifiable_expr = build_verifiable_expr(expr, schema, &mut stat_columns);
+        let varifiable_expr = exprs
+            .iter()
+            .map(|expr| build_verifiable_expr(expr, schema.clone(), &mut stat_columns))
+            .reduce(|acc, expr| acc.and(expr))
+            .unwrap_or_else(|| lit(true));
         let input_fields = stat_columns
             .iter()
             .map(|c| c.stat_field.clone())
@@ -100,7 +106,16 @@ pub(crate) fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
-        Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
+        Expression::ScalarFunction { op, args } => match op.to_lowercase().as_str() {
+            "in" | "not in" if matches!(args[1..], [Expression::Subquery { .. }]) => {
+                return unhandled;
+            }
+            "in" | "not in" if args.len() > 1 => {
+                let expr = expand_in_list(args, op.to_lowercase().as_str() == "not in");
+                return build_verifiable_expr(&expr, schema, stat_columns);
+            }
+            _ => (args.clone(), op.clone()),
+        },
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
                 let left = build_verifiable_expr(left, schema.clone(), stat_columns);
@@ -129,6 +144,21 @@ pub(crate) fn build_verifiable_expr(
     .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
 }
 
+/// e.g. col in (1, 2) => col = 1 or col = 2, col not in (1, 2) => col != 1 and col != 2
+fn expand_in_list(args: &[Expression], negated: bool) -> Expression {
+    let value = &args[0];
+    let mut items = args[1..].iter().map(|item| match negated {
+        true => value.not_eq(item.clone()),
+        false => value.eq(item.clone()),
+    });
+
+    let first = items.next().unwrap();
+    items.fold(first, |acc, item| match negated {
+        true => acc.and(item),
+        false => acc.or(item),
+    })
+}
+
 struct Monotonic {
     is_monotonic: bool,
     is_positive: bool,
@@ -168,7 +198,8 @@ fn inverse_operator(op: &str) -> Result<&str> {
         "<=" => Ok(">="),
         ">" => Ok("<"),
         ">=" => Ok("<="),
-        "like" | "not like" | "ilike" | "not ilike" => Err(ErrorCode::UnknownException(format!(
+        "like" | "not like" | "ilike" | "not ilike" | "regexp" | "not regexp" | "rlike"
+        | "not rlike" | "match" | "~" | "!~" => Err(ErrorCode::UnknownException(format!(
             "cannot inverse the operator: {:?}",
             op
         ))),
@@ -296,7 +327,6 @@ impl<'a> VerifiableExprBuilder<'a> {
     }
 
     fn build(&mut self) -> Result<Expression> {
-        // TODO: support in/not in.
         match self.op {
             "isnull" => {
                 let nulls_expr = self.nulls_column_expr()?;
@@ -123,10 +123,85 @@ fn test_range_filter() -> Result<()> {
             ]),
             expect: true,
         },
+        Test {
+            name: "a in (21, 30)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(21), lit(30)]),
+            expect: false,
+        },
+        Test {
+            name: "a in (0, 5)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(0), lit(5)]),
+            expect: true,
+        },
+        Test {
+            name: "b not in (3, 10)",
+            expr: Expression::create_scalar_function("not in", vec![col("b"), lit(3), lit(10)]),
+            expect: true,
+        },
+    ];
+
+    for test in tests {
+        let prune = RangeFilter::try_create(std::slice::from_ref(&test.expr), schema.clone())?;
+        let actual = prune.eval(&stats)?;
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_range_filter_conjunctions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int32, false),
+    ]);
+
+    let mut stats: BlockStats = HashMap::new();
+    stats.insert(0u32, ColStats {
+        min: DataValue::Int64(Some(1)),
+        max: DataValue::Int64(Some(20)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+    stats.insert(1u32, ColStats {
+        min: DataValue::Int32(Some(3)),
+        max: DataValue::Int32(Some(10)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        exprs: Vec<Expression>,
+        expect: bool,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "no conjunction",
+            exprs: vec![],
+            expect: true,
+        },
+        Test {
+            name: "a > 1, b < 5",
+            exprs: vec![col("a").gt(lit(1)), col("b").lt(lit(5))],
+            expect: true,
+        },
+        Test {
+            name: "a > 1, b > 10",
+            exprs: vec![col("a").gt(lit(1)), col("b").gt(lit(10))],
+            expect: false,
+        },
+        Test {
+            name: "a < 1, b > 3",
+            exprs: vec![col("a").lt(lit(1)), col("b").gt(lit(3))],
+            expect: false,
+        },
     ];
 
     for test in tests {
-        let prune = RangeFilter::try_create(&test.expr, schema.clone())?;
+        let prune = RangeFilter::try_create(&test.exprs, schema.clone())?;
         let actual = prune.eval(&stats)?;
         assert_eq!(test.expect, actual, "{:#?}", test.name);
     }
@@ -249,6 +324,16 @@ fn test_build_verifiable_function() -> Result<()> {
             ]),
             expect: "(min_c < ffffff)",
         },
+        Test {
+            name: "a in (1, 2)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(1), lit(2)]),
+            expect: "(((min_a <= 1) and (max_a >= 1)) or ((min_a <= 2) and (max_a >= 2)))",
+        },
+        Test {
+            name: "b not in (3)",
+            expr: Expression::create_scalar_function("not in", vec![col("b"), lit(3)]),
+            expect: "((min_b != 3) or (max_b != 3))",
+        },
     ];
 
     for test in tests {
@@ -13,12 +13,20 @@
 //  limitations under the License.
 //
 
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::tokio;
+use common_dal::read_obj;
 use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::MetaVersion;
 use common_planners::InsertIntoPlan;
 use common_streams::SendableDataBlockStream;
+use rand::thread_rng;
+use rand::Rng;
 use uuid::Uuid;
 
 use crate::catalogs::Catalog;
@@ -40,49 +48,86 @@ impl FuseTable {
     ) -> Result<()> {
         // 1. get da
         let da = ctx.get_data_accessor()?;
+        let location_prefix = self.location_prefix();
 
         // 2. Append blocks to storage
-        let segment_info =
-            BlockAppender::append_blocks(da.clone(), stream, self.table_info.schema().as_ref())
-                .await?;
+        let segment_info = BlockAppender::append_blocks(
+            da.clone(),
+            stream,
+            self.table_info.schema().as_ref(),
+            &location_prefix,
+        )
+        .await?;
 
         // 3. save segment info
-        let seg_loc = util::gen_segment_info_location();
+        let seg_loc = util::gen_segment_info_location(&location_prefix);
         let bytes = serde_json::to_vec(&segment_info)?;
         da.put(&seg_loc, bytes).await?;
 
-        // 4. new snapshot
-        let prev_snapshot = self.table_snapshot(ctx.clone()).await?;
-
-        // TODO backoff retry this block
-        {
+        // 4. new snapshot, optimistically committed against the table version we've read
+        let table_id = insert_plan.tbl_id;
+        let max_retries = ctx.get_settings().get_max_commit_retries()?;
+        let mut table_version = self.table_info.ident.version;
+        let mut prev_snapshot = self.table_snapshot(ctx.clone()).await?;
+        let mut retries = 0;
+        loop {
             let new_snapshot = merge_snapshot(
                 self.table_info.schema().as_ref(),
                 prev_snapshot,
-                (segment_info, seg_loc),
+                (&segment_info, seg_loc.clone()),
             )?;
 
             // 4.1 save the new snapshot
             let uuid = new_snapshot.snapshot_id;
-            let snapshot_loc = util::snapshot_location(uuid.to_simple().to_string().as_str());
+            let snapshot_loc =
+                util::snapshot_location(&location_prefix, uuid.to_simple().to_string());
             let bytes = serde_json::to_vec(&new_snapshot)?;
             da.put(&snapshot_loc, bytes).await?;
 
             // 5. commit
-            let table_id = insert_plan.tbl_id;
-            commit(ctx, table_id, self.table_info.ident.version, snapshot_loc).await?;
+            match commit(ctx.clone(), table_id, table_version, snapshot_loc.clone()).await {
+                Err(e)
+                    if e.code() == ErrorCode::TableVersionMissMatch("").code()
+                        && retries < max_retries =>
+                {
+                    // someone else committed in between, the snapshot we've written is garbage
+                    da.delete(&snapshot_loc).await?;
+                    retries += 1;
+                    tokio::time::sleep(backoff(retries)).await;
+
+                    // 5.1 re-read the latest snapshot, and merge our segment into it
+                    let (ident, meta) = ctx.get_catalog().get_table_meta_by_id(table_id).await?;
+                    table_version = ident.version;
+                    prev_snapshot = match meta.options.get(TBL_OPT_KEY_SNAPSHOT_LOC) {
+                        Some(loc) => Some(read_obj(da.clone(), loc.clone()).await?),
+                        None => None,
+                    };
+                }
+                res => return res,
+            }
         }
-        Ok(())
     }
 }
 
+/// Exponential backoff (10ms, 20ms, ... up to about 1s) with a little jitter,
+/// so that the conflicting committers do not retry in lockstep.
+fn backoff(retries: u64) -> Duration {
+    let base = 10 * 2u64.pow(retries.min(7) as u32);
+    let jitter = thread_rng().gen_range(0..=base / 2);
+    Duration::from_millis(base + jitter)
+}
+
 fn merge_snapshot(
     schema: &DataSchema,
     pre: Option<TableSnapshot>,
-    (seg_info, loc): (SegmentInfo, String),
+    (seg_info, loc): (&SegmentInfo, String),
 ) -> Result<TableSnapshot> {
     if let Some(s) = pre {
+        let prev_id = s.snapshot_id;
         let mut new_snapshot = s.append_segment(loc);
+        new_snapshot.snapshot_id = Uuid::new_v4();
+        new_snapshot.prev_snapshot_id = Some(prev_id);
+        new_snapshot.timestamp = Some(Utc::now());
         let new_stat = util::merge_stats(schema, &new_snapshot.summary, &seg_info.summary)?;
         new_snapshot.summary = new_stat;
         Ok(new_snapshot)
@@ -90,14 +135,15 @@ fn merge_snapshot(
         Ok(TableSnapshot {
             snapshot_id: Uuid::new_v4(),
             prev_snapshot_id: None,
+            timestamp: Some(Utc::now()),
             schema: schema.clone(),
-            summary: seg_info.summary,
+            summary: seg_info.summary.clone(),
             segments: vec![loc],
         })
     }
 }
 
-async fn commit(
+pub(super) async fn commit(
     ctx: DatabendQueryContextRef,
     table_id: MetaId,
     table_version: MetaVersion,
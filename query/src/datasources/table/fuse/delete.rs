@@ -0,0 +1,42 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::TruncateTablePlan;
+
+use crate::datasources::common::BlockMutator;
+use crate::datasources::table::fuse::FuseTable;
+use crate::sessions::DatabendQueryContextRef;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_delete(&self, ctx: DatabendQueryContextRef, plan: DeletePlan) -> Result<()> {
+        match plan.selection {
+            None => {
+                let truncate_plan = TruncateTablePlan {
+                    db: plan.db_name,
+                    table: plan.tbl_name,
+                };
+                self.do_truncate(ctx, truncate_plan).await
+            }
+            Some(selection) => {
+                let mutator =
+                    BlockMutator::try_create_delete(self.table_info.schema(), Some(&selection))?;
+                self.do_mutate(ctx, Some(selection), &mutator).await
+            }
+        }
+    }
+}
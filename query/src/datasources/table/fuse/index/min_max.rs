@@ -23,23 +23,20 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 
 use crate::datasources::index::RangeFilter;
-use crate::datasources::table::fuse::util;
 use crate::datasources::table::fuse::util::BlockStats;
 use crate::datasources::table::fuse::BlockMeta;
 use crate::datasources::table::fuse::SegmentInfo;
 use crate::datasources::table::fuse::TableSnapshot;
 
 pub struct MinMaxIndex {
-    table_snapshot_loc: String,
+    segment_locs: Vec<String>,
     da: Arc<dyn DataAccessor>,
 }
 
 impl MinMaxIndex {
     pub fn new(table_snapshot: &TableSnapshot, da: Arc<dyn DataAccessor>) -> Self {
         Self {
-            table_snapshot_loc: util::snapshot_location(
-                table_snapshot.snapshot_id.to_simple().to_string(),
-            ),
+            segment_locs: table_snapshot.segments.clone(),
             da,
         }
     }
@@ -58,19 +55,15 @@ impl MinMaxIndex {
             if exprs.filters.is_empty() {
                 pred_true()
             } else {
-                // for the time being, we only handle the first expr
-                let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
+                let verifiable_expression = RangeFilter::try_create(&exprs.filters, schema)?;
                 Box::new(move |v: &BlockStats| verifiable_expression.eval(v))
             }
         } else {
             pred_true()
         };
 
-        let snapshot =
-            common_dal::read_obj::<TableSnapshot>(self.da.clone(), self.table_snapshot_loc.clone())
-                .await?;
-        let segment_num = snapshot.segments.len();
-        let segment_locs = snapshot.segments;
+        let segment_num = self.segment_locs.len();
+        let segment_locs = self.segment_locs.clone();
         if segment_locs.is_empty() {
             return Ok(vec![]);
         };
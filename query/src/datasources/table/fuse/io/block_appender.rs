@@ -39,6 +39,7 @@ impl BlockAppender {
         data_accessor: Arc<dyn DataAccessor>,
         mut stream: SendableDataBlockStream,
         data_schema: &DataSchema,
+        location_prefix: &str,
     ) -> Result<SegmentInfo> {
         let mut stats_acc = util::StatisticsAccumulator::new();
         let mut block_meta_acc = util::BlockMetaAccumulator::new();
@@ -48,7 +49,7 @@ impl BlockAppender {
             let block = block?;
             stats_acc.acc(&block)?;
             let schema = block.schema().to_arrow();
-            let location = util::gen_unique_block_location();
+            let location = util::gen_unique_block_location(location_prefix);
             let file_size = Self::save_block(&schema, block, &data_accessor, &location).await?;
             block_meta_acc.acc(file_size, location, &mut stats_acc);
         }
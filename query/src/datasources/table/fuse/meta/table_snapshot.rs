@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::uuid;
 use common_datavalues::DataSchema;
 use common_datavalues::DataValue;
@@ -34,6 +36,9 @@ pub struct TableSnapshot {
 
     pub prev_snapshot_id: Option<SnapshotId>,
 
+    /// The time when the snapshot is created, `None` for the snapshots created before it is tracked
+    pub timestamp: Option<DateTime<Utc>>,
+
     /// For each snapshot, we keep a schema for it (in case of schema evolution)
     pub schema: DataSchema,
 
@@ -42,8 +47,8 @@ pub struct TableSnapshot {
 
     /// Pointers to SegmentInfos (may be of different format)
     ///
-    /// We rely on background merge tasks to keep merging segments, so that
-    /// this the size of this vector could be kept reasonable
+    /// We rely on `OPTIMIZE TABLE` to merge the segments, so that
+    /// the size of this vector could be kept reasonable
     pub segments: Vec<Location>,
 }
 
@@ -84,6 +89,9 @@ pub struct BlockMeta {
     /// Pointer of the data Block
     pub row_count: u64,
     pub block_size: u64,
+    /// Size of the parquet file, 0 for the blocks written before it was recorded
+    #[serde(default)]
+    pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColStats>,
     pub location: BlockLocation,
 }
@@ -14,14 +14,20 @@
 //
 
 mod append;
+mod delete;
 pub(crate) mod index;
 pub(crate) mod io;
 mod meta;
+mod mutation;
+mod optimize;
 mod read;
 mod read_plan;
 mod table;
+mod time_travel;
 mod truncate;
+mod update;
 pub(crate) mod util;
+mod vacuum;
 
 #[cfg(test)]
 mod read_plan_test;
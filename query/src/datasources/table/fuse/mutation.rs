@@ -0,0 +1,181 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use common_base::MemoryTracker;
+use common_dal::read_obj;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::Extras;
+use uuid::Uuid;
+
+use crate::catalogs::Table;
+use crate::datasources::common::BlockMutator;
+use crate::datasources::table::fuse::append::commit;
+use crate::datasources::table::fuse::index;
+use crate::datasources::table::fuse::util;
+use crate::datasources::table::fuse::BlockAppender;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::SegmentInfo;
+use crate::datasources::table::fuse::Stats;
+use crate::sessions::DatabendQueryContextRef;
+
+impl FuseTable {
+    /// Copy-on-write the rows which match the selection (all the rows if there is no selection):
+    /// the blocks which can not contain any of them (according to the min/max index) are kept
+    /// untouched, the blocks which do contain some of them are rewritten by the mutator.
+    pub(crate) async fn do_mutate(
+        &self,
+        ctx: DatabendQueryContextRef,
+        selection: Option<Expression>,
+        mutator: &BlockMutator,
+    ) -> Result<()> {
+        let prev_snapshot = match self.table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let da = ctx.get_data_accessor()?;
+        let schema = self.table_info.schema();
+        let location_prefix = self.location_prefix();
+
+        // 1. prune the blocks which can not contain any matching rows
+        let push_downs = selection.map(|selection| Extras {
+            filters: vec![selection],
+            ..Extras::default()
+        });
+        let candidates =
+            index::range_filter(&prev_snapshot, schema.clone(), push_downs, da.clone())
+                .await?
+                .into_iter()
+                .map(|block_meta| block_meta.location.location)
+                .collect::<HashSet<_>>();
+        if candidates.is_empty() {
+            return Ok(());
+        }
+
+        // 2. rewrite the candidate blocks, the segments which contain no matching rows are kept
+        let mut segments = Vec::with_capacity(prev_snapshot.segments.len());
+        let mut summaries = Vec::with_capacity(prev_snapshot.segments.len());
+        let mut rewritten = false;
+        for seg_loc in &prev_snapshot.segments {
+            let segment: SegmentInfo = read_obj(da.clone(), seg_loc.clone()).await?;
+            let mut changed = false;
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            let mut compressed_byte_size = segment.summary.compressed_byte_size;
+            for block_meta in segment.blocks {
+                if !candidates.contains(&block_meta.location.location) {
+                    blocks.push(block_meta);
+                    continue;
+                }
+
+                let data =
+                    Self::read_blocks(da.clone(), std::slice::from_ref(&block_meta), &schema)
+                        .await?;
+                let data = DataBlock::concat_blocks(&data)?;
+                MemoryTracker::check_current_memory_limit()?;
+                let mutated = match mutator.mutate(&data)? {
+                    Some(mutated) => mutated,
+                    None => {
+                        // the min/max index can not tell, but none of the rows matches
+                        blocks.push(block_meta);
+                        continue;
+                    }
+                };
+
+                changed = true;
+                compressed_byte_size = compressed_byte_size
+                    .saturating_sub(Self::object_size(da.as_ref(), &block_meta).await?);
+                if mutated.num_rows() > 0 {
+                    let stream = Box::pin(futures::stream::iter(vec![Ok(mutated)]));
+                    let new_segment = BlockAppender::append_blocks(
+                        da.clone(),
+                        stream,
+                        schema.as_ref(),
+                        &location_prefix,
+                    )
+                    .await?;
+                    compressed_byte_size += new_segment.summary.compressed_byte_size;
+                    blocks.extend(new_segment.blocks);
+                }
+            }
+
+            if !changed {
+                segments.push(seg_loc.clone());
+                summaries.push(segment.summary);
+                continue;
+            }
+
+            rewritten = true;
+            // all the rows of the segment are deleted
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+            let summary = Stats {
+                row_count: blocks.iter().map(|b| b.row_count).sum(),
+                block_count: blocks.len() as u64,
+                uncompressed_byte_size: blocks.iter().map(|b| b.block_size).sum(),
+                compressed_byte_size,
+                col_stats: util::column_stats_reduce_with_schema(&col_stats, &schema)?,
+            };
+            let segment_info = SegmentInfo {
+                blocks,
+                summary: summary.clone(),
+            };
+            let new_seg_loc = util::gen_segment_info_location(&location_prefix);
+            let bytes = serde_json::to_vec(&segment_info)?;
+            da.put(&new_seg_loc, bytes).await?;
+            segments.push(new_seg_loc);
+            summaries.push(summary);
+        }
+
+        if !rewritten {
+            return Ok(());
+        }
+
+        // 3. new snapshot, which points at the previous one
+        let summary = summaries.iter().try_fold(Stats::default(), |acc, s| {
+            util::merge_stats(&schema, &acc, s)
+        })?;
+        let prev_id = prev_snapshot.snapshot_id;
+        let mut new_snapshot = prev_snapshot;
+        new_snapshot.snapshot_id = Uuid::new_v4();
+        new_snapshot.prev_snapshot_id = Some(prev_id);
+        new_snapshot.timestamp = Some(Utc::now());
+        new_snapshot.summary = summary;
+        new_snapshot.segments = segments;
+
+        let snapshot_loc = util::snapshot_location(
+            &location_prefix,
+            new_snapshot.snapshot_id.to_simple().to_string(),
+        );
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        da.put(&snapshot_loc, bytes).await?;
+
+        // 4. commit
+        commit(
+            ctx,
+            self.get_id(),
+            self.table_info.ident.version,
+            snapshot_loc,
+        )
+        .await
+    }
+}
@@ -0,0 +1,182 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::MemoryTracker;
+use common_dal::read_obj;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::OptimizeTablePlan;
+use common_streams::ParquetSource;
+use common_streams::Source;
+use uuid::Uuid;
+
+use crate::catalogs::Table;
+use crate::datasources::table::fuse::append::commit;
+use crate::datasources::table::fuse::util;
+use crate::datasources::table::fuse::BlockAppender;
+use crate::datasources::table::fuse::BlockMeta;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::SegmentInfo;
+use crate::datasources::table::fuse::Stats;
+use crate::sessions::DatabendQueryContextRef;
+
+impl FuseTable {
+    /// Compact the table: the blocks smaller than `max_block_size` rows are rewritten into
+    /// blocks of about `max_block_size` rows, and all the segments are merged into one.
+    #[inline]
+    pub async fn do_optimize(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _optimize_plan: OptimizeTablePlan,
+    ) -> Result<()> {
+        let prev_snapshot = match self.table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let da = ctx.get_data_accessor()?;
+        let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+
+        // 1. split the blocks into the small ones to be rewritten and the ones to be kept
+        let mut small_blocks = vec![];
+        let mut kept_blocks = vec![];
+        for seg_loc in &prev_snapshot.segments {
+            let segment: SegmentInfo = read_obj(da.clone(), seg_loc.clone()).await?;
+            for block in segment.blocks {
+                match (block.row_count as usize) < max_block_size {
+                    true => small_blocks.push(block),
+                    false => kept_blocks.push(block),
+                }
+            }
+        }
+
+        // nothing to merge, the table is compact already
+        if prev_snapshot.segments.len() <= 1 && small_blocks.len() <= 1 {
+            return Ok(());
+        }
+
+        // 2. rewrite the small blocks
+        let schema = self.table_info.schema();
+        let blocks = Self::read_blocks(da.clone(), &small_blocks, &schema).await?;
+        let blocks = match blocks.is_empty() {
+            true => vec![],
+            false => {
+                let block = DataBlock::concat_blocks(&blocks)?;
+                MemoryTracker::check_current_memory_limit()?;
+                DataBlock::split_block_by_size(&block, max_block_size)?
+            }
+        };
+        let stream = Box::pin(futures::stream::iter(blocks.into_iter().map(Ok)));
+        let location_prefix = self.location_prefix();
+        let new_segment =
+            BlockAppender::append_blocks(da.clone(), stream, schema.as_ref(), &location_prefix)
+                .await?;
+
+        // 3. merge the kept blocks and the rewritten blocks into one segment, the data is
+        // not changed, so the row count and the column statistics are not changed either
+        let mut rewritten_compressed_size = 0;
+        let mut rewritten_uncompressed_size = 0;
+        for block in &small_blocks {
+            rewritten_compressed_size += Self::object_size(da.as_ref(), block).await?;
+            rewritten_uncompressed_size += block.block_size;
+        }
+
+        let prev_summary = &prev_snapshot.summary;
+        let mut blocks = kept_blocks;
+        blocks.extend(new_segment.blocks);
+        let summary = Stats {
+            row_count: prev_summary.row_count,
+            block_count: blocks.len() as u64,
+            uncompressed_byte_size: prev_summary
+                .uncompressed_byte_size
+                .saturating_sub(rewritten_uncompressed_size)
+                + new_segment.summary.uncompressed_byte_size,
+            compressed_byte_size: prev_summary
+                .compressed_byte_size
+                .saturating_sub(rewritten_compressed_size)
+                + new_segment.summary.compressed_byte_size,
+            col_stats: prev_summary.col_stats.clone(),
+        };
+
+        let segment_info = SegmentInfo {
+            blocks,
+            summary: summary.clone(),
+        };
+        let seg_loc = util::gen_segment_info_location(&location_prefix);
+        let bytes = serde_json::to_vec(&segment_info)?;
+        da.put(&seg_loc, bytes).await?;
+
+        // 4. new snapshot, which points at the previous one
+        let prev_id = prev_snapshot.snapshot_id;
+        let mut new_snapshot = prev_snapshot;
+        new_snapshot.snapshot_id = Uuid::new_v4();
+        new_snapshot.prev_snapshot_id = Some(prev_id);
+        new_snapshot.timestamp = Some(Utc::now());
+        new_snapshot.summary = summary;
+        new_snapshot.segments = vec![seg_loc];
+
+        let snapshot_loc = util::snapshot_location(
+            &location_prefix,
+            new_snapshot.snapshot_id.to_simple().to_string(),
+        );
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        da.put(&snapshot_loc, bytes).await?;
+
+        // 5. commit
+        commit(
+            ctx,
+            self.get_id(),
+            self.table_info.ident.version,
+            snapshot_loc,
+        )
+        .await
+    }
+
+    pub(crate) async fn read_blocks(
+        da: Arc<dyn DataAccessor>,
+        block_metas: &[BlockMeta],
+        schema: &DataSchemaRef,
+    ) -> Result<Vec<DataBlock>> {
+        let projection = (0..schema.fields().len()).collect::<Vec<usize>>();
+
+        let mut blocks = vec![];
+        for block_meta in block_metas {
+            let mut source = ParquetSource::new(
+                da.clone(),
+                block_meta.location.location.clone(),
+                schema.clone(),
+                projection.clone(),
+            );
+            while let Some(block) = source.read().await? {
+                blocks.push(block);
+            }
+        }
+        Ok(blocks)
+    }
+
+    // The size of the parquet file of the block, which is only looked up in the storage for
+    // the blocks written before the size was recorded in the block meta
+    pub(crate) async fn object_size(da: &dyn DataAccessor, block_meta: &BlockMeta) -> Result<u64> {
+        match block_meta.file_size {
+            0 => Ok(da.head(&block_meta.location.location).await?.size),
+            file_size => Ok(file_size),
+        }
+    }
+}
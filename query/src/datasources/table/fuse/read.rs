@@ -16,8 +16,12 @@
 use std::sync::Arc;
 
 use async_stream::stream;
-use common_datavalues::DataSchema;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_streams::ParquetSource;
 use common_streams::SendableDataBlockStream;
@@ -25,6 +29,8 @@ use common_streams::Source;
 use futures::StreamExt;
 
 use crate::datasources::table::fuse::FuseTable;
+use crate::optimizers::RequireColumnsVisitor;
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::DatabendQueryContextRef;
 
 impl FuseTable {
@@ -66,10 +72,28 @@ impl FuseTable {
         let da = ctx.get_data_accessor()?;
         let arrow_schema = self.table_info.schema().to_arrow();
         let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        let prewhere = Prewhere::try_create(push_downs, &projection, &table_schema)?;
 
         let mut iter = futures::stream::iter(iter);
         let stream = stream! {
             while let Some(part) = iter.next().await {
+                if let Some(prewhere) = &prewhere {
+                    let (mut filter_source, mut remain_source) =
+                        prewhere.sources(da.clone(), &part.name, &table_schema);
+                    loop {
+                        let block = prewhere.read(&mut filter_source, &mut remain_source).await;
+                        match block {
+                            Ok(None) => break,
+                            Ok(Some(b)) => yield(Ok(b)),
+                            Err(e) => {
+                                yield(Err(e));
+                                break;
+                            }
+                        }
+                    }
+                    continue;
+                }
+
                 let mut source = ParquetSource::new(
                     da.clone(),
                     part.name.clone(),
@@ -89,3 +113,189 @@ impl FuseTable {
         Ok(Box::pin(stream))
     }
 }
+
+/// Late materialization of the fuse blocks: the columns of the prewhere are read and filtered
+/// first, the other projected columns are only read for the blocks with matching rows.
+pub(super) struct Prewhere {
+    projection: Vec<usize>,
+    filter_columns: Vec<usize>,
+    remain_columns: Vec<usize>,
+    output_schema: DataSchemaRef,
+    executor: ExpressionExecutor,
+}
+
+impl Prewhere {
+    /// The explicit prewhere is always applied. Otherwise, the pushed down filters are used as
+    /// the prewhere if they only need some of the projected columns, that's where it pays off.
+    pub(super) fn try_create(
+        push_downs: &Option<Extras>,
+        projection: &[usize],
+        table_schema: &DataSchemaRef,
+    ) -> Result<Option<Self>> {
+        let (exprs, explicit) = match push_downs {
+            Some(extras) if !extras.prewhere.is_empty() => (&extras.prewhere, true),
+            Some(extras) if !extras.filters.is_empty() => (&extras.filters, false),
+            _ => return Ok(None),
+        };
+
+        let mut filter_columns = vec![];
+        for expr in exprs {
+            for name in RequireColumnsVisitor::collect_columns_from_expr(expr)? {
+                let idx = table_schema.index_of(&name)?;
+                if !filter_columns.contains(&idx) {
+                    filter_columns.push(idx);
+                }
+            }
+        }
+        filter_columns.sort_unstable();
+
+        // The rows of the columns not projected can't be taken back to the upstream.
+        if filter_columns.is_empty() || filter_columns.iter().any(|c| !projection.contains(c)) {
+            return Ok(None);
+        }
+
+        let remain_columns = projection
+            .iter()
+            .filter(|c| !filter_columns.contains(c))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !explicit && remain_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let predicate = exprs
+            .iter()
+            .cloned()
+            .reduce(|acc, expr| acc.and(expr))
+            .unwrap_or_else(|| Expression::create_literal(DataValue::Boolean(Some(true))));
+        let input_schema = Arc::new(table_schema.project(filter_columns.clone()));
+        let output_fields = vec![predicate.to_data_field(&input_schema)?];
+        let executor = ExpressionExecutor::try_create(
+            "prewhere expression executor",
+            input_schema,
+            DataSchemaRefExt::create(output_fields),
+            vec![predicate],
+            false,
+        )?;
+        executor.validate()?;
+
+        Ok(Some(Self {
+            projection: projection.to_vec(),
+            filter_columns,
+            remain_columns,
+            output_schema: Arc::new(table_schema.project(projection.to_vec())),
+            executor,
+        }))
+    }
+
+    /// The sources of the prewhere columns and of the other projected columns of the block.
+    pub(super) fn sources(
+        &self,
+        da: Arc<dyn DataAccessor>,
+        location: &str,
+        table_schema: &DataSchemaRef,
+    ) -> (ParquetSource, Option<ParquetSource>) {
+        let filter_source = ParquetSource::new(
+            da.clone(),
+            location.to_string(),
+            table_schema.clone(),
+            self.filter_columns.clone(),
+        );
+        let remain_source = match self.remain_columns.is_empty() {
+            true => None,
+            false => Some(ParquetSource::new(
+                da,
+                location.to_string(),
+                table_schema.clone(),
+                self.remain_columns.clone(),
+            )),
+        };
+        (filter_source, remain_source)
+    }
+
+    /// Reads the next block with matching rows, the row groups without any are skipped.
+    pub(super) async fn read(
+        &self,
+        filter_source: &mut ParquetSource,
+        remain_source: &mut Option<ParquetSource>,
+    ) -> Result<Option<DataBlock>> {
+        loop {
+            let filter_block = match filter_source.read().await? {
+                None => return Ok(None),
+                Some(block) => block,
+            };
+
+            let indices = self.matched_rows(&filter_block)?;
+            if indices.is_empty() {
+                if let Some(source) = remain_source {
+                    source.skip().await?;
+                }
+                continue;
+            }
+
+            let remain_block = match remain_source {
+                None => None,
+                Some(source) => match source.read().await? {
+                    Some(block) => Some(block),
+                    None => {
+                        return Err(ErrorCode::ParquetError(
+                            "The row groups of the prewhere columns mismatch",
+                        ))
+                    }
+                },
+            };
+
+            return self.take(filter_block, remain_block, &indices).map(Some);
+        }
+    }
+
+    // The rows which the prewhere evaluates to NULL do not match.
+    fn matched_rows(&self, block: &DataBlock) -> Result<Vec<u32>> {
+        let matched = self
+            .executor
+            .execute(block)?
+            .column(0)
+            .to_array()?
+            .cast_with_type(&DataType::Boolean)?;
+        Ok(matched
+            .bool()?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(row, v)| match v {
+                Some(true) => Some(row as u32),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn take(
+        &self,
+        filter_block: DataBlock,
+        remain_block: Option<DataBlock>,
+        indices: &[u32],
+    ) -> Result<DataBlock> {
+        let take = |block: DataBlock| match indices.len() == block.num_rows() {
+            true => Ok(block),
+            false => DataBlock::block_take_by_indices(&block, &[], indices),
+        };
+        let filter_block = take(filter_block)?;
+        let remain_block = remain_block.map(take).transpose()?;
+
+        let mut columns = Vec::with_capacity(self.projection.len());
+        for column in &self.projection {
+            let filter_pos = self.filter_columns.iter().position(|c| c == column);
+            let remain_pos = self.remain_columns.iter().position(|c| c == column);
+            match (filter_pos, remain_pos, &remain_block) {
+                (Some(pos), _, _) => columns.push(filter_block.column(pos).clone()),
+                (None, Some(pos), Some(block)) => columns.push(block.column(pos).clone()),
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Logical error: column {} is not read by the prewhere",
+                        column
+                    )))
+                }
+            }
+        }
+        Ok(DataBlock::create(self.output_schema.clone(), columns))
+    }
+}
@@ -48,6 +48,7 @@ fn test_to_partitions() -> Result<()> {
             .iter()
             .map(|(_, col_stats)| col_stats.in_memory_size)
             .sum(),
+        file_size: 0,
         col_stats: cols_stats.clone(),
         location: BlockLocation {
             location: "".to_string(),
@@ -86,6 +87,7 @@ fn test_to_partitions() -> Result<()> {
     let push_down = Some(Extras {
         projection: Some(proj),
         filters: vec![],
+        prewhere: vec![],
         limit: None,
         order_by: vec![],
     });
@@ -14,19 +14,25 @@
 //
 
 use std::any::Any;
+use std::sync::Arc;
 
 use common_dal::read_obj;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::DeletePlan;
 use common_planners::Extras;
 use common_planners::InsertIntoPlan;
+use common_planners::OptimizeTablePlan;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
 use common_streams::SendableDataBlockStream;
 
 use super::util;
+use crate::catalogs::NavigationPoint;
 use crate::catalogs::Table;
 use crate::datasources::context::TableContext;
 use crate::datasources::table::fuse::TableSnapshot;
@@ -92,9 +98,45 @@ impl Table for FuseTable {
     ) -> Result<()> {
         self.do_truncate(ctx, truncate_plan).await
     }
+
+    async fn delete(&self, ctx: DatabendQueryContextRef, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, delete_plan).await
+    }
+
+    async fn update(&self, ctx: DatabendQueryContextRef, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, update_plan).await
+    }
+
+    async fn optimize(
+        &self,
+        ctx: DatabendQueryContextRef,
+        optimize_plan: OptimizeTablePlan,
+    ) -> Result<()> {
+        self.do_optimize(ctx, optimize_plan).await
+    }
+
+    async fn vacuum(
+        &self,
+        ctx: DatabendQueryContextRef,
+        vacuum_plan: VacuumTablePlan,
+    ) -> Result<()> {
+        self.do_vacuum(ctx, vacuum_plan).await
+    }
+
+    fn navigate_to(
+        &self,
+        ctx: DatabendQueryContextRef,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        self.do_navigate_to(ctx, point)
+    }
 }
 
 impl FuseTable {
+    pub(crate) fn location_prefix(&self) -> String {
+        util::table_location_prefix(self.get_id())
+    }
+
     pub(crate) fn snapshot_loc(&self) -> Option<String> {
         self.table_info
             .options()
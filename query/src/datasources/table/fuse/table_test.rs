@@ -14,14 +14,36 @@
 //
 
 use common_base::tokio;
+use common_dal::read_obj;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::add;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::DeletePlan;
+use common_planners::Extras;
+use common_planners::OptimizeTablePlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
+use common_streams::Source;
 use futures::TryStreamExt;
 
 use crate::catalogs::Catalog;
+use crate::catalogs::NavigationPoint;
 use crate::catalogs::ToReadDataSourcePlan;
+use crate::datasources::table::fuse::read::Prewhere;
 use crate::datasources::table::fuse::table_test_fixture::TestFixture;
+use crate::datasources::table::fuse::util;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::SegmentInfo;
 
 #[tokio::test]
 async fn test_fuse_table_simple_case() -> Result<()> {
@@ -110,6 +132,186 @@ async fn test_fuse_table_simple_case() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_table_prewhere() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // insert 2 blocks of [1, 2, 3]
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+    let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(2)));
+    table
+        .append_data(ctx.clone(), insert_into_plan, stream)
+        .await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+
+    let read_with_prewhere = |prewhere| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let (_, parts) = table.read_partitions(ctx.clone(), None)?;
+            ctx.try_set_partitions(parts)?;
+            let stream = table
+                .read(ctx, &ReadDataSourcePlan {
+                    table_info: Default::default(),
+                    scan_fields: None,
+                    parts: Default::default(),
+                    statistics: Default::default(),
+                    description: "".to_string(),
+                    tbl_args: None,
+                    push_downs: Some(Extras {
+                        prewhere: vec![prewhere],
+                        ..Extras::default()
+                    }),
+                })
+                .await?;
+            stream.try_collect::<Vec<_>>().await
+        }
+    };
+
+    // only the matching rows are taken
+    let blocks = read_with_prewhere(col("id").gt(lit(1i32))).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 2  |", //
+        "| 2  |", //
+        "| 3  |", //
+        "| 3  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the blocks without any matching rows are skipped
+    let blocks = read_with_prewhere(col("id").gt(lit(3i32))).await?;
+    assert!(blocks.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_prewhere_remain_columns() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::Int32, false),
+        DataField::new("name", DataType::String, false),
+        DataField::new("score", DataType::Int64, false),
+    ]);
+    let mut crate_table_plan = fixture.default_crate_table_plan();
+    crate_table_plan.table_meta.schema = schema.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // insert 2 blocks, the ids of the first one are [1, 2, 3], and of the second one [4, 5, 6]
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let mut insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+    insert_into_plan.schema = schema.clone();
+    let blocks = vec![
+        Ok(DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![1i32, 2, 3]),
+            Series::new(vec!["a", "b", "c"]),
+            Series::new(vec![10i64, 20, 30]),
+        ])),
+        Ok(DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![4i32, 5, 6]),
+            Series::new(vec!["d", "e", "f"]),
+            Series::new(vec![40i64, 50, 60]),
+        ])),
+    ];
+    let stream = Box::pin(futures::stream::iter(blocks));
+    table
+        .append_data(ctx.clone(), insert_into_plan, stream)
+        .await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+
+    // the prewhere column `id` is read first, then `score` and `name` of the matching rows,
+    // and the columns are put back in the order of the projection
+    let push_downs = Some(Extras {
+        projection: Some(vec![2, 1, 0]),
+        prewhere: vec![col("id").gt(lit(2i32))],
+        ..Extras::default()
+    });
+    let (_, parts) = table.read_partitions(ctx.clone(), push_downs.clone())?;
+    ctx.try_set_partitions(parts.clone())?;
+    let stream = table
+        .read(ctx.clone(), &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs,
+        })
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+-------+------+----+",
+        "| score | name | id |",
+        "+-------+------+----+",
+        "| 30    | c    | 3  |",
+        "| 40    | d    | 4  |",
+        "| 50    | e    | 5  |",
+        "| 60    | f    | 6  |",
+        "+-------+------+----+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the row group without any matching rows is skipped by the source of the remaining columns
+    let push_downs = Some(Extras {
+        prewhere: vec![col("id").gt(lit(3i32))],
+        ..Extras::default()
+    });
+    let prewhere = Prewhere::try_create(&push_downs, &[0, 1, 2], &schema)?.unwrap();
+    let da = ctx.get_data_accessor()?;
+    let mut num_rows = 0;
+    for part in &parts {
+        let (mut filter_source, mut remain_source) =
+            prewhere.sources(da.clone(), &part.name, &schema);
+        while let Some(block) = prewhere
+            .read(&mut filter_source, &mut remain_source)
+            .await?
+        {
+            assert_eq!(block.schema(), &schema);
+            num_rows += block.num_rows();
+        }
+        assert!(remain_source.unwrap().read().await?.is_none());
+    }
+    assert_eq!(num_rows, 3);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fuse_table_truncate() -> Result<()> {
     let fixture = TestFixture::new().await;
@@ -191,3 +393,586 @@ async fn test_fuse_table_truncate() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_table_optimize() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    let optimize_plan = OptimizeTablePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+    };
+
+    // 1. optimize empty table
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let prev_version = table.get_table_info().ident.version;
+    let r = table.optimize(ctx.clone(), optimize_plan.clone()).await;
+    assert!(r.is_ok());
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    // no side effects
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 2. insert 10 times, each insertion generates a segment of one small block
+    let num_inserts = 10;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let prev_snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert_eq!(prev_snapshot.segments.len(), num_inserts);
+    let (stats, parts) = table.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), num_inserts);
+    assert_eq!(stats.read_rows, num_inserts * 3);
+
+    // 3. optimize, the small blocks are merged into one block of one segment
+    table.optimize(ctx.clone(), optimize_plan).await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert_eq!(snapshot.segments.len(), 1);
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.summary.row_count, prev_snapshot.summary.row_count);
+    assert_eq!(snapshot.summary.block_count, 1);
+
+    // the file sizes of the blocks are recorded, all the small blocks are rewritten
+    let da = ctx.get_data_accessor()?;
+    let segment: SegmentInfo = read_obj(da.clone(), snapshot.segments[0].clone()).await?;
+    let block_meta = &segment.blocks[0];
+    let file_size = da.head(&block_meta.location.location).await?.size;
+    assert_eq!(block_meta.file_size, file_size);
+    assert_eq!(snapshot.summary.compressed_byte_size, file_size);
+
+    let (stats, parts) = table.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), 1);
+    assert_eq!(stats.read_rows, num_inserts * 3);
+
+    // inject partitions to current ctx
+    ctx.try_set_partitions(parts)?;
+    let stream = table
+        .read(ctx, &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs: None,
+        })
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, num_inserts * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_time_travel() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // 1. insert 3 times, each insertion generates a new snapshot
+    let num_inserts = 3;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let history = fuse_table.snapshot_history(ctx.clone()).await?;
+    assert_eq!(history.len(), num_inserts);
+    assert_eq!(history[num_inserts - 1].0.prev_snapshot_id, None);
+    for (idx, (snapshot, _)) in history.iter().enumerate() {
+        assert!(snapshot.timestamp.is_some());
+        assert_eq!(snapshot.segments.len(), num_inserts - idx);
+    }
+
+    // 2. navigate by snapshot id, to the first snapshot
+    let (first_snapshot, _) = &history[num_inserts - 1];
+    let point = NavigationPoint::SnapshotID(first_snapshot.snapshot_id.to_simple().to_string());
+    let first = table.navigate_to(ctx.clone(), &point)?;
+    let (stats, parts) = first.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), 1);
+    assert_eq!(stats.read_rows, 3);
+
+    // 3. navigate by timestamp, to the second snapshot
+    let (second_snapshot, _) = &history[num_inserts - 2];
+    let point = NavigationPoint::TimePoint(second_snapshot.timestamp.unwrap());
+    let second = table.navigate_to(ctx.clone(), &point)?;
+    let (stats, parts) = second.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), 2);
+    assert_eq!(stats.read_rows, 6);
+
+    // 4. points out of the history
+    let point = NavigationPoint::TimePoint(
+        first_snapshot.timestamp.unwrap() - chrono::Duration::seconds(1),
+    );
+    let r = table.navigate_to(ctx.clone(), &point);
+    assert_eq!(
+        r.err().unwrap().code(),
+        ErrorCode::TableHistoricalDataNotFound("").code()
+    );
+
+    let point = NavigationPoint::SnapshotID("not a uuid".to_string());
+    let r = table.navigate_to(ctx.clone(), &point);
+    assert_eq!(r.err().unwrap().code(), ErrorCode::BadArguments("").code());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_vacuum() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // 1. insert 3 times and then compact, each of them generates a snapshot, a segment and a block
+    let num_inserts = 3;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    table
+        .optimize(ctx.clone(), OptimizeTablePlan {
+            db: "".to_string(),
+            table: "".to_string(),
+        })
+        .await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let da = ctx.get_data_accessor()?;
+    let table_prefix = format!("{}/", fuse_table.location_prefix());
+    let num_objects = (num_inserts + 1) * 3;
+    assert_eq!(da.list(&table_prefix).await?.len(), num_objects);
+
+    // 2. nothing is purged inside of the default retention window
+    let mut vacuum_plan = VacuumTablePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+        retain_hours: None,
+    };
+    table.vacuum(ctx.clone(), vacuum_plan.clone()).await?;
+    assert_eq!(da.list(&table_prefix).await?.len(), num_objects);
+    assert_eq!(
+        fuse_table.snapshot_history(ctx.clone()).await?.len(),
+        num_inserts + 1
+    );
+
+    // 3. only the current snapshot, and the segment and the block it refers to are kept
+    vacuum_plan.retain_hours = Some(0);
+    table.vacuum(ctx.clone(), vacuum_plan).await?;
+    assert_eq!(da.list(&table_prefix).await?.len(), 3);
+    assert_eq!(fuse_table.snapshot_history(ctx.clone()).await?.len(), 1);
+
+    let (stats, parts) = table.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), 1);
+    assert_eq!(stats.read_rows, num_inserts * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_legacy_snapshot_history() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // 1. insert 2 times, each insertion generates a new snapshot
+    let num_inserts = 2;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    // 2. move the first snapshot to the location it had before the table prefix was introduced
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let da = ctx.get_data_accessor()?;
+    let history = fuse_table.snapshot_history(ctx.clone()).await?;
+    let (first_snapshot, first_snapshot_loc) = &history[num_inserts - 1];
+    let legacy_loc =
+        util::legacy_snapshot_location(first_snapshot.snapshot_id.to_simple().to_string());
+    da.put(&legacy_loc, da.read(first_snapshot_loc).await?)
+        .await?;
+    da.delete(first_snapshot_loc).await?;
+
+    // 3. the history still reaches the first snapshot
+    let history = fuse_table.snapshot_history(ctx.clone()).await?;
+    assert_eq!(history.len(), num_inserts);
+    assert_eq!(history[num_inserts - 1].1, legacy_loc);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_delete() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // insert 3 times, each insertion generates a segment of one block [1, 2, 3]
+    let num_inserts = 3;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    let delete_plan = |table_id, selection| DeletePlan {
+        db_name: fixture.default_db(),
+        tbl_name: fixture.default_table(),
+        tbl_id: table_id,
+        selection: Some(selection),
+    };
+
+    // 1. no block may contain the matching rows, nothing changed
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let prev_version = table.get_table_info().ident.version;
+    let plan = delete_plan(table.get_id(), col("id").eq(lit(5i32)));
+    table.delete(ctx.clone(), plan).await?;
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 2. delete one row of each block, all the blocks are rewritten
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let prev_snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    let plan = delete_plan(table.get_id(), col("id").eq(lit(2i32)));
+    table.delete(ctx.clone(), plan).await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.segments.len(), num_inserts);
+    assert_eq!(snapshot.summary.row_count, (num_inserts * 2) as u64);
+    assert_eq!(snapshot.summary.block_count, num_inserts as u64);
+    assert!(snapshot
+        .segments
+        .iter()
+        .all(|seg_loc| !prev_snapshot.segments.contains(seg_loc)));
+
+    let (stats, parts) = table.read_partitions(ctx.clone(), None)?;
+    assert_eq!(stats.read_rows, num_inserts * 2);
+    ctx.try_set_partitions(parts)?;
+    let stream = table
+        .read(ctx.clone(), &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs: None,
+        })
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 1  |", //
+        "| 1  |", //
+        "| 3  |", //
+        "| 3  |", //
+        "| 3  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // 3. the blocks may contain the matching rows, but none of the rows matches
+    let prev_version = table.get_table_info().ident.version;
+    let plan = delete_plan(table.get_id(), col("id").eq(lit(2i32)));
+    table.delete(ctx.clone(), plan).await?;
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 4. delete all the rows
+    let plan = delete_plan(table.get_id(), col("id").gt(lit(0i32)));
+    table.delete(ctx.clone(), plan).await?;
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert!(snapshot.segments.is_empty());
+    assert_eq!(snapshot.summary.row_count, 0);
+    let (stats, parts) = table.read_partitions(ctx, None)?;
+    assert_eq!(stats.read_rows, 0);
+    assert!(parts.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_update() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // insert 2 times, each insertion generates a segment of one block [1, 2, 3]
+    let num_inserts = 2;
+    for _ in 0..num_inserts {
+        let table = catalog
+            .get_table(
+                fixture.default_db().as_str(),
+                fixture.default_table().as_str(),
+            )
+            .await?;
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table
+            .append_data(ctx.clone(), insert_into_plan, stream)
+            .await?;
+    }
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let prev_snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+
+    let update_plan = UpdatePlan {
+        db_name: fixture.default_db(),
+        tbl_name: fixture.default_table(),
+        tbl_id: table.get_id(),
+        assignments: vec![("id".to_string(), add(col("id"), lit(10i32)))],
+        selection: Some(col("id").eq(lit(2i32))),
+    };
+    table.update(ctx.clone(), update_plan).await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.segments.len(), num_inserts);
+    assert_eq!(snapshot.summary.row_count, (num_inserts * 3) as u64);
+
+    let (_, parts) = table.read_partitions(ctx.clone(), None)?;
+    ctx.try_set_partitions(parts)?;
+    let stream = table
+        .read(ctx.clone(), &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs: None,
+        })
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 1  |", //
+        "| 12 |", //
+        "| 12 |", //
+        "| 3  |", //
+        "| 3  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the min/max statistics are updated as well, no block may contain 2 any more
+    let push_downs = Extras {
+        filters: vec![col("id").eq(lit(2i32))],
+        ..Extras::default()
+    };
+    let (stats, _) = table.read_partitions(ctx.clone(), Some(push_downs))?;
+    assert_eq!(stats.read_rows, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_concurrent_append() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    // every appender but the winner of each round conflicts, leave enough room for them
+    ctx.get_settings().set_max_commit_retries(20)?;
+
+    let crate_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+
+    // all the appenders start from the same version of table
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+
+    let num_appenders = 8;
+    let appends = (0..num_appenders).map(|_| {
+        let insert_into_plan = fixture.insert_plan_of_table(table.as_ref());
+        let stream = Box::pin(futures::stream::iter(TestFixture::gen_block_stream(1)));
+        table.append_data(ctx.clone(), insert_into_plan, stream)
+    });
+    futures::future::try_join_all(appends).await?;
+
+    // no segment should be lost
+    let table = catalog
+        .get_table(
+            fixture.default_db().as_str(),
+            fixture.default_table().as_str(),
+        )
+        .await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.table_snapshot(ctx.clone()).await?.unwrap();
+    assert_eq!(snapshot.segments.len(), num_appenders);
+    assert_eq!(snapshot.summary.row_count, (num_appenders * 3) as u64);
+
+    let (stats, parts) = table.read_partitions(ctx.clone(), None)?;
+    assert_eq!(parts.len(), num_appenders);
+    assert_eq!(stats.read_rows, num_appenders * 3);
+
+    Ok(())
+}
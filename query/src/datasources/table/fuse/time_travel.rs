@@ -0,0 +1,140 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::BlockingWait;
+use common_dal::read_obj;
+use common_dal::DataAccessor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::catalogs::NavigationPoint;
+use crate::catalogs::Table;
+use crate::datasources::table::fuse::util;
+use crate::datasources::table::fuse::util::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::TableSnapshot;
+use crate::sessions::DatabendQueryContextRef;
+
+impl FuseTable {
+    /// Snapshots of the table along with their locations, from the current one back to the first one
+    pub(crate) async fn snapshot_history(
+        &self,
+        ctx: DatabendQueryContextRef,
+    ) -> Result<Vec<(TableSnapshot, String)>> {
+        let da = ctx.get_data_accessor()?;
+        read_snapshot_history(da, self.location_prefix(), self.snapshot_loc(), |_| false).await
+    }
+
+    #[inline]
+    pub fn do_navigate_to(
+        &self,
+        ctx: DatabendQueryContextRef,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        let snapshot_id = match point {
+            NavigationPoint::SnapshotID(id) => Some(Uuid::parse_str(id).map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid snapshot id {}: {}", id, e))
+            })?),
+            NavigationPoint::TimePoint(_) => None,
+        };
+        let at_point = {
+            let point = point.clone();
+            move |snapshot: &TableSnapshot| match &point {
+                NavigationPoint::SnapshotID(_) => Some(snapshot.snapshot_id) == snapshot_id,
+                NavigationPoint::TimePoint(time_point) => {
+                    matches!(snapshot.timestamp, Some(ts) if ts <= *time_point)
+                }
+            }
+        };
+
+        // the walk stops at the snapshot of the point, which is the last one read
+        let da = ctx.get_data_accessor()?;
+        let mut history = read_snapshot_history(
+            da,
+            self.location_prefix(),
+            self.snapshot_loc(),
+            at_point.clone(),
+        )
+        .wait_in(&ctx.get_shared_runtime()?, None)??;
+
+        let location = history
+            .pop()
+            .filter(|(snapshot, _)| at_point(snapshot))
+            .map(|(_, loc)| loc)
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "no historical data of table {} found at {:?}",
+                    self.name(),
+                    point
+                ))
+            })?;
+
+        let mut table_info = self.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(TBL_OPT_KEY_SNAPSHOT_LOC.to_string(), location);
+        Ok(Arc::new(FuseTable { table_info }))
+    }
+}
+
+/// Snapshots along with their locations, from the given one back to the first one, or to the
+/// one which `stop_at` is true for
+async fn read_snapshot_history<F>(
+    da: Arc<dyn DataAccessor>,
+    location_prefix: String,
+    mut location: Option<String>,
+    stop_at: F,
+) -> Result<Vec<(TableSnapshot, String)>>
+where
+    F: Fn(&TableSnapshot) -> bool,
+{
+    let mut history = vec![];
+    while let Some(loc) = location {
+        let snapshot: TableSnapshot = read_obj(da.clone(), loc.clone()).await?;
+        location = match snapshot.prev_snapshot_id {
+            Some(id) if !stop_at(&snapshot) => {
+                prev_snapshot_location(da.as_ref(), &location_prefix, id).await?
+            }
+            _ => None,
+        };
+        history.push((snapshot, loc));
+    }
+    Ok(history)
+}
+
+async fn prev_snapshot_location(
+    da: &dyn DataAccessor,
+    location_prefix: &str,
+    prev_snapshot_id: Uuid,
+) -> Result<Option<String>> {
+    // the snapshots of the tables created before the objects were kept under the table prefix
+    // are still at their legacy locations, and the snapshots out of the retention window may
+    // have been purged by `VACUUM TABLE`, in which case the history ends at the oldest one left
+    let name = prev_snapshot_id.to_simple().to_string();
+    let candidates = [
+        util::snapshot_location(location_prefix, &name),
+        util::legacy_snapshot_location(&name),
+    ];
+    for loc in candidates {
+        if da.exists(&loc).await? {
+            return Ok(Some(loc));
+        }
+    }
+    Ok(None)
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use chrono::Utc;
 use common_exception::Result;
 use common_planners::TruncateTablePlan;
 use uuid::Uuid;
@@ -36,10 +37,13 @@ impl FuseTable {
             let mut new_snapshot = prev_snapshot;
             new_snapshot.segments = vec![];
             new_snapshot.prev_snapshot_id = Some(prev_id);
+            new_snapshot.timestamp = Some(Utc::now());
             new_snapshot.summary = Default::default();
             new_snapshot.snapshot_id = Uuid::new_v4();
-            let new_snapshot_loc =
-                util::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str()); // TODO refine this
+            let new_snapshot_loc = util::snapshot_location(
+                &self.location_prefix(),
+                new_snapshot.snapshot_id.to_simple().to_string(),
+            );
             let da = ctx.get_data_accessor()?;
             let bytes = serde_json::to_vec(&new_snapshot)?;
             da.put(&new_snapshot_loc, bytes).await?;
@@ -0,0 +1,33 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::Result;
+use common_planners::UpdatePlan;
+
+use crate::datasources::common::BlockMutator;
+use crate::datasources::table::fuse::FuseTable;
+use crate::sessions::DatabendQueryContextRef;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_update(&self, ctx: DatabendQueryContextRef, plan: UpdatePlan) -> Result<()> {
+        let mutator = BlockMutator::try_create_update(
+            self.table_info.schema(),
+            &plan.assignments,
+            plan.selection.as_ref(),
+        )?;
+        self.do_mutate(ctx, plan.selection, &mutator).await
+    }
+}
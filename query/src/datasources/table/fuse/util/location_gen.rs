@@ -13,22 +13,41 @@
 //  limitations under the License.
 //
 
+use common_meta_types::MetaId;
 use uuid::Uuid;
 
 const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 
-pub fn gen_unique_block_location() -> String {
+/// All the objects (snapshots, segments and blocks) of a table are kept under this prefix
+pub fn table_location_prefix(table_id: MetaId) -> String {
+    format!("{}", table_id)
+}
+
+pub fn gen_unique_block_location(table_prefix: &str) -> String {
     let part_uuid = Uuid::new_v4().to_simple().to_string() + ".parquet";
-    format!("{}/{}", FUSE_TBL_BLOCK_PREFIX, part_uuid)
+    format!("{}/{}/{}", table_prefix, FUSE_TBL_BLOCK_PREFIX, part_uuid)
 }
 
-pub fn gen_segment_info_location() -> String {
+pub fn gen_segment_info_location(table_prefix: &str) -> String {
     let segment_uuid = Uuid::new_v4().to_simple().to_string();
-    format!("{}/{}", FUSE_TBL_SEGMENT_PREFIX, segment_uuid)
+    format!(
+        "{}/{}/{}",
+        table_prefix, FUSE_TBL_SEGMENT_PREFIX, segment_uuid
+    )
+}
+
+pub fn snapshot_location(table_prefix: &str, name: impl AsRef<str>) -> String {
+    format!(
+        "{}/{}/{}",
+        table_prefix,
+        FUSE_TBL_SNAPSHOT_PREFIX,
+        name.as_ref()
+    )
 }
 
-pub fn snapshot_location(name: impl AsRef<str>) -> String {
+/// Location of a snapshot written before the objects of a table were kept under the table prefix
+pub fn legacy_snapshot_location(name: impl AsRef<str>) -> String {
     format!("{}/{}", FUSE_TBL_SNAPSHOT_PREFIX, name.as_ref())
 }
@@ -89,6 +89,7 @@ impl BlockMetaAccumulator {
             },
             row_count: stats.last_block_rows,
             block_size: stats.last_block_size,
+            file_size,
             col_stats: stats.last_block_col_stats.take().unwrap_or_default(),
         };
         self.blocks_metas.push(block_meta);
@@ -0,0 +1,75 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_dal::read_obj;
+use common_exception::Result;
+use common_planners::VacuumTablePlan;
+
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::SegmentInfo;
+use crate::sessions::DatabendQueryContextRef;
+
+/// The retention of `VACUUM TABLE`, if `RETAIN n HOURS` is not specified
+const DEFAULT_RETAIN_HOURS: u64 = 7 * 24;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_vacuum(
+        &self,
+        ctx: DatabendQueryContextRef,
+        vacuum_plan: VacuumTablePlan,
+    ) -> Result<()> {
+        let retain_hours = vacuum_plan.retain_hours.unwrap_or(DEFAULT_RETAIN_HOURS);
+        let retention_start = Utc::now() - Duration::hours(retain_hours as i64);
+        let da = ctx.get_data_accessor()?;
+
+        // 1. collect the locations reachable from the snapshots inside the retention window,
+        // and from the snapshot which was the current one at the beginning of the window, so
+        // that the time travel queries inside the window still work
+        let mut reachable = HashSet::new();
+        for (snapshot, snapshot_loc) in self.snapshot_history(ctx.clone()).await? {
+            reachable.insert(snapshot_loc);
+            for seg_loc in snapshot.segments {
+                // segments are shared by the successive snapshots, read each of them only once
+                if reachable.insert(seg_loc.clone()) {
+                    let segment: SegmentInfo = read_obj(da.clone(), seg_loc).await?;
+                    reachable.extend(segment.blocks.into_iter().map(|b| b.location.location));
+                }
+            }
+
+            if !matches!(snapshot.timestamp, Some(ts) if ts > retention_start) {
+                break;
+            }
+        }
+
+        // 2. purge the unreachable objects which are older than the retention window, the
+        // recent ones are kept, since they may be written by the insertions not committed yet.
+        // The objects written before the table prefix was introduced are out of it, and kept
+        let table_prefix = format!("{}/", self.location_prefix());
+        let garbage = da
+            .list(&table_prefix)
+            .await?
+            .into_iter()
+            .filter(|o| matches!(o.last_modified, Some(t) if t < retention_start))
+            .filter(|o| !reachable.contains(&o.path))
+            .map(|o| o.path)
+            .collect::<Vec<_>>();
+        da.batch_delete(&garbage).await
+    }
+}
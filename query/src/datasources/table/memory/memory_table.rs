@@ -28,11 +28,13 @@ use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 use futures::stream::StreamExt;
 
 use crate::catalogs::Table;
 use crate::datasources::common::generate_parts;
+use crate::datasources::common::BlockMutator;
 use crate::datasources::context::TableContext;
 use crate::datasources::table::memory::memory_table_stream::MemoryTableStream;
 use crate::sessions::DatabendQueryContextRef;
@@ -144,4 +146,24 @@ impl Table for MemoryTable {
         blocks.clear();
         Ok(())
     }
+
+    async fn update(&self, _ctx: DatabendQueryContextRef, update_plan: UpdatePlan) -> Result<()> {
+        let mutator = BlockMutator::try_create_update(
+            self.table_info.schema(),
+            &update_plan.assignments,
+            update_plan.selection.as_ref(),
+        )?;
+
+        // update in place, the blocks are replaced only if all of them are updated successfully
+        let mut blocks = self.blocks.write();
+        let mut updated_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks.iter() {
+            match mutator.mutate(block)? {
+                Some(updated) => updated_blocks.push(updated),
+                None => updated_blocks.push(block.clone()),
+            }
+        }
+        *blocks = updated_blocks;
+        Ok(())
+    }
 }
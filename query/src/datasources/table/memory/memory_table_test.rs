@@ -98,6 +98,36 @@ async fn test_memorytable() -> Result<()> {
         );
     }
 
+    // update.
+    {
+        let update_plan = UpdatePlan {
+            db_name: "default".to_string(),
+            tbl_name: "a".to_string(),
+            tbl_id: 0,
+            assignments: vec![("b".to_string(), add(col("b"), lit(1u64)))],
+            selection: Some(col("b").eq(lit(33u64))),
+        };
+        table.update(ctx.clone(), update_plan).await?;
+
+        let source_plan = table.read_plan(ctx.clone(), None)?;
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_blocks_sorted_eq(
+            vec![
+                "+---+----+",
+                "| a | b  |",
+                "+---+----+",
+                "| 1 | 11 |",
+                "| 2 | 22 |",
+                "| 3 | 34 |",
+                "| 4 | 34 |",
+                "+---+----+",
+            ],
+            &result,
+        );
+    }
+
     // truncate.
     {
         let truncate_plan = TruncateTablePlan {
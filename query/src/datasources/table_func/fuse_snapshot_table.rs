@@ -0,0 +1,223 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::catalogs::Table;
+use crate::catalogs::TableFunction;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table_func_engine::TableArgs;
+use crate::sessions::DatabendQueryContextRef;
+
+/// Lists the snapshots of a fuse table, e.g. `SELECT * FROM fuse_snapshot('db', 't')`
+pub struct FuseSnapshotTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseSnapshotTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.unwrap_or_default();
+        let (arg_database_name, arg_table_name) = match args.as_slice() {
+            [db, tbl] => (string_literal(db), string_literal(tbl)),
+            _ => (None, None),
+        };
+
+        let (arg_database_name, arg_table_name) = match (arg_database_name, arg_table_name) {
+            (Some(db), Some(tbl)) => (db, tbl),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Must have exactly two string arguments (database and table name) for table function.{}",
+                    table_func_name
+                )))
+            }
+        };
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("snapshot_id", DataType::String, false),
+            DataField::new("snapshot_location", DataType::String, false),
+            DataField::new("prev_snapshot_id", DataType::String, true),
+            DataField::new("timestamp", DataType::String, true),
+            DataField::new("segment_count", DataType::UInt64, false),
+            DataField::new("block_count", DataType::UInt64, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("bytes_uncompressed", DataType::UInt64, false),
+            DataField::new("bytes_compressed", DataType::UInt64, false),
+        ]);
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "FuseSnapshot".to_string(),
+                options: Default::default(),
+            },
+        };
+
+        Ok(Arc::new(FuseSnapshotTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseSnapshotTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            Expression::create_literal(DataValue::String(Some(
+                self.arg_database_name.as_bytes().to_vec(),
+            ))),
+            Expression::create_literal(DataValue::String(Some(
+                self.arg_table_name.as_bytes().to_vec(),
+            ))),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tbl = ctx
+            .get_catalog()
+            .get_table(&self.arg_database_name, &self.arg_table_name)
+            .await?;
+        let tbl = tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                tbl.get_table_info().meta.engine
+            ))
+        })?;
+
+        let history = tbl.snapshot_history(ctx.clone()).await?;
+
+        let snapshot_ids: Vec<String> = history
+            .iter()
+            .map(|(s, _)| s.snapshot_id.to_simple().to_string())
+            .collect();
+        let prev_snapshot_ids: Vec<Option<String>> = history
+            .iter()
+            .map(|(s, _)| s.prev_snapshot_id.map(|id| id.to_simple().to_string()))
+            .collect();
+        let timestamps: Vec<Option<String>> = history
+            .iter()
+            .map(|(s, _)| {
+                s.timestamp
+                    .map(|ts| ts.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+            })
+            .collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(snapshot_ids.iter().map(|s| s.as_str()).collect::<Vec<_>>()),
+            Series::new(history.iter().map(|(_, l)| l.as_str()).collect::<Vec<_>>()),
+            Series::new(
+                prev_snapshot_ids
+                    .iter()
+                    .map(|s| s.as_deref())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(timestamps.iter().map(|s| s.as_deref()).collect::<Vec<_>>()),
+            Series::new(
+                history
+                    .iter()
+                    .map(|(s, _)| s.segments.len() as u64)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                history
+                    .iter()
+                    .map(|(s, _)| s.summary.block_count)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                history
+                    .iter()
+                    .map(|(s, _)| s.summary.row_count)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                history
+                    .iter()
+                    .map(|(s, _)| s.summary.uncompressed_byte_size)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                history
+                    .iter()
+                    .map(|(s, _)| s.summary.compressed_byte_size)
+                    .collect::<Vec<_>>(),
+            ),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+impl TableFunction for FuseSnapshotTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+fn string_literal(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::Literal {
+            value: DataValue::String(Some(v)),
+            ..
+        } => String::from_utf8(v.clone()).ok(),
+        _ => None,
+    }
+}
@@ -13,8 +13,10 @@
 //  limitations under the License.
 //
 
+pub use fuse_snapshot_table::FuseSnapshotTable;
 pub use numbers_table::NumbersTable;
 
+mod fuse_snapshot_table;
 mod numbers_stream;
 mod numbers_table;
 #[cfg(test)]
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -51,4 +54,49 @@ impl ContextFunction {
             _ => vec![],
         })
     }
+
+    // The timezone aware functions take the session timezone if the datetime has no timezone,
+    // such as `SELECT toHour(now())`, the arg is appended after the args of the function.
+    // The timezone is the last optional arg, it's given explicitly if all the args are given.
+    pub fn build_timezone_args(
+        name: &str,
+        args: &[Expression],
+        schema: &DataSchema,
+        ctx: DatabendQueryContextRef,
+    ) -> Result<Vec<Expression>> {
+        let function_factory = FunctionFactory::instance();
+        match function_factory.get_features(name) {
+            Ok(features) if features.is_timezone_aware => {}
+            _ => return Ok(vec![]),
+        }
+
+        let timezone = ctx.get_settings().get_timezone()?;
+        if timezone == "UTC" || args.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let function = function_factory.get(name)?;
+        let max_args = match function.variadic_arguments() {
+            Some((_, max_args)) => max_args,
+            None => function.num_arguments(),
+        };
+        if args.len() >= max_args {
+            return Ok(vec![]);
+        }
+
+        // The datetime has a timezone already
+        let schema = DataSchemaRefExt::create(schema.fields().clone());
+        if args.iter().any(|arg| {
+            matches!(
+                arg.to_data_type(&schema),
+                Ok(DataType::DateTime32(Some(_))) | Ok(DataType::DateTime64(_, Some(_)))
+            )
+        }) {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Expression::create_literal(DataValue::String(Some(
+            timezone.into_bytes(),
+        )))])
+    }
 }
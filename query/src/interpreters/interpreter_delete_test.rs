@@ -0,0 +1,57 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_delete_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // Create table.
+    {
+        if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("create table default.a(a String, b String) Engine = Memory")?
+        {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+    }
+
+    // Delete from a table which does not support it.
+    {
+        if let PlanNode::Delete(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("delete from default.a where a = '1,1'")?
+        {
+            let executor = DeleteInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DeleteInterpreter");
+
+            let result = executor.execute(None).await;
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap().message(),
+                "delete for table a is not implemented"
+            );
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
@@ -38,14 +38,14 @@ async fn test_explain_interpreter() -> Result<()> {
         assert_eq!(block.column(0).len(), 4);
 
         let expected = vec![
-            "+-----------------------------------------------------------------------------------------------------------------------+",
-            "| explain                                                                                                               |",
-            "+-----------------------------------------------------------------------------------------------------------------------+",
-            "| Projection: number:UInt64                                                                                             |",
-            "|   Having: ((number + 1) = 4)                                                                                          |",
-            "|     Filter: ((number + 1) = 4)                                                                                        |",
-            "|       ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80] |",
-            "+-----------------------------------------------------------------------------------------------------------------------+",
+            "+--------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| explain                                                                                                                                                            |",
+            "+--------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| Projection: number:UInt64                                                                                                                                          |",
+            "|   Having: ((number + 1) = 4)                                                                                                                                       |",
+            "|     Filter: ((number + 1) = 4)                                                                                                                                     |",
+            "|       ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [filters: [((number + 1) = 4)]] |",
+            "+--------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
         ];
         common_datablocks::assert_blocks_eq(expected, result.as_slice());
     } else {
@@ -23,6 +23,7 @@ use crate::interpreters::interpreter_user_alter::AlterUserInterpreter;
 use crate::interpreters::CreatUserInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
 use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
 use crate::interpreters::DropTableInterpreter;
@@ -30,11 +31,14 @@ use crate::interpreters::ExplainInterpreter;
 use crate::interpreters::GrantPrivilegeInterpreter;
 use crate::interpreters::InsertIntoInterpreter;
 use crate::interpreters::Interpreter;
+use crate::interpreters::OptimizeTableInterpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
+use crate::interpreters::VacuumTableInterpreter;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct InterpreterFactory;
@@ -50,9 +54,13 @@ impl InterpreterFactory {
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx, v),
+            PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx, v),
+            PlanNode::VacuumTable(v) => VacuumTableInterpreter::try_create(ctx, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx, v),
             PlanNode::InsertInto(v) => InsertIntoInterpreter::try_create(ctx, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx, v),
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx, v),
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx, v),
             PlanNode::CreateUser(v) => CreatUserInterpreter::try_create(ctx, v),
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_datavalues::prelude::parse_timezone;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
@@ -56,6 +57,11 @@ impl Interpreter for SettingInterpreter {
                     let threads: u64 = var.value.parse()?;
                     self.ctx.get_settings().set_max_threads(threads)?;
                 }
+                "timezone" => {
+                    // Check the timezone is known before it's used by the date functions.
+                    parse_timezone(&Some(var.value.clone()))?;
+                    self.ctx.get_settings().set_timezone(var.value)?;
+                }
                 _ => {
                     self.ctx
                         .get_settings()
@@ -17,6 +17,8 @@ mod interpreter_database_create_test;
 #[cfg(test)]
 mod interpreter_database_drop_test;
 #[cfg(test)]
+mod interpreter_delete_test;
+#[cfg(test)]
 mod interpreter_describe_table_test;
 #[cfg(test)]
 mod interpreter_explain_test;
@@ -35,6 +37,8 @@ mod interpreter_table_drop_test;
 #[cfg(test)]
 mod interpreter_truncate_table_test;
 #[cfg(test)]
+mod interpreter_update_test;
+#[cfg(test)]
 mod interpreter_use_database_test;
 #[cfg(test)]
 mod interpreter_user_alter_test;
@@ -46,20 +50,24 @@ mod plan_scheduler_test;
 mod interpreter;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_delete;
 mod interpreter_describe_table;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_grant_privilege;
 mod interpreter_insert_into;
 mod interpreter_kill;
+mod interpreter_optimize_table;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
 mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_truncate_table;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_create;
+mod interpreter_vacuum_table;
 mod plan_do_readsource;
 mod utils;
 
@@ -71,17 +79,21 @@ pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_grant_privilege::GrantPrivilegeInterpreter;
 pub use interpreter_insert_into::InsertIntoInterpreter;
+pub use interpreter_optimize_table::OptimizeTableInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreatUserInterpreter;
+pub use interpreter_vacuum_table::VacuumTableInterpreter;
@@ -23,12 +23,14 @@ use common_meta_types::NodeInfo;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::EmptyPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
 use common_planners::Expressions;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::Partitions;
@@ -41,6 +43,7 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -69,6 +72,8 @@ pub struct PlanScheduler {
     running_mode: RunningMode,
     query_context: DatabendQueryContextRef,
     subqueries_expressions: Vec<Expressions>,
+    // The scheduled query plan of each CTE, it's shared by all the scans of the CTE.
+    cte_plans: HashMap<String, PlanNode>,
 }
 
 impl PlanScheduler {
@@ -94,6 +99,7 @@ impl PlanScheduler {
             stage_id: uuid::Uuid::new_v4().to_string(),
             query_context: context,
             subqueries_expressions: vec![],
+            cte_plans: HashMap::new(),
             cluster_nodes: cluster_nodes_name,
             running_mode: RunningMode::Standalone,
         })
@@ -302,10 +308,13 @@ impl PlanScheduler {
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
+            PlanNode::CteScan(plan) => self.visit_cte_scan(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
             PlanNode::Stage(plan) => self.visit_stage(plan, tasks),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan, tasks),
             PlanNode::Having(plan) => self.visit_having(plan, tasks),
+            PlanNode::Join(plan) => self.visit_join(plan, tasks),
+            PlanNode::Union(plan) => self.visit_union(plan, tasks),
             PlanNode::Expression(plan) => self.visit_expression(plan, tasks),
             PlanNode::SubQueryExpression(plan) => self.visit_subqueries_set(plan, tasks),
             _ => Err(ErrorCode::UnImplement("")),
@@ -639,10 +648,49 @@ impl PlanScheduler {
     fn visit_subquery(&mut self, plan: &PlanNode, tasks: &mut Tasks) -> Result<Vec<PlanNode>> {
         let subquery_context = DatabendQueryContext::new(self.query_context.clone());
         let mut subquery_scheduler = PlanScheduler::try_create(subquery_context)?;
-        subquery_scheduler.visit_plan_node(plan, tasks)?;
+        subquery_scheduler.cte_plans = std::mem::take(&mut self.cte_plans);
+        let visit_res = subquery_scheduler.visit_plan_node(plan, tasks);
+        self.cte_plans = std::mem::take(&mut subquery_scheduler.cte_plans);
+        visit_res?;
         Ok(subquery_scheduler.nodes_plan)
     }
 
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan, tasks: &mut Tasks) -> Result<()> {
+        // The CTE is evaluated once in local node, so its query is only scheduled for the first scan.
+        let query_plan = match self.cte_plans.get(&plan.name) {
+            Some(query_plan) => query_plan.clone(),
+            None => {
+                let nodes_plan = self.visit_subquery(plan.query_plan.as_ref(), tasks)?;
+                let query_plan = nodes_plan[self.local_pos].clone();
+                self.cte_plans.insert(plan.name.clone(), query_plan.clone());
+                query_plan
+            }
+        };
+
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::CteScan(CteScanPlan {
+            name: plan.name.clone(),
+            query_plan: Arc::new(query_plan),
+        });
+        Ok(())
+    }
+
+    fn visit_union(&mut self, plan: &UnionPlan, tasks: &mut Tasks) -> Result<()> {
+        // The inputs are converged to local node by the optimizer, so union is evaluated in local node.
+        let mut inputs = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let nodes_plan = self.visit_subquery(input.as_ref(), tasks)?;
+            inputs.push(Arc::new(nodes_plan[self.local_pos].clone()));
+        }
+
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::Union(UnionPlan {
+            schema: plan.schema(),
+            inputs,
+        });
+        Ok(())
+    }
+
     fn visit_filter(&mut self, plan: &FilterPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -697,6 +745,49 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref(), tasks)?;
+
+        // The build side is scheduled as a subquery
+        let build_nodes_plan = self.visit_subquery(plan.right.as_ref(), tasks)?;
+
+        if build_nodes_plan.len() != self.nodes_plan.len() {
+            return Err(ErrorCode::LogicalError(
+                "Join build side size miss match nodes plan",
+            ));
+        }
+
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_join(plan, &build_nodes_plan),
+            RunningMode::Standalone => self.visit_local_join(plan, &build_nodes_plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_join(&mut self, plan: &JoinPlan, build_nodes_plan: &[PlanNode]) {
+        self.nodes_plan[self.local_pos] = PlanNode::Join(JoinPlan {
+            join_type: plan.join_type,
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
+            schema: plan.schema.clone(),
+            left: Arc::new(self.nodes_plan[self.local_pos].clone()),
+            right: Arc::new(build_nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_join(&mut self, plan: &JoinPlan, build_nodes_plan: &[PlanNode]) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Join(JoinPlan {
+                join_type: plan.join_type,
+                left_keys: plan.left_keys.clone(),
+                right_keys: plan.right_keys.clone(),
+                schema: plan.schema.clone(),
+                left: Arc::new(self.nodes_plan[index].clone()),
+                right: Arc::new(build_nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_sort(&mut self, plan: &SortPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
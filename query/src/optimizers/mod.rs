@@ -17,6 +17,8 @@ mod optimizer_constant_folding_test;
 #[cfg(test)]
 mod optimizer_expression_transform_test;
 #[cfg(test)]
+mod optimizer_filter_push_down_test;
+#[cfg(test)]
 mod optimizer_projection_push_down_test;
 #[cfg(test)]
 mod optimizer_scatters_test;
@@ -32,6 +34,7 @@ mod metrics;
 mod optimizer;
 mod optimizer_constant_folding;
 mod optimizer_expression_transform;
+mod optimizer_filter_push_down;
 mod optimizer_projection_push_down;
 mod optimizer_scatters;
 mod optimizer_statistics_exact;
@@ -42,6 +45,7 @@ pub use optimizer::Optimizer;
 pub use optimizer::Optimizers;
 pub use optimizer_constant_folding::ConstantFoldingOptimizer;
 pub use optimizer_expression_transform::ExprTransformOptimizer;
+pub use optimizer_filter_push_down::FilterPushDownOptimizer;
 pub use optimizer_projection_push_down::ProjectionPushDownOptimizer;
 pub use optimizer_scatters::ScattersOptimizer;
 pub use optimizer_statistics_exact::StatisticsExactOptimizer;
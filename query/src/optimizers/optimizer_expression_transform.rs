@@ -58,7 +58,6 @@ impl ExprTransformImpl {
     // Apply NOT transformation to the expression and return a new one.
     fn truth_transformer(origin: &Expression, is_negated: bool) -> Result<Expression> {
         match origin {
-            // TODO: support in and not in.
             Expression::BinaryExpression { op, left, right } => match op.to_lowercase().as_str() {
                 "and" => {
                     let new_left = Self::truth_transformer(left, is_negated)?;
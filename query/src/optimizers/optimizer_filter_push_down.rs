@@ -0,0 +1,307 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::*;
+
+use super::RequireColumnsVisitor;
+use crate::optimizers::Optimizer;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct FilterPushDownOptimizer {}
+
+// Pushes the deterministic conjuncts of the filters down to the read source, so that the
+// storage can prune the blocks which can not match. The filters themselves are kept
+// untouched, the pushed down conjuncts are only hints for the storage.
+struct FilterPushDownImpl {
+    before_group_by_schema: Option<DataSchemaRef>,
+    // The conjuncts which can be pushed down to the read source of the current sub tree.
+    filters: Vec<Expression>,
+    // The conjuncts of a having which only reference the group keys,
+    // they are pushed down through the aggregation.
+    group_key_filters: Vec<Expression>,
+}
+
+impl PlanRewriter for FilterPushDownImpl {
+    fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+        match self.before_group_by_schema {
+            Some(_) => Err(ErrorCode::LogicalError(
+                "Logical error: before group by schema must be None",
+            )),
+            None => {
+                self.before_group_by_schema = Some(new_input.schema());
+                let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
+                let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
+                PlanBuilder::from(&new_input)
+                    .aggregate_partial(&new_aggr_expr, &new_group_expr)?
+                    .build()
+            }
+        }
+    }
+
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        // Only the filters on the group keys are still valid below the aggregation.
+        let group_keys = plan
+            .group_expr
+            .iter()
+            .filter_map(|expr| match expr {
+                Expression::Column(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut filters = vec![];
+        for filter in std::mem::take(&mut self.group_key_filters) {
+            let columns = RequireColumnsVisitor::collect_columns_from_expr(&filter)?;
+            if columns.is_subset(&group_keys) {
+                filters.push(filter);
+            }
+        }
+        self.filters = filters;
+
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+
+        match self.before_group_by_schema.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Logical error: before group by schema must be Some",
+            )),
+            Some(schema_before_group_by) => {
+                let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
+                let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
+                PlanBuilder::from(&new_input)
+                    .aggregate_final(schema_before_group_by, &new_aggr_expr, &new_group_expr)?
+                    .build()
+            }
+        }
+    }
+
+    fn rewrite_projection(&mut self, plan: &ProjectionPlan) -> Result<PlanNode> {
+        self.retain_pass_through_filters(&plan.expr)?;
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &plan.expr)?;
+        PlanBuilder::from(&new_input).project(&new_exprs)?.build()
+    }
+
+    fn rewrite_expression(&mut self, plan: &ExpressionPlan) -> Result<PlanNode> {
+        self.retain_pass_through_filters(&plan.exprs)?;
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &plan.exprs)?;
+        PlanBuilder::from(&new_input)
+            .expression(&new_exprs, &plan.desc)?
+            .build()
+    }
+
+    fn rewrite_filter(&mut self, plan: &FilterPlan) -> Result<PlanNode> {
+        let current_filters = self.filters.clone();
+        for conjunct in split_conjunctions(&plan.predicate) {
+            if is_deterministic(&conjunct) {
+                self.filters.push(conjunct);
+            }
+        }
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
+        let plan_node = PlanBuilder::from(&new_input).filter(new_predicate)?.build();
+
+        self.filters = current_filters; // recover back to previous state
+        plan_node
+    }
+
+    fn rewrite_having(&mut self, plan: &HavingPlan) -> Result<PlanNode> {
+        // The conjuncts which reference the group keys only are picked by the aggregation.
+        for conjunct in split_conjunctions(&plan.predicate) {
+            if is_deterministic(&conjunct) {
+                self.group_key_filters.push(conjunct);
+            }
+        }
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        self.group_key_filters.clear();
+        let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
+        PlanBuilder::from(&new_input).having(new_predicate)?.build()
+    }
+
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // A filter above the join may reference the columns of both sides.
+        self.filters.clear();
+        self.group_key_filters.clear();
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_subquery_plan(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
+    fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
+        // The rows are filtered after the limit, pruning blocks would change the result.
+        self.filters.clear();
+        self.group_key_filters.clear();
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .limit_offset(plan.n, plan.offset)?
+            .build()
+    }
+
+    fn rewrite_limit_by(&mut self, plan: &LimitByPlan) -> Result<PlanNode> {
+        self.filters.clear();
+        self.group_key_filters.clear();
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .limit_by(plan.limit, &plan.limit_by)?
+            .build()
+    }
+
+    fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
+        // The constant conjuncts are left to the constant folding.
+        let schema = plan.table_info.schema();
+        let mut filters = Vec::with_capacity(self.filters.len());
+        for filter in &self.filters {
+            let columns = RequireColumnsVisitor::collect_columns_from_expr(filter)?;
+            if !columns.is_empty()
+                && columns
+                    .iter()
+                    .all(|name| schema.field_with_name(name).is_ok())
+            {
+                filters.push(filter.clone());
+            }
+        }
+
+        if filters.is_empty() {
+            return Ok(PlanNode::ReadSource(plan.clone()));
+        }
+
+        let mut new_plan = plan.clone();
+        new_plan.push_downs = match &plan.push_downs {
+            Some(extras) => {
+                let mut new_extras = extras.clone();
+                new_extras.filters.extend(filters);
+                Some(new_extras)
+            }
+            None => {
+                let mut extras = Extras::default();
+                extras.filters = filters;
+                Some(extras)
+            }
+        };
+        Ok(PlanNode::ReadSource(new_plan))
+    }
+
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = FilterPushDownOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+}
+
+impl FilterPushDownImpl {
+    pub fn new() -> FilterPushDownImpl {
+        FilterPushDownImpl {
+            before_group_by_schema: None,
+            filters: vec![],
+            group_key_filters: vec![],
+        }
+    }
+
+    // Drops the filters which reference a column computed by the expressions,
+    // the columns only passed through keep their meaning below the plan.
+    fn retain_pass_through_filters(&mut self, exprs: &[Expression]) -> Result<()> {
+        let computed_columns = exprs
+            .iter()
+            .filter(|expr| !matches!(expr, Expression::Column(_)))
+            .map(|expr| expr.column_name())
+            .collect::<HashSet<_>>();
+
+        self.filters = Self::pass_through(&self.filters, &computed_columns)?;
+        self.group_key_filters = Self::pass_through(&self.group_key_filters, &computed_columns)?;
+        Ok(())
+    }
+
+    fn pass_through(
+        filters: &[Expression],
+        computed_columns: &HashSet<String>,
+    ) -> Result<Vec<Expression>> {
+        let mut res = Vec::with_capacity(filters.len());
+        for filter in filters {
+            let columns = RequireColumnsVisitor::collect_columns_from_expr(filter)?;
+            if columns.is_disjoint(computed_columns) {
+                res.push(filter.clone());
+            }
+        }
+        Ok(res)
+    }
+}
+
+// e.g. a > 1 and (b < 2 and c = 3) => [a > 1, b < 2, c = 3]
+fn split_conjunctions(expr: &Expression) -> Vec<Expression> {
+    match expr {
+        Expression::BinaryExpression { left, op, right } if op.to_lowercase() == "and" => {
+            let mut conjuncts = split_conjunctions(left);
+            conjuncts.extend(split_conjunctions(right));
+            conjuncts
+        }
+        _ => vec![expr.clone()],
+    }
+}
+
+// An expression is deterministic if it is made of deterministic functions only,
+// subqueries and aggregate functions are never pushed down.
+fn is_deterministic(expr: &Expression) -> bool {
+    let deterministic_function = |op: &str| {
+        FunctionFactory::instance()
+            .get_features(op)
+            .map_or(false, |features| features.is_deterministic)
+    };
+
+    match expr {
+        Expression::Column(_) | Expression::Literal { .. } => true,
+        Expression::Alias(_, expr) | Expression::Cast { expr, .. } => is_deterministic(expr),
+        Expression::UnaryExpression { op, expr } => {
+            deterministic_function(op) && is_deterministic(expr)
+        }
+        Expression::BinaryExpression { op, left, right } => {
+            deterministic_function(op) && is_deterministic(left) && is_deterministic(right)
+        }
+        Expression::ScalarFunction { op, args } => {
+            deterministic_function(op) && args.iter().all(is_deterministic)
+        }
+        _ => false,
+    }
+}
+
+impl Optimizer for FilterPushDownOptimizer {
+    fn name(&self) -> &str {
+        "FilterPushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = FilterPushDownImpl::new();
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl FilterPushDownOptimizer {
+    pub fn create(_ctx: DatabendQueryContextRef) -> FilterPushDownOptimizer {
+        FilterPushDownOptimizer {}
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::optimizers::*;
+
+#[test]
+fn test_filter_push_down_optimizer() -> Result<()> {
+    let query = "select number from numbers_mt(10) where number > 1 and (number + 1) < 5";
+    let ctx = crate::tests::try_create_context()?;
+
+    let plan = crate::tests::parse_query(query)?;
+
+    let mut optimizer = FilterPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    let expect = "\
+    Projection: number:UInt64\
+    \n  Filter: ((number > 1) AND ((number + 1) < 5))\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [filters: [(number > 1), ((number + 1) < 5)]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[test]
+fn test_filter_push_down_optimizer_cases() -> Result<()> {
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        // The expected push downs of the read source, empty if nothing is pushed down.
+        expect: &'static str,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Disjunction is pushed down as a whole",
+            query: "select number from numbers_mt(10) where number > 8 or number < 1",
+            expect: "push_downs: [filters: [((number > 8) OR (number < 1))]]",
+        },
+        Test {
+            name: "Constant conjunction is not pushed down",
+            query: "select number from numbers_mt(10) where number > 1 and 1 = 1",
+            expect: "push_downs: [filters: [(number > 1)]]",
+        },
+        Test {
+            name: "Filter through the group by",
+            query: "select max(number) from numbers_mt(10) where number > 1 group by number % 3",
+            expect: "push_downs: [filters: [(number > 1)]]",
+        },
+        Test {
+            name: "Having on the group keys",
+            query: "select max(number) from numbers_mt(10) group by number having number > 1 and max(number) > 2",
+            expect: "push_downs: [filters: [(number > 1)]]",
+        },
+        Test {
+            name: "Having on the group expressions",
+            query: "select max(number) from numbers_mt(10) group by number % 3 having number % 3 > 1",
+            expect: "",
+        },
+        Test {
+            name: "Filter above the limit",
+            query: "select number from (select number from numbers_mt(10) limit 3) where number > 1",
+            expect: "",
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::try_create_context()?;
+        let plan = crate::tests::parse_query(test.query)?;
+
+        let mut optimizer = FilterPushDownOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+        let actual = format!("{:?}", optimized);
+        match test.expect {
+            "" => assert!(!actual.contains("push_downs"), "{:#?}", test.name),
+            expect => assert!(actual.contains(expect), "{:#?}", test.name),
+        }
+    }
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
@@ -20,17 +21,20 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
+use common_planners::CteScanPlan;
 use common_planners::EmptyPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
 use common_planners::Extras;
 use common_planners::FilterPlan;
+use common_planners::JoinPlan;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::PlanRewriter;
 use common_planners::ProjectionPlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
+use common_planners::UnionPlan;
 
 use crate::optimizers::Optimizer;
 use crate::optimizers::RequireColumnsVisitor;
@@ -107,6 +111,45 @@ impl PlanRewriter for ProjectionPushDownImpl {
             .build()
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        self.collect_column_names_from_expr_vec(&plan.left_keys)?;
+        self.collect_column_names_from_expr_vec(&plan.right_keys)?;
+        let new_left = self.rewrite_plan_node(&plan.left)?;
+        let new_right = self.rewrite_plan_node(&plan.right)?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        // The query of CTE is shared by all the scans, so it must not be pruned by the columns
+        // required by one of them.
+        let mut cte_optimizer = ProjectionPushDownImpl::new();
+        Ok(PlanNode::CteScan(CteScanPlan {
+            name: plan.name.clone(),
+            query_plan: Arc::new(cte_optimizer.rewrite_plan_node(plan.query_plan.as_ref())?),
+        }))
+    }
+
+    fn rewrite_union(&mut self, plan: &UnionPlan) -> Result<PlanNode> {
+        // The columns of union are matched by position and every column is needed to
+        // deduplicate the rows, so each input is pushed down independently.
+        let mut new_inputs = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let mut input_optimizer = ProjectionPushDownImpl::new();
+            new_inputs.push(input_optimizer.rewrite_plan_node(input.as_ref())?);
+        }
+
+        PlanBuilder::from(&new_inputs[0])
+            .union(&new_inputs[1..])?
+            .build()
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         self.collect_column_names_from_expr_vec(plan.order_by.as_slice())?;
         let new_input = self.rewrite_plan_node(&plan.input)?;
@@ -21,7 +21,9 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::Expression;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanBuilder;
@@ -31,6 +33,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::UnionPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::DatabendQueryContext;
@@ -252,6 +255,30 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let mut new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+
+        // The unmatched rows of the build side can only be found when all of the
+        // probe side has been seen, so we convergent the probe side in local node.
+        if let (RunningMode::Cluster, true) =
+            (&self.running_mode, plan.join_type.keep_unmatched_right())
+        {
+            self.running_mode = RunningMode::Standalone;
+            new_left = Self::convergent_shuffle_stage(new_left)?;
+        }
+
+        // The build side is broadcast to every node like a subquery.
+        let new_right = self.rewrite_subquery_plan(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
 
@@ -279,6 +306,28 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        // The CTE is evaluated once in local node, so the query is convergent to local node.
+        self.running_mode = RunningMode::Standalone;
+        Ok(PlanNode::CteScan(CteScanPlan {
+            name: plan.name.clone(),
+            query_plan: Arc::new(self.rewrite_subquery_plan(plan.query_plan.as_ref())?),
+        }))
+    }
+
+    fn rewrite_union(&mut self, plan: &UnionPlan) -> Result<PlanNode> {
+        // The union is evaluated in local node, so every input is convergent to local node.
+        self.running_mode = RunningMode::Standalone;
+        let mut new_inputs = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            new_inputs.push(self.rewrite_subquery_plan(input.as_ref())?);
+        }
+
+        PlanBuilder::from(&new_inputs[0])
+            .union(&new_inputs[1..])?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
 
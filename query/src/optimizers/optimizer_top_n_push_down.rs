@@ -111,6 +111,7 @@ impl PlanRewriter for TopNPushDownImpl {
                     Some(Extras {
                         projection: extras.projection.clone(),
                         filters: extras.filters.clone(),
+                        prewhere: extras.prewhere.clone(),
                         limit: Some(new_limit),
                         order_by: self.get_sort_columns()?,
                     })
@@ -132,6 +133,21 @@ impl PlanRewriter for TopNPushDownImpl {
         optimizer.optimize(subquery_plan)
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // A join may drop or duplicate the rows of its inputs, so we clear the top n option.
+        self.limit = None;
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_subquery_plan(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         if self.limit.is_some() {
             self.order_by = plan.order_by.clone();
@@ -73,6 +73,23 @@ impl Pipeline {
         Ok(())
     }
 
+    /// Union the outputs of many pipelines into one pipeline.
+    ///
+    /// pipeline1 --> processor1_1
+    ///
+    /// pipeline2 --> processor2_1
+    ///
+    /// The last processors of every input pipeline become the sources of the new pipeline.
+    pub fn union(ctx: DatabendQueryContextRef, pipelines: Vec<Pipeline>) -> Result<Pipeline> {
+        let mut pipeline = Pipeline::create(ctx);
+        for input in pipelines {
+            for processor in input.last_pipe()?.processors() {
+                pipeline.add_source(processor)?;
+            }
+        }
+        Ok(pipeline)
+    }
+
     /// Add a normal processor to the pipeline.
     ///
     /// processor1 --> processor1_1
@@ -18,9 +18,11 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanNode;
@@ -31,6 +33,7 @@ use common_planners::SelectPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
@@ -38,10 +41,13 @@ use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
+use crate::pipelines::transforms::CteScanTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
+use crate::pipelines::transforms::HashJoinTransform;
 use crate::pipelines::transforms::HavingTransform;
+use crate::pipelines::transforms::JoinBuildPuller;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
 use crate::pipelines::transforms::ProjectionTransform;
@@ -51,6 +57,7 @@ use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
 use crate::pipelines::transforms::WhereTransform;
+use crate::sessions::DatabendQueryContext;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct PipelineBuilder {
@@ -89,10 +96,13 @@ impl PipelineBuilder {
             PlanNode::AggregatorFinal(node) => self.visit_aggregator_final(node),
             PlanNode::Filter(node) => self.visit_filter(node),
             PlanNode::Having(node) => self.visit_having(node),
+            PlanNode::Join(node) => self.visit_join(node),
+            PlanNode::Union(node) => self.visit_union(node),
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
+            PlanNode::CteScan(node) => self.visit_cte_scan(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             other => Result::Err(ErrorCode::UnknownPlan(format!(
                 "Build pipeline from the plan node unsupported:{:?}",
@@ -173,6 +183,7 @@ impl PipelineBuilder {
         } else {
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(GroupByPartialTransform::create(
+                    self.ctx.clone(),
                     node.schema(),
                     node.input.schema(),
                     node.aggr_expr.clone(),
@@ -199,6 +210,7 @@ impl PipelineBuilder {
             let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(GroupByFinalTransform::create(
+                    self.ctx.clone(),
                     node.schema(),
                     max_block_size,
                     node.schema_before_group_by.clone(),
@@ -233,6 +245,50 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*plan.left)?;
+
+        // The unmatched rows of the build side are emitted when the probe side is exhausted,
+        // so we need only one join processor for RIGHT and FULL join.
+        if plan.join_type.keep_unmatched_right() {
+            pipeline.merge_processor()?;
+        }
+
+        let context = self.ctx.clone();
+        let join_type = plan.join_type;
+        let schema = plan.schema();
+        let probe_schema = plan.left.schema();
+        let probe_keys = plan.left_keys.clone();
+        let build_puller = JoinBuildPuller::create(
+            context.clone(),
+            plan.right.as_ref().clone(),
+            plan.right_keys.clone(),
+        );
+        pipeline.add_simple_transform(move || {
+            Ok(Box::new(HashJoinTransform::try_create(
+                context.clone(),
+                join_type,
+                schema.clone(),
+                probe_schema.clone(),
+                &probe_keys,
+                build_puller.clone(),
+            )?))
+        })?;
+
+        Ok(pipeline)
+    }
+
+    fn visit_union(&mut self, plan: &UnionPlan) -> Result<Pipeline> {
+        let mut pipelines = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            // Each input binds its own partitions, so it must be built with its own context.
+            let input_ctx = DatabendQueryContext::new(self.ctx.clone());
+            pipelines.push(PipelineBuilder::create(input_ctx).build(input.as_ref())?);
+        }
+
+        Pipeline::union(self.ctx.clone(), pipelines)
+    }
+
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
 
@@ -260,6 +316,7 @@ impl PipelineBuilder {
         // processor 3: [sorted blocks ...] ---> merge to one sorted block
         pipeline.add_simple_transform(|| {
             Ok(Box::new(SortMergeTransform::try_create(
+                self.ctx.clone(),
                 plan.schema(),
                 plan.order_by.clone(),
                 rows_limit,
@@ -275,6 +332,7 @@ impl PipelineBuilder {
             pipeline.merge_processor()?;
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(SortMergeTransform::try_create(
+                    self.ctx.clone(),
                     plan.schema(),
                     plan.order_by.clone(),
                     rows_limit,
@@ -324,6 +382,13 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan) -> Result<Pipeline> {
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        let source = CteScanTransform::try_create(self.ctx.clone(), plan.clone())?;
+        pipeline.add_source(Arc::new(source))?;
+        Ok(pipeline)
+    }
+
     fn visit_create_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
         let schema = plan.schema();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::MemoryTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datavalues::arrays::StringArrayBuilder;
@@ -33,32 +34,49 @@ use crate::pipelines::transforms::group_by::aggregator_params::AggregatorParams;
 use crate::pipelines::transforms::group_by::aggregator_params::AggregatorParamsRef;
 use crate::pipelines::transforms::group_by::aggregator_state::AggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state_entity::StateEntity;
+use crate::pipelines::transforms::group_by::BucketKey;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+use crate::pipelines::transforms::group_by::TwoLevelAggregatorState;
+use crate::pipelines::transforms::group_by::BUCKETS;
 
 pub struct Aggregator<Method: HashMethod> {
     method: Method,
     params: AggregatorParamsRef,
 }
 
-impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
+impl<Method> Aggregator<Method>
+where
+    Method: HashMethod + PolymorphicKeysHelper<Method>,
+    Method::HashKey: BucketKey,
+{
     pub fn create(method: Method, params: AggregatorParamsRef) -> Aggregator<Method> {
         Aggregator { method, params }
     }
 
     // If we set it to inline(performance degradation).
     // Because it will make other internal functions to no inline
+    //
+    // The state is split into the buckets once the groups grow past group_by_two_level_threshold.
+    // Once the bytes allocated by the state exceed max_bytes_before_external_group_by, the state
+    // is handed over to the spill and the aggregation goes on with a new state.
     #[inline(never)]
-    pub async fn aggregate(
+    pub async fn aggregate<Spill>(
         &self,
         group_cols: Vec<String>,
         mut stream: SendableDataBlockStream,
-    ) -> Result<Method::State> {
+        two_level_threshold: usize,
+        max_bytes_before_external_group_by: usize,
+        mut spill: Spill,
+    ) -> Result<TwoLevelAggregatorState<Method>>
+    where
+        Spill: FnMut(TwoLevelAggregatorState<Method>) -> Result<()> + Send,
+    {
         // This may be confusing
         // It will help us improve performance ~10% when we declare local references for them.
         let hash_method = &self.method;
         let aggregator_params = self.params.as_ref();
 
-        let mut state = hash_method.aggregate_state();
+        let mut state = TwoLevelAggregatorState::create(hash_method);
 
         match aggregator_params.aggregate_functions.is_empty() {
             true => {
@@ -69,6 +87,18 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
                     let group_columns = Self::group_columns(&group_cols, &block)?;
                     let group_keys = hash_method.build_keys(&group_columns, block.num_rows())?;
                     self.lookup_key(group_keys, &mut state);
+
+                    if state.len() > two_level_threshold {
+                        state.convert_to_two_level(hash_method);
+                    }
+                    if max_bytes_before_external_group_by != 0
+                        && state.allocated_bytes() > max_bytes_before_external_group_by
+                    {
+                        let new_state = TwoLevelAggregatorState::create(hash_method);
+                        spill(std::mem::replace(&mut state, new_state))?;
+                    }
+
+                    MemoryTracker::check_current_memory_limit()?;
                 }
             }
             false => {
@@ -81,6 +111,18 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
                     let places = self.lookup_state(group_keys, &mut state);
                     Self::execute(aggregator_params, &block, &places)?;
+
+                    if state.len() > two_level_threshold {
+                        state.convert_to_two_level(hash_method);
+                    }
+                    if max_bytes_before_external_group_by != 0
+                        && state.allocated_bytes() > max_bytes_before_external_group_by
+                    {
+                        let new_state = TwoLevelAggregatorState::create(hash_method);
+                        spill(std::mem::replace(&mut state, new_state))?;
+                    }
+
+                    MemoryTracker::check_current_memory_limit()?;
                 }
             }
         }
@@ -111,22 +153,27 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
     }
 
     #[inline(always)]
-    fn lookup_key(&self, keys: Vec<Method::HashKey>, state: &mut Method::State) {
+    fn lookup_key(&self, keys: Vec<Method::HashKey>, state: &mut TwoLevelAggregatorState<Method>) {
         let mut inserted = true;
         for key in keys.iter() {
-            state.entity(key, &mut inserted);
+            state.state_of(key).entity(key, &mut inserted);
         }
     }
 
     /// Allocate aggregation function state for each key(the same key can always get the same state)
     #[inline(always)]
-    fn lookup_state(&self, keys: Vec<Method::HashKey>, state: &mut Method::State) -> StateAddrs {
+    fn lookup_state(
+        &self,
+        keys: Vec<Method::HashKey>,
+        state: &mut TwoLevelAggregatorState<Method>,
+    ) -> StateAddrs {
         let mut places = Vec::with_capacity(keys.len());
 
         let mut inserted = true;
         let params = self.params.as_ref();
 
         for key in keys.iter() {
+            let state = state.state_of(key);
             let entity = state.entity(key, &mut inserted);
 
             match inserted {
@@ -177,15 +224,73 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
     #[inline(never)]
     pub fn aggregate_finalized(
         &self,
-        groups: &Method::State,
+        groups: &TwoLevelAggregatorState<Method>,
         schema: DataSchemaRef,
     ) -> Result<SendableDataBlockStream> {
-        if groups.len() == 0 {
-            return Ok(Box::pin(DataBlockStream::create(
+        let blocks = match groups.is_two_level() {
+            true => self
+                .aggregate_finalized_buckets(groups, schema.clone())?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+            false => {
+                let state = &groups.states()[0];
+                self.aggregate_finalized_block(state.iter(), state.len(), schema.clone())?
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        match blocks.is_empty() {
+            true => Ok(Box::pin(DataBlockStream::create(
                 DataSchemaRefExt::create(vec![]),
                 None,
                 vec![],
-            )));
+            ))),
+            false => Ok(Box::pin(DataBlockStream::create(schema, None, blocks))),
+        }
+    }
+
+    /// Serialize the states along with the group keys into a block for each bucket, None if
+    /// there is no group in the bucket. The single level state is partitioned by the key bucket.
+    pub fn aggregate_finalized_buckets(
+        &self,
+        groups: &TwoLevelAggregatorState<Method>,
+        schema: DataSchemaRef,
+    ) -> Result<Vec<Option<DataBlock>>> {
+        if groups.is_two_level() {
+            return groups
+                .states()
+                .iter()
+                .map(|state| {
+                    self.aggregate_finalized_block(state.iter(), state.len(), schema.clone())
+                })
+                .collect();
+        }
+
+        let mut buckets = (0..BUCKETS).map(|_| vec![]).collect::<Vec<_>>();
+        for group_entity in groups.states()[0].iter() {
+            buckets[group_entity.get_state_key().bucket()].push(group_entity);
+        }
+
+        buckets
+            .into_iter()
+            .map(|entities| {
+                let len = entities.len();
+                self.aggregate_finalized_block(entities.into_iter(), len, schema.clone())
+            })
+            .collect()
+    }
+
+    /// Serialize the states along with the group keys into a block, None if there is no group.
+    fn aggregate_finalized_block(
+        &self,
+        groups: impl Iterator<Item = *mut StateEntityOf<Method>>,
+        groups_len: usize,
+        schema: DataSchemaRef,
+    ) -> Result<Option<DataBlock>> {
+        if groups_len == 0 {
+            return Ok(None);
         }
 
         let aggregator_params = self.params.as_ref();
@@ -195,13 +300,13 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
         // Builders.
         let mut state_builders: Vec<StringArrayBuilder> = (0..aggr_len)
-            .map(|_| StringArrayBuilder::with_capacity(groups.len() * 4))
+            .map(|_| StringArrayBuilder::with_capacity(groups_len * 4))
             .collect();
 
-        let mut group_key_builder = self.method.state_array_builder(groups.len());
+        let mut group_key_builder = self.method.state_array_builder(groups_len);
 
         let mut bytes = BytesMut::new();
-        for group_entity in groups.iter() {
+        for group_entity in groups {
             let place: StateAddr = (*group_entity.get_state_value()).into();
 
             for (idx, func) in funcs.iter().enumerate() {
@@ -221,7 +326,9 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
         columns.push(group_key_builder.finish());
 
-        let block = DataBlock::create_by_array(schema.clone(), columns);
-        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+        Ok(Some(DataBlock::create_by_array(schema, columns)))
     }
 }
+
+type StateEntityOf<Method> =
+    <<Method as PolymorphicKeysHelper<Method>>::State as AggregatorState<Method>>::Entity;
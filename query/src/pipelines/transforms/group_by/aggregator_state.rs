@@ -31,6 +31,7 @@ use crate::pipelines::transforms::group_by::aggregator_state_entity::ShortFixedK
 use crate::pipelines::transforms::group_by::aggregator_state_entity::ShortFixedKeysStateEntity;
 use crate::pipelines::transforms::group_by::aggregator_state_entity::StateEntity;
 use crate::pipelines::transforms::group_by::aggregator_state_iterator::ShortFixedKeysStateIterator;
+use crate::pipelines::transforms::group_by::aggregator_two_level::BucketKey;
 use crate::pipelines::transforms::group_by::keys_ref::KeysRef;
 use crate::pipelines::transforms::group_by::AggregatorParams;
 
@@ -41,10 +42,13 @@ use crate::pipelines::transforms::group_by::AggregatorParams;
 ///     - Aggregate function state data memory pool
 ///     - Group by key data memory pool (if necessary)
 pub trait AggregatorState<Method: HashMethod>: Sync + Send {
-    type Key;
+    type Key: BucketKey;
     type Entity: StateEntity<Self::Key>;
     type Iterator: Iterator<Item = *mut Self::Entity>;
 
+    /// Whether the state is split into the buckets of the two level group by.
+    const TWO_LEVEL: bool = true;
+
     fn len(&self) -> usize;
 
     fn iter(&self) -> Self::Iterator;
@@ -52,6 +56,13 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
     fn alloc_layout(&self, params: &AggregatorParams) -> StateAddr;
 
     fn entity(&mut self, key: &Method::HashKey, inserted: &mut bool) -> *mut Self::Entity;
+
+    /// The bytes allocated by the keys and the aggregate function states, roughly.
+    fn allocated_bytes(&self) -> usize;
+
+    /// Inserts the key along with the place of its aggregate function states, both of which are
+    /// owned by another state. It's used to split a state into the buckets without copying.
+    fn insert_moved(&mut self, key: &Self::Key, place: usize);
 }
 
 /// The fixed length array is used as the data structure to locate the key by subscript
@@ -104,7 +115,7 @@ impl<T: ShortFixedKeyable> Drop for ShortFixedKeysAggregatorState<T> {
 
 impl<T> AggregatorState<HashMethodFixedKeys<T>> for ShortFixedKeysAggregatorState<T>
 where
-    T: DFPrimitiveType + ShortFixedKeyable,
+    T: DFPrimitiveType + ShortFixedKeyable + BucketKey,
     HashMethodFixedKeys<T>: HashMethod<HashKey = T>,
     <HashMethodFixedKeys<T> as HashMethod>::HashKey: HashTableKeyable,
 {
@@ -112,6 +123,9 @@ where
     type Entity = ShortFixedKeysStateEntity<T>;
     type Iterator = ShortFixedKeysStateIterator<T>;
 
+    // There are 65536 groups at most, and each bucket would take the whole array.
+    const TWO_LEVEL: bool = false;
+
     #[inline(always)]
     fn len(&self) -> usize {
         self.size
@@ -153,6 +167,17 @@ where
             value
         }
     }
+
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        let entities_bytes = self.max_size * std::mem::size_of::<ShortFixedKeysStateEntity<T>>();
+        self.area.allocated_bytes() + entities_bytes
+    }
+
+    fn insert_moved(&mut self, key: &T, place: usize) {
+        let mut inserted = true;
+        self.entity(key, &mut inserted).set_state_value(place);
+    }
 }
 
 pub struct LongerFixedKeysAggregatorState<T: HashTableKeyable> {
@@ -172,7 +197,7 @@ unsafe impl<T: HashTableKeyable + Sync> Sync for LongerFixedKeysAggregatorState<
 
 impl<T> AggregatorState<HashMethodFixedKeys<T>> for LongerFixedKeysAggregatorState<T>
 where
-    T: DFPrimitiveType,
+    T: DFPrimitiveType + BucketKey,
     HashMethodFixedKeys<T>: HashMethod<HashKey = T>,
     <HashMethodFixedKeys<T> as HashMethod>::HashKey: HashTableKeyable,
 {
@@ -207,6 +232,19 @@ where
     fn entity(&mut self, key: &Self::Key, inserted: &mut bool) -> *mut Self::Entity {
         self.data.insert_key(key, inserted)
     }
+
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        let entities_bytes = self.data.len() * std::mem::size_of::<Self::Entity>();
+        self.area.allocated_bytes() + entities_bytes
+    }
+
+    fn insert_moved(&mut self, key: &Self::Key, place: usize) {
+        let mut inserted = true;
+        self.data
+            .insert_key(key, &mut inserted)
+            .set_state_value(place);
+    }
 }
 
 pub struct SerializedKeysAggregatorState {
@@ -268,4 +306,17 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
 
         state_entity
     }
+
+    fn allocated_bytes(&self) -> usize {
+        let entities_bytes = self.data_state_map.len() * std::mem::size_of::<Self::Entity>();
+        self.keys_area.allocated_bytes() + self.state_area.allocated_bytes() + entities_bytes
+    }
+
+    // The keys stay in the keys area of the state they are moved from.
+    fn insert_moved(&mut self, key: &KeysRef, place: usize) {
+        let mut inserted = true;
+        self.data_state_map
+            .insert_key(key, &mut inserted)
+            .set_state_value(place);
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::HashMethod;
+
+use crate::common::HashTableKeyable;
+use crate::pipelines::transforms::group_by::aggregator_state_entity::StateEntity;
+use crate::pipelines::transforms::group_by::AggregatorState;
+use crate::pipelines::transforms::group_by::KeysRef;
+use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+
+/// The number of buckets of the two level group by.
+pub const BUCKETS: usize = 256;
+
+/// The bucket of the group key in the two level group by.
+///
+/// The hash is stable, unlike the hash of the hash tables, so the partial group by of every node
+/// and the final group by agree on the bucket of a key.
+pub trait BucketKey {
+    fn bucket(&self) -> usize;
+}
+
+macro_rules! primitive_bucket_key_impl {
+    ($primitive_type:ty) => {
+        impl BucketKey for $primitive_type {
+            #[inline(always)]
+            fn bucket(&self) -> usize {
+                (self.fast_hash() >> 56) as usize
+            }
+        }
+    };
+}
+
+primitive_bucket_key_impl!(u8);
+primitive_bucket_key_impl!(u16);
+primitive_bucket_key_impl!(u32);
+primitive_bucket_key_impl!(u64);
+
+impl BucketKey for [u8] {
+    #[inline(always)]
+    fn bucket(&self) -> usize {
+        let mut hash_value = self.len() as u64;
+        for chunk in self.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            hash_value = (hash_value ^ u64::from_le_bytes(word)).fast_hash();
+        }
+        (hash_value >> 56) as usize
+    }
+}
+
+impl BucketKey for Vec<u8> {
+    #[inline(always)]
+    fn bucket(&self) -> usize {
+        self.as_slice().bucket()
+    }
+}
+
+impl BucketKey for KeysRef {
+    #[inline(always)]
+    fn bucket(&self) -> usize {
+        unsafe { std::slice::from_raw_parts(self.address as *const u8, self.length).bucket() }
+    }
+}
+
+/// The aggregate state of the partial group by, it's a single level state at first and is split
+/// into BUCKETS states by the key bucket once the groups grow past group_by_two_level_threshold.
+pub struct TwoLevelAggregatorState<Method: HashMethod + PolymorphicKeysHelper<Method>> {
+    states: Vec<Method::State>,
+    // The single level state which the buckets are split from, the keys and the aggregate
+    // function states are moved to the buckets without copying, so it's kept alive with them.
+    single_level: Option<Method::State>,
+}
+
+impl<Method> TwoLevelAggregatorState<Method>
+where
+    Method: HashMethod + PolymorphicKeysHelper<Method>,
+    Method::HashKey: BucketKey,
+{
+    pub fn create(method: &Method) -> Self {
+        TwoLevelAggregatorState {
+            states: vec![method.aggregate_state()],
+            single_level: None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_two_level(&self) -> bool {
+        self.states.len() == BUCKETS
+    }
+
+    /// The single level state, or the states of the buckets.
+    #[inline(always)]
+    pub fn states(&self) -> &[Method::State] {
+        &self.states
+    }
+
+    /// The state which the key belongs to.
+    #[inline(always)]
+    pub fn state_of(&mut self, key: &Method::HashKey) -> &mut Method::State {
+        match self.states.len() {
+            1 => &mut self.states[0],
+            _ => &mut self.states[key.bucket()],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.iter().map(|state| state.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn allocated_bytes(&self) -> usize {
+        let single_level_bytes = self
+            .single_level
+            .as_ref()
+            .map_or(0, |state| state.allocated_bytes());
+        let states_bytes: usize = self.states.iter().map(|s| s.allocated_bytes()).sum();
+        single_level_bytes + states_bytes
+    }
+
+    /// Splits the single level state into the buckets, it's a no-op for the states which are
+    /// small enough to be kept in one level.
+    pub fn convert_to_two_level(&mut self, method: &Method) {
+        if self.is_two_level() || !<Method::State as AggregatorState<Method>>::TWO_LEVEL {
+            return;
+        }
+
+        let single_level = self.states.remove(0);
+        self.states = (0..BUCKETS).map(|_| method.aggregate_state()).collect();
+        for entity in single_level.iter() {
+            let key = entity.get_state_key();
+            let place = *entity.get_state_value();
+            self.states[key.bucket()].insert_moved(key, place);
+        }
+        self.single_level = Some(single_level);
+    }
+}
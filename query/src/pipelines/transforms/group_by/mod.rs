@@ -19,6 +19,7 @@ mod aggregator_polymorphic_keys;
 mod aggregator_state;
 mod aggregator_state_entity;
 mod aggregator_state_iterator;
+mod aggregator_two_level;
 mod keys_ref;
 
 pub use aggregator::Aggregator;
@@ -26,3 +27,7 @@ pub use aggregator_params::AggregatorParams;
 pub use aggregator_params::AggregatorParamsRef;
 pub use aggregator_polymorphic_keys::PolymorphicKeysHelper;
 pub use aggregator_state::AggregatorState;
+pub use aggregator_two_level::BucketKey;
+pub use aggregator_two_level::TwoLevelAggregatorState;
+pub use aggregator_two_level::BUCKETS;
+pub use keys_ref::KeysRef;
@@ -16,12 +16,16 @@ pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
+pub use transform_cte_scan::CteScanTransform;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::HavingTransform;
 pub use transform_filter::WhereTransform;
 pub use transform_group_by_final::GroupByFinalTransform;
 pub use transform_group_by_partial::GroupByPartialTransform;
+pub use transform_hash_join::HashJoinTransform;
+pub use transform_hash_join::JoinBuildPuller;
+pub use transform_hash_join::JoinHashTable;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
@@ -36,6 +40,8 @@ mod transform_aggregator_final_test;
 #[cfg(test)]
 mod transform_aggregator_partial_test;
 #[cfg(test)]
+mod transform_cte_scan_test;
+#[cfg(test)]
 mod transform_expression_test;
 #[cfg(test)]
 mod transform_filter_test;
@@ -44,6 +50,8 @@ mod transform_group_by_final_test;
 #[cfg(test)]
 mod transform_group_by_partial_test;
 #[cfg(test)]
+mod transform_hash_join_test;
+#[cfg(test)]
 mod transform_limit_by_test;
 #[cfg(test)]
 mod transform_limit_test;
@@ -57,11 +65,13 @@ mod transform_source_test;
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_create_sets;
+mod transform_cte_scan;
 mod transform_expression;
 mod transform_expression_executor;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
@@ -71,3 +81,4 @@ mod transform_sort_partial;
 mod transform_source;
 
 mod group_by;
+mod spill;
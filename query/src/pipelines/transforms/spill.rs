@@ -0,0 +1,98 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_base::uuid;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::sessions::DatabendQueryContextRef;
+
+/// An arrow IPC file of spilled blocks under the temp data path, the file is removed once it's
+/// dropped, so the spilled files are cleaned up on both the completion and the cancellation.
+pub struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    pub fn try_create(ctx: &DatabendQueryContextRef, prefix: &str) -> Result<SpillFile> {
+        let temp_data_path = ctx.get_config().storage.disk.temp_data_path;
+        let dir = match temp_data_path.is_empty() {
+            true => std::env::temp_dir(),
+            false => PathBuf::from(temp_data_path),
+        };
+        std::fs::create_dir_all(&dir)?;
+
+        let name = format!("{}-{}-{}.arrow", prefix, ctx.get_id(), uuid::Uuid::new_v4());
+        Ok(SpillFile {
+            path: dir.join(name),
+        })
+    }
+
+    pub fn write(&self, schema: &DataSchemaRef, blocks: Vec<DataBlock>) -> Result<()> {
+        tracing::debug!("spill {} blocks to {:?}", blocks.len(), self.path);
+
+        let file = BufWriter::new(File::create(&self.path)?);
+        let options = WriteOptions { compression: None };
+        let mut writer = FileWriter::try_new(file, &schema.to_arrow(), options)?;
+        for block in blocks {
+            writer.write(&RecordBatch::try_from(block)?)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Reads the spilled blocks back one by one, the file is removed with the reader.
+    pub fn read(self) -> Result<SpillReader> {
+        let mut file = BufReader::new(File::open(&self.path)?);
+        let metadata = read_file_metadata(&mut file)?;
+        Ok(SpillReader {
+            reader: FileReader::new(file, metadata, None),
+            _file: self,
+        })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(cause) = std::fs::remove_file(&self.path) {
+            tracing::warn!("failed to remove the spill file {:?}: {}", self.path, cause);
+        }
+    }
+}
+
+pub struct SpillReader {
+    reader: FileReader<BufReader<File>>,
+    _file: SpillFile,
+}
+
+impl Iterator for SpillReader {
+    type Item = Result<DataBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next().map(|batch| DataBlock::try_from(batch?))
+    }
+}
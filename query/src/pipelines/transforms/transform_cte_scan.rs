@@ -0,0 +1,92 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CteScanPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::FutureExt;
+use futures::TryStreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::sessions::CteData;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct CteScanTransform {
+    ctx: DatabendQueryContextRef,
+    plan: CteScanPlan,
+}
+
+impl CteScanTransform {
+    pub fn try_create(ctx: DatabendQueryContextRef, plan: CteScanPlan) -> Result<Self> {
+        Ok(CteScanTransform { ctx, plan })
+    }
+
+    fn evaluate_cte(&self) -> Result<CteData> {
+        let cte_ctx = DatabendQueryContext::new(self.ctx.clone());
+        let mut pipeline = PipelineBuilder::create(cte_ctx).build(&self.plan.query_plan)?;
+
+        let cte_future = async move {
+            let stream = pipeline.execute().await?;
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            Ok(Arc::new(blocks))
+        };
+
+        Ok(cte_future.boxed().shared())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for CteScanTransform {
+    fn name(&self) -> &str {
+        "CteScanTransform"
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::LogicalError(
+            "Cannot call CteScanTransform connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![Arc::new(EmptyProcessor::create())]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute, cte:{} ...", self.plan.name);
+
+        let cte_data = self
+            .ctx
+            .get_cte_data(&self.plan.name, || self.evaluate_cte())?;
+        let blocks = cte_data.await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            blocks.as_ref().clone(),
+        )))
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::pipelines::processors::*;
+use crate::pipelines::transforms::*;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_cte_scan() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+    let query_plan = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(3)?);
+    let plan = CteScanPlan {
+        name: "t_0".to_string(),
+        query_plan: Arc::new(query_plan),
+    };
+
+    // Two scans of the same CTE.
+    for _ in 0..2 {
+        let scan = CteScanTransform::try_create(ctx.clone(), plan.clone())?;
+        let stream = scan.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+--------+",
+            "| number |",
+            "+--------+",
+            "| 0      |",
+            "| 1      |",
+            "| 2      |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The CTE has been evaluated by the first scan.
+    let cte_data = ctx.get_cte_data("t_0", || {
+        Err(ErrorCode::LogicalError("CTE must be evaluated only once"))
+    })?;
+    let rows = cte_data
+        .await?
+        .iter()
+        .map(|block| block.num_rows())
+        .sum::<usize>();
+    assert_eq!(rows, 3);
+
+    Ok(())
+}
@@ -18,23 +18,29 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use bumpalo::Bump;
+use common_base::MemoryTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethodKind;
+use common_datavalues::arrays::StringArrayBuilder;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::StateAddr;
-use common_infallible::RwLock;
+use common_io::prelude::BytesMut;
 use common_planners::Expression;
-use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::stream::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::BucketKey;
+use crate::pipelines::transforms::group_by::BUCKETS;
+use crate::pipelines::transforms::spill::SpillFile;
+use crate::sessions::DatabendQueryContextRef;
 
 pub struct GroupByFinalTransform {
+    ctx: DatabendQueryContextRef,
     max_block_size: usize,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
@@ -45,6 +51,7 @@ pub struct GroupByFinalTransform {
 
 impl GroupByFinalTransform {
     pub fn create(
+        ctx: DatabendQueryContextRef,
         schema: DataSchemaRef,
         max_block_size: usize,
         schema_before_group_by: DataSchemaRef,
@@ -52,6 +59,7 @@ impl GroupByFinalTransform {
         group_exprs: Vec<Expression>,
     ) -> Self {
         Self {
+            ctx,
             max_block_size,
             aggr_exprs,
             group_exprs,
@@ -81,6 +89,11 @@ impl Processor for GroupByFinalTransform {
         self
     }
 
+    /// The states are merged into a single hash table, which is partitioned into 256 buckets by
+    /// the key bucket once the groups grow past group_by_two_level_threshold. When the bytes
+    /// allocated by the states exceed max_bytes_before_external_group_by, the buckets are spilled
+    /// to the disk. The result is streamed bucket by bucket, each bucket is restored, merged and
+    /// finalized only when the stream is polled for it.
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
         let funcs = self
@@ -89,6 +102,12 @@ impl Processor for GroupByFinalTransform {
             .map(|x| x.to_aggregate_function(&self.schema_before_group_by))
             .collect::<Result<Vec<_>>>()?;
 
+        let aggr_types = self
+            .aggr_exprs
+            .iter()
+            .map(|x| x.to_data_type(&self.schema_before_group_by))
+            .collect::<Result<Vec<_>>>()?;
+
         let aggr_funcs_len = funcs.len();
         let group_expr_len = self.group_exprs.len();
 
@@ -104,8 +123,14 @@ impl Processor for GroupByFinalTransform {
             .map(|c| c.to_data_field(&self.schema_before_group_by))
             .collect::<Result<Vec<_>>>()?;
 
-        let start = Instant::now();
-        let arena = Bump::new();
+        let settings = self.ctx.get_settings();
+        let two_level_threshold = settings.get_group_by_two_level_threshold()? as usize;
+        let max_bytes_before_external_group_by =
+            settings.get_max_bytes_before_external_group_by()? as usize;
+
+        let ctx = self.ctx.clone();
+        let schema = self.schema.clone();
+        let max_block_size = self.max_block_size;
 
         let mut stream = self.input.execute().await?;
         let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
@@ -114,115 +139,226 @@ impl Processor for GroupByFinalTransform {
         let (layout, offsets_aggregate_states) = unsafe { get_layout_offsets(&funcs) };
 
         macro_rules! apply {
-            ($hash_method: ident, $key_array_type: ty, $downcast_fn: ident, $group_func_table: ty) => {{
-                type GroupFuncTable = $group_func_table;
-                let groups_locker = GroupFuncTable::default();
-
-                while let Some(block) = stream.next().await {
-                    let mut groups = groups_locker.write();
-                    let block = block?;
-
-                    let key_array = block.column(aggr_funcs_len).to_array()?;
-                    let key_array: $key_array_type = key_array.$downcast_fn()?;
-
-                    let states_series = (0..aggr_funcs_len)
-                        .map(|i| block.column(i).to_array())
-                        .collect::<Result<Vec<_>>>()?;
-                    let mut states_binary_arrays = Vec::with_capacity(states_series.len());
-
-                    for agg in states_series.iter().take(aggr_funcs_len) {
-                        let aggr_array: &DFStringArray = agg.string()?;
-                        let aggr_array = aggr_array.inner();
-                        states_binary_arrays.push(aggr_array);
-                    }
+            ($hash_method: ident, $key_array_type: ty, $downcast_fn: ident, $key_type: ty) => {{
+                type GroupFuncTable = HashMap<$key_type, usize, ahash::RandomState>;
+
+                Ok(Box::pin(async_stream::try_stream! {
+                    // Partition the single level table into the buckets.
+                    let two_level = |table: GroupFuncTable| {
+                        let mut buckets = (0..BUCKETS)
+                            .map(|_| GroupFuncTable::default())
+                            .collect::<Vec<_>>();
+                        for (key, place) in table {
+                            buckets[key.bucket()].insert(key, place);
+                        }
+                        buckets
+                    };
+
+                    // Merge the states of the block into the tables, allocating in the arena.
+                    let merge_block = |tables: &mut Vec<GroupFuncTable>,
+                                       arena: &Bump,
+                                       block: &DataBlock|
+                     -> Result<()> {
+                        let key_array = block.column(aggr_funcs_len).to_array()?;
+                        let key_array: $key_array_type = key_array.$downcast_fn()?;
+
+                        let states_series = (0..aggr_funcs_len)
+                            .map(|i| block.column(i).to_array())
+                            .collect::<Result<Vec<_>>>()?;
+                        let mut states_binary_arrays = Vec::with_capacity(states_series.len());
+
+                        for agg in states_series.iter().take(aggr_funcs_len) {
+                            let aggr_array: &DFStringArray = agg.string()?;
+                            let aggr_array = aggr_array.inner();
+                            states_binary_arrays.push(aggr_array);
+                        }
+
+                        for row in 0..block.num_rows() {
+                            let group_key = $hash_method.get_key(&key_array, row);
+                            let groups = match tables.len() {
+                                1 => &mut tables[0],
+                                _ => &mut tables[group_key.bucket()],
+                            };
+                            match groups.get(&group_key) {
+                                None => {
+                                    if aggr_funcs_len == 0 {
+                                        groups.insert(group_key, 0usize);
+                                    } else {
+                                        let place: StateAddr = arena.alloc_layout(layout).into();
+                                        for (idx, func) in funcs.iter().enumerate() {
+                                            let offset = offsets_aggregate_states[idx];
+                                            let arg_place = place.next(offset);
+
+                                            let mut data = states_binary_arrays[idx].value(row);
+                                            func.init_state(arg_place);
+                                            func.deserialize(arg_place, &mut data)?;
+                                        }
+                                        groups.insert(group_key, place.addr());
+                                    }
+                                }
+                                Some(place) => {
+                                    let place: StateAddr = (*place).into();
 
-                    for row in 0..block.num_rows() {
-                        let group_key = $hash_method.get_key(&key_array, row);
-                        match groups.get(&group_key) {
-                            None => {
-                                if aggr_funcs_len == 0 {
-                                    groups.insert(group_key, 0usize);
-                                } else {
-                                    let place: StateAddr = arena.alloc_layout(layout).into();
                                     for (idx, func) in funcs.iter().enumerate() {
                                         let arg_place = place.next(offsets_aggregate_states[idx]);
 
                                         let mut data = states_binary_arrays[idx].value(row);
-                                        func.init_state(arg_place);
-                                        func.deserialize(arg_place, &mut data)?;
+                                        let temp = arena.alloc_layout(funcs[idx].state_layout());
+                                        let temp_addr = temp.into();
+
+                                        funcs[idx].init_state(temp_addr);
+                                        func.deserialize(temp_addr, &mut data)?;
+                                        func.merge(arg_place, temp_addr)?;
                                     }
-                                    groups.insert(group_key, place.addr());
                                 }
-                            }
-                            Some(place) => {
+                            };
+                        }
+                        Ok(())
+                    };
+
+                    // Serialize the states of the table into a block of the partial layout.
+                    let serialize_table =
+                        |table: &GroupFuncTable, schema: &DataSchemaRef| -> Result<DataBlock> {
+                            let mut state_builders: Vec<StringArrayBuilder> = (0..aggr_funcs_len)
+                                .map(|_| StringArrayBuilder::with_capacity(table.len() * 4))
+                                .collect();
+                            let mut keys = Vec::with_capacity(table.len());
+
+                            let mut bytes = BytesMut::new();
+                            for (key, place) in table.iter() {
                                 let place: StateAddr = (*place).into();
-
                                 for (idx, func) in funcs.iter().enumerate() {
                                     let arg_place = place.next(offsets_aggregate_states[idx]);
-
-                                    let mut data = states_binary_arrays[idx].value(row);
-                                    let temp = arena.alloc_layout(funcs[idx].state_layout());
-                                    let temp_addr = temp.into();
-
-                                    funcs[idx].init_state(temp_addr);
-                                    func.deserialize(temp_addr, &mut data)?;
-                                    func.merge(arg_place, temp_addr)?;
+                                    func.serialize(arg_place, &mut bytes)?;
+                                    state_builders[idx].append_value(&bytes[..]);
+                                    bytes.clear();
                                 }
+                                keys.push(DataValue::from(key.clone()));
                             }
+
+                            let mut columns: Vec<Series> = Vec::with_capacity(aggr_funcs_len + 1);
+                            for mut builder in state_builders {
+                                columns.push(builder.finish().into_series());
+                            }
+                            let key_type = schema.field(aggr_funcs_len).data_type();
+                            columns.push(DataValue::try_into_data_array(&keys, key_type)?);
+                            Ok(DataBlock::create_by_array(schema.clone(), columns))
                         };
-                    }
-                }
-                let delta = start.elapsed();
-                tracing::debug!("Group by final cost: {:?}", delta);
 
-                // Collect the merge states.
-                let groups = groups_locker.read();
+                    // Build the final blocks of the merged states.
+                    let finalize = |tables: &[GroupFuncTable]| -> Result<Vec<DataBlock>> {
+                        let groups_len = tables.iter().map(|table| table.len()).sum();
 
-                let mut aggr_values: Vec<Vec<DataValue>> = {
-                    let mut values = vec![];
-                    for _i in 0..aggr_funcs_len {
-                        values.push(vec![])
-                    }
-                    values
-                };
-                let mut keys = Vec::with_capacity(groups.len());
-                for (key, place) in groups.iter() {
-                    keys.push(key.clone());
-
-                    let place: StateAddr = (*place).into();
-                    for (idx, func) in funcs.iter().enumerate() {
-                        let arg_place = place.next(offsets_aggregate_states[idx]);
-                        let merge = func.merge_result(arg_place)?;
-                        aggr_values[idx].push(merge);
-                    }
-                }
+                        let mut aggr_values: Vec<Vec<DataValue>> = {
+                            let mut values = vec![];
+                            for _i in 0..aggr_funcs_len {
+                                values.push(vec![])
+                            }
+                            values
+                        };
+                        let mut keys = Vec::with_capacity(groups_len);
+                        for (key, place) in tables.iter().flat_map(|table| table.iter()) {
+                            keys.push(key.clone());
+
+                            let place: StateAddr = (*place).into();
+                            for (idx, func) in funcs.iter().enumerate() {
+                                let arg_place = place.next(offsets_aggregate_states[idx]);
+                                let merge = func.merge_result(arg_place)?;
+                                aggr_values[idx].push(merge);
+                            }
+                        }
+
+                        // Build final state block.
+                        let mut columns: Vec<Series> =
+                            Vec::with_capacity(aggr_funcs_len + group_expr_len);
+
+                        for (i, value) in aggr_values.iter().enumerate() {
+                            columns.push(DataValue::try_into_data_array(
+                                value.as_slice(),
+                                &aggr_types[i],
+                            )?);
+                        }
+
+                        {
+                            let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
+                            columns.extend_from_slice(&group_columns);
+                        }
+
+                        let mut blocks = vec![];
+                        if !columns.is_empty() {
+                            let block = DataBlock::create_by_array(schema.clone(), columns);
+                            blocks = DataBlock::split_block_by_size(&block, max_block_size)?;
+                        }
+                        Ok(blocks)
+                    };
+
+                    let start = Instant::now();
+                    let mut arena = Bump::new();
+                    let mut tables = vec![GroupFuncTable::default()];
+                    // The spilled files of each bucket, empty until the first spill.
+                    let mut spilled: Vec<Vec<SpillFile>> = vec![];
+
+                    while let Some(block) = stream.next().await {
+                        let block = block?;
+                        merge_block(&mut tables, &arena, &block)?;
+
+                        if tables.len() == 1 && tables[0].len() > two_level_threshold {
+                            tables = two_level(tables.remove(0));
+                        }
+
+                        // The bytes allocated by the states of this transform only, so the
+                        // trigger is reset by the spill.
+                        let groups_len: usize = tables.iter().map(|table| table.len()).sum();
+                        let allocated_bytes = arena.allocated_bytes()
+                            + groups_len * std::mem::size_of::<($key_type, usize)>();
+                        if max_bytes_before_external_group_by != 0
+                            && allocated_bytes > max_bytes_before_external_group_by
+                        {
+                            if tables.len() == 1 {
+                                tables = two_level(tables.remove(0));
+                            }
+                            if spilled.is_empty() {
+                                spilled = (0..BUCKETS).map(|_| vec![]).collect();
+                            }
 
-                // Build final state block.
-                let mut columns: Vec<Series> = Vec::with_capacity(aggr_funcs_len + group_expr_len);
+                            for (bucket, table) in tables.iter_mut().enumerate() {
+                                if table.is_empty() {
+                                    continue;
+                                }
 
-                for (i, value) in aggr_values.iter().enumerate() {
-                    columns.push(DataValue::try_into_data_array(
-                        value.as_slice(),
-                        &self.aggr_exprs[i].to_data_type(&self.schema_before_group_by)?,
-                    )?);
-                }
+                                let file = SpillFile::try_create(&ctx, "group-by-final")?;
+                                let spilled_block = serialize_table(table, block.schema())?;
+                                file.write(block.schema(), vec![spilled_block])?;
+                                spilled[bucket].push(file);
+                                *table = GroupFuncTable::default();
+                            }
 
-                {
-                    let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
-                    columns.extend_from_slice(&group_columns);
-                }
+                            // All the states in the arena are spilled.
+                            arena = Bump::new();
+                        }
 
-                let mut blocks = vec![];
-                if !columns.is_empty() {
-                    let block = DataBlock::create_by_array(self.schema.clone(), columns);
-                    blocks = DataBlock::split_block_by_size(&block, self.max_block_size)?;
-                }
+                        MemoryTracker::check_current_memory_limit()?;
+                    }
+                    let delta = start.elapsed();
+                    tracing::debug!("Group by final merge cost: {:?}", delta);
+
+                    // The single level table is never spilled, it's finalized as a whole.
+                    let mut spilled = spilled.into_iter();
+                    for bucket in 0..tables.len() {
+                        let mut bucket_tables = vec![std::mem::take(&mut tables[bucket])];
+                        let bucket_arena = Bump::new();
+                        for file in spilled.next().unwrap_or_default() {
+                            for block in file.read()? {
+                                merge_block(&mut bucket_tables, &bucket_arena, &block?)?;
+                            }
+                        }
+                        MemoryTracker::check_current_memory_limit()?;
 
-                Ok(Box::pin(DataBlockStream::create(
-                    self.schema.clone(),
-                    None,
-                    blocks,
-                )))
+                        for block in finalize(&bucket_tables)? {
+                            yield block;
+                        }
+                    }
+                }))
             }};
         }
 
@@ -230,19 +366,19 @@ impl Processor for GroupByFinalTransform {
             ($method: ident, $apply: ident) => {{
                 match $method {
                     HashMethodKind::Serializer(hash_method) => {
-                        apply! { hash_method,  &DFStringArray, string, RwLock<HashMap<Vec<u8>, usize, ahash::RandomState>>}
+                        apply! { hash_method,  &DFStringArray, string, Vec<u8> }
                     }
                     HashMethodKind::KeysU8(hash_method) => {
-                        apply! { hash_method , &DFUInt8Array, u8, RwLock<HashMap<u8, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt8Array, u8, u8 }
                     }
                     HashMethodKind::KeysU16(hash_method) => {
-                        apply! { hash_method , &DFUInt16Array, u16, RwLock<HashMap<u16, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt16Array, u16, u16 }
                     }
                     HashMethodKind::KeysU32(hash_method) => {
-                        apply! { hash_method , &DFUInt32Array, u32, RwLock<HashMap<u32, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt32Array, u32, u32 }
                     }
                     HashMethodKind::KeysU64(hash_method) => {
-                        apply! { hash_method , &DFUInt64Array, u64, RwLock<HashMap<u64, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt64Array, u64, u64 }
                     }
                 }
             }};
@@ -51,6 +51,7 @@ async fn test_transform_final_group_by() -> Result<()> {
     pipeline.add_source(Arc::new(source))?;
     pipeline.add_simple_transform(|| {
         Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
             aggr_partial.schema(),
             source_schema.clone(),
             aggr_exprs.to_vec(),
@@ -62,6 +63,7 @@ async fn test_transform_final_group_by() -> Result<()> {
     let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
     pipeline.add_simple_transform(|| {
         Ok(Box::new(GroupByFinalTransform::create(
+            ctx.clone(),
             aggr_final.schema(),
             max_block_size,
             source_schema.clone(),
@@ -92,3 +94,77 @@ async fn test_transform_final_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_two_level_spill() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    // partition the groups into the buckets, and spill the states for every block
+    ctx.get_settings().set_group_by_two_level_threshold(2)?;
+    ctx.get_settings()
+        .set_max_bytes_before_external_group_by(1)?;
+    ctx.get_settings().set_max_block_size(2)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+        )?
+        .build()?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(6)?;
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+    pipeline.merge_processor()?;
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByFinalTransform::create(
+            ctx.clone(),
+            aggr_final.schema(),
+            2,
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    // SELECT SUM(number), AVG(number), number from numbers(6) group by number;
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 1           | 1           | 1      |",
+        "| 2           | 2           | 2      |",
+        "| 3           | 3           | 3      |",
+        "| 4           | 4           | 4      |",
+        "| 5           | 5           | 5      |",
+        "+-------------+-------------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
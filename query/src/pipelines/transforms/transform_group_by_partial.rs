@@ -29,9 +29,14 @@ use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::transforms::group_by::Aggregator;
 use crate::pipelines::transforms::group_by::AggregatorParams;
+use crate::pipelines::transforms::group_by::BucketKey;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+use crate::pipelines::transforms::group_by::BUCKETS;
+use crate::pipelines::transforms::spill::SpillFile;
+use crate::sessions::DatabendQueryContextRef;
 
 pub struct GroupByPartialTransform {
+    ctx: DatabendQueryContextRef,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
 
@@ -42,12 +47,14 @@ pub struct GroupByPartialTransform {
 
 impl GroupByPartialTransform {
     pub fn create(
+        ctx: DatabendQueryContextRef,
         schema: DataSchemaRef,
         schema_before_group_by: DataSchemaRef,
         aggr_exprs: Vec<Expression>,
         group_exprs: Vec<Expression>,
     ) -> Self {
         Self {
+            ctx,
             aggr_exprs,
             group_exprs,
             schema,
@@ -64,11 +71,15 @@ impl GroupByPartialTransform {
     }
 
     #[inline]
-    async fn aggregate<Method: HashMethod + PolymorphicKeysHelper<Method>>(
+    async fn aggregate<Method>(
         &self,
         method: Method,
         group_cols: Vec<String>,
-    ) -> Result<SendableDataBlockStream> {
+    ) -> Result<SendableDataBlockStream>
+    where
+        Method: HashMethod + PolymorphicKeysHelper<Method>,
+        Method::HashKey: BucketKey,
+    {
         let start = Instant::now();
 
         let stream = self.input.execute().await?;
@@ -76,14 +87,62 @@ impl GroupByPartialTransform {
         let schema = self.schema_before_group_by.clone();
         let aggregator_params = AggregatorParams::try_create(schema, aggr_exprs)?;
 
+        let settings = self.ctx.get_settings();
+        let two_level_threshold = settings.get_group_by_two_level_threshold()? as usize;
+        let max_bytes_before_external_group_by =
+            settings.get_max_bytes_before_external_group_by()? as usize;
+        let finalized_schema = self.schema.clone();
+
+        // The states are spilled bucket by bucket, a file for each bucket of a spill.
         let aggregator = Aggregator::create(method, aggregator_params);
-        let state = aggregator.aggregate(group_cols, stream).await?;
+        let mut spilled: Vec<Vec<SpillFile>> = vec![];
+        let spill = |state| {
+            let blocks = aggregator.aggregate_finalized_buckets(&state, self.schema.clone())?;
+            if spilled.is_empty() {
+                spilled = (0..BUCKETS).map(|_| vec![]).collect();
+            }
+
+            for (bucket, block) in blocks.into_iter().enumerate() {
+                if let Some(block) = block {
+                    let file = SpillFile::try_create(&self.ctx, "group-by-partial")?;
+                    file.write(&self.schema, vec![block])?;
+                    spilled[bucket].push(file);
+                }
+            }
+            Ok(())
+        };
+        let state = aggregator
+            .aggregate(
+                group_cols,
+                stream,
+                two_level_threshold,
+                max_bytes_before_external_group_by,
+                spill,
+            )
+            .await?;
 
         let delta = start.elapsed();
         tracing::debug!("Group by partial cost: {:?}", delta);
 
-        let finalized_schema = self.schema.clone();
-        aggregator.aggregate_finalized(&state, finalized_schema)
+        if spilled.is_empty() {
+            return aggregator.aggregate_finalized(&state, finalized_schema);
+        }
+
+        // The states are sent bucket by bucket, the spilled ones of a bucket are restored only
+        // when the bucket is reached.
+        let blocks = aggregator.aggregate_finalized_buckets(&state, finalized_schema)?;
+        Ok(Box::pin(async_stream::try_stream! {
+            for (files, block) in spilled.into_iter().zip(blocks.into_iter()) {
+                for file in files {
+                    for spilled_block in file.read()? {
+                        yield spilled_block?;
+                    }
+                }
+                if let Some(block) = block {
+                    yield block;
+                }
+            }
+        }))
     }
 }
 
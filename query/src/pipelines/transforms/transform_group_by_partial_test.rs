@@ -44,6 +44,7 @@ async fn test_transform_partial_group_by() -> Result<()> {
     pipeline.add_source(Arc::new(source))?;
     pipeline.add_simple_transform(|| {
         Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
             aggr_partial.schema(),
             source_schema.clone(),
             aggr_exprs.clone(),
@@ -75,3 +76,61 @@ async fn test_transform_partial_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_partial_group_by_two_level_spill() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    // partition the groups into the buckets, and spill the buckets for every block
+    ctx.get_settings().set_group_by_two_level_threshold(2)?;
+    ctx.get_settings()
+        .set_max_bytes_before_external_group_by(1)?;
+    ctx.get_settings().set_max_block_size(2)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = vec![sum(col("number")), avg(col("number"))];
+    let group_exprs = vec![col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(&aggr_exprs, &group_exprs)?
+        .build()?;
+
+    // Pipeline.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(5)?;
+    let source_schema = test_source.number_schema_for_test()?;
+
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.clone(),
+            group_exprs.clone(),
+        )))
+    })?;
+    pipeline.merge_processor()?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 5);
+
+    // SELECT SUM(number), AVG(number), number ... GROUP BY number;
+    // binary-state, the states of a group are sent by a single bucket
+    let expected = vec![
+        "+-------------+-------------+---------------+",
+        "| sum(number) | avg(number) | _group_by_key |",
+        "+-------------+-------------+---------------+",
+        "| \u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | \u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | 0             |",
+        "| \u{1}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | \u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | 1             |",
+        "| \u{1}\u{2}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | \u{2}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | 2             |",
+        "| \u{1}\u{3}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | \u{3}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | 3             |",
+        "| \u{1}\u{4}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | \u{4}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{1}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}\u{0}            | 4             |",
+        "+-------------+-------------+---------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,490 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_stream::stream;
+use bumpalo::Bump;
+use common_base::MemoryTracker;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::Expression;
+use common_planners::JoinType;
+use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
+use futures::future::BoxFuture;
+use futures::future::Shared;
+use futures::Future;
+use futures::FutureExt;
+use futures::StreamExt;
+
+use crate::common::HashMap;
+use crate::common::HashTableEntity;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::KeysRef;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+
+/// The end of the rows chain of a key.
+const CHAIN_END: usize = usize::MAX;
+
+/// The hash table built from all the rows of the join build(right) side.
+///
+/// Rows with the same key are linked as a chain:
+/// `heads` maps the serialized key to the last inserted row,
+/// and `next[row]` points to the previous row with the same key.
+pub struct JoinHashTable {
+    block: DataBlock,
+    keys_area: Bump,
+    heads: HashMap<KeysRef, usize>,
+    next: Vec<usize>,
+}
+
+// TODO:(Winter) Hack:
+// The KeysRef points to the keys_area, the hash table is immutable after it has been built.
+unsafe impl Send for JoinHashTable {}
+
+// TODO:(Winter) Hack:
+// The KeysRef points to the keys_area, the hash table is immutable after it has been built.
+unsafe impl Sync for JoinHashTable {}
+
+pub type JoinHashTableRef = Arc<JoinHashTable>;
+
+impl JoinHashTable {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        blocks: Vec<DataBlock>,
+        keys_executor: &ExpressionExecutor,
+    ) -> Result<JoinHashTable> {
+        let block = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(schema),
+            false => DataBlock::concat_blocks(&blocks)?,
+        };
+        MemoryTracker::check_current_memory_limit()?;
+
+        let rows = block.num_rows();
+        let mut hash_table = JoinHashTable {
+            block,
+            keys_area: Bump::new(),
+            heads: HashMap::create(),
+            next: vec![CHAIN_END; rows],
+        };
+
+        if rows == 0 {
+            return Ok(hash_table);
+        }
+
+        let (keys, valids) = Self::build_keys(&keys_executor.execute(&hash_table.block)?)?;
+        for (row, key) in keys.iter().enumerate() {
+            // NULL never equals to anything, so the row can't be matched.
+            if !valids[row] {
+                continue;
+            }
+
+            let mut inserted = false;
+            let mut keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+            let entity = hash_table.heads.insert_key(&keys_ref, &mut inserted);
+
+            match inserted {
+                true => unsafe {
+                    // Keys will be destroyed after build, we need copy the keys to the memory pool.
+                    let global_keys = hash_table.keys_area.alloc_slice_copy(key);
+                    let inserted_hash = entity.get_hash();
+                    keys_ref.address = global_keys.as_ptr() as usize;
+                    entity.set_key_and_hash(&keys_ref, inserted_hash);
+                },
+                false => hash_table.next[row] = *entity.get_value(),
+            }
+
+            entity.set_value(row);
+        }
+
+        Ok(hash_table)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.block.num_rows()
+    }
+
+    pub fn keys_executor(
+        schema: &DataSchemaRef,
+        keys: &[Expression],
+    ) -> Result<ExpressionExecutor> {
+        let keys_fields = keys
+            .iter()
+            .map(|key| key.to_data_field(schema))
+            .collect::<Result<Vec<_>>>()?;
+
+        ExpressionExecutor::try_create(
+            "join keys executor",
+            schema.clone(),
+            DataSchemaRefExt::create(keys_fields),
+            keys.to_vec(),
+            false,
+        )
+    }
+
+    /// Serialize the keys of each row, a row is invalid if any of its keys is NULL.
+    fn build_keys(keys_block: &DataBlock) -> Result<(Vec<Vec<u8>>, Vec<bool>)> {
+        let rows = keys_block.num_rows();
+        let mut valids = vec![true; rows];
+        let mut keys_columns = Vec::with_capacity(keys_block.num_columns());
+
+        for column in keys_block.columns() {
+            let series = column.to_array()?;
+            if series.null_count() == rows {
+                return Ok((vec![vec![]; rows], vec![false; rows]));
+            }
+
+            if series.null_count() > 0 {
+                for (row, valid) in valids.iter_mut().enumerate() {
+                    if series.is_null(row) {
+                        *valid = false;
+                    }
+                }
+            }
+
+            keys_columns.push(DataColumn::Array(series));
+        }
+
+        let keys_columns = keys_columns.iter().collect::<Vec<_>>();
+        let keys = HashMethodSerializer::default().build_keys(&keys_columns, rows)?;
+        Ok((keys, valids))
+    }
+
+    /// Probe the hash table with a block of the probe(left) side.
+    /// The matched build rows are marked in `build_matched` if it's not empty.
+    pub fn probe(
+        &self,
+        join_type: JoinType,
+        schema: &DataSchemaRef,
+        keys_executor: &ExpressionExecutor,
+        block: &DataBlock,
+        build_matched: &mut [bool],
+    ) -> Result<DataBlock> {
+        let (keys, valids) = Self::build_keys(&keys_executor.execute(block)?)?;
+
+        if !join_type.output_right() {
+            return self.probe_existence(join_type, schema, block, &keys, &valids);
+        }
+
+        let mut probe_indices = Vec::with_capacity(block.num_rows());
+        let mut build_indices = Vec::with_capacity(block.num_rows());
+        for (row, key) in keys.iter().enumerate() {
+            let mut matched = false;
+
+            if valids[row] {
+                let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+                if let Some(entity) = self.heads.find_key(&keys_ref) {
+                    let mut build_row = *entity.get_value();
+                    while build_row != CHAIN_END {
+                        probe_indices.push(row as u32);
+                        build_indices.push(Some(build_row));
+                        if !build_matched.is_empty() {
+                            build_matched[build_row] = true;
+                        }
+                        build_row = self.next[build_row];
+                    }
+                    matched = true;
+                }
+            }
+
+            if !matched && join_type.keep_unmatched_left() {
+                probe_indices.push(row as u32);
+                build_indices.push(None);
+            }
+        }
+
+        if probe_indices.is_empty() {
+            return Ok(DataBlock::empty_with_schema(schema.clone()));
+        }
+
+        let probe_block = DataBlock::block_take_by_indices(block, &[], &probe_indices)?;
+        let build_block = DataBlock::block_take_by_opt_indices(&self.block, &build_indices)?;
+        Self::merge_columns(schema, &probe_block, &build_block)
+    }
+
+    /// SEMI join keeps the probe rows having a match and ANTI join keeps the others,
+    /// each probe row is output at most once.
+    fn probe_existence(
+        &self,
+        join_type: JoinType,
+        schema: &DataSchemaRef,
+        block: &DataBlock,
+        keys: &[Vec<u8>],
+        valids: &[bool],
+    ) -> Result<DataBlock> {
+        let keep_matched = join_type == JoinType::Semi;
+        let probe_indices = keys
+            .iter()
+            .enumerate()
+            .filter(|(row, key)| {
+                let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+                let matched = valids[*row] && self.heads.find_key(&keys_ref).is_some();
+                matched == keep_matched
+            })
+            .map(|(row, _)| row as u32)
+            .collect::<Vec<_>>();
+
+        if probe_indices.is_empty() {
+            return Ok(DataBlock::empty_with_schema(schema.clone()));
+        }
+
+        let probe_block = match probe_indices.len() == block.num_rows() {
+            true => block.clone(),
+            false => DataBlock::block_take_by_indices(block, &[], &probe_indices)?,
+        };
+        Ok(DataBlock::create(
+            schema.clone(),
+            probe_block.columns().to_vec(),
+        ))
+    }
+
+    /// The build rows without any match, the probe side columns are NULL-extended.
+    pub fn unmatched_build_rows(
+        &self,
+        schema: &DataSchemaRef,
+        probe_schema: &DataSchemaRef,
+        build_matched: &[bool],
+    ) -> Result<DataBlock> {
+        let build_indices = build_matched
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !**matched)
+            .map(|(row, _)| row as u32)
+            .collect::<Vec<_>>();
+
+        if build_indices.is_empty() {
+            return Ok(DataBlock::empty_with_schema(schema.clone()));
+        }
+
+        let probe_indices = vec![None; build_indices.len()];
+        let probe_block = DataBlock::empty_with_schema(probe_schema.clone());
+        let probe_block = DataBlock::block_take_by_opt_indices(&probe_block, &probe_indices)?;
+        let build_block = DataBlock::block_take_by_indices(&self.block, &[], &build_indices)?;
+        Self::merge_columns(schema, &probe_block, &build_block)
+    }
+
+    fn merge_columns(
+        schema: &DataSchemaRef,
+        probe_block: &DataBlock,
+        build_block: &DataBlock,
+    ) -> Result<DataBlock> {
+        let mut columns = probe_block.columns().to_vec();
+        columns.extend_from_slice(build_block.columns());
+
+        if columns.len() != schema.fields().len() {
+            return Err(ErrorCode::LogicalError(format!(
+                "Join output columns size {} mismatch schema fields size {}",
+                columns.len(),
+                schema.fields().len()
+            )));
+        }
+
+        Ok(DataBlock::create(schema.clone(), columns))
+    }
+}
+
+/// Hash join of the input(probe side) with the build side.
+///
+/// The build side is executed once and shared by all the join transforms of the pipeline,
+/// each input block is probed against the hash table of the build side.
+/// For RIGHT and FULL join, the unmatched build rows are emitted after the input is exhausted,
+/// so the input must be merged into one stream before the transform.
+pub struct HashJoinTransform {
+    ctx: DatabendQueryContextRef,
+    join_type: JoinType,
+    schema: DataSchemaRef,
+    probe_schema: DataSchemaRef,
+    probe_keys_executor: Arc<ExpressionExecutor>,
+    build_puller: Arc<Mutex<JoinBuildPuller<'static>>>,
+    input: Arc<dyn Processor>,
+}
+
+impl HashJoinTransform {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        join_type: JoinType,
+        schema: DataSchemaRef,
+        probe_schema: DataSchemaRef,
+        probe_keys: &[Expression],
+        build_puller: Arc<Mutex<JoinBuildPuller<'static>>>,
+    ) -> Result<HashJoinTransform> {
+        let probe_keys_executor = JoinHashTable::keys_executor(&probe_schema, probe_keys)?;
+        probe_keys_executor.validate()?;
+
+        Ok(HashJoinTransform {
+            ctx,
+            join_type,
+            schema,
+            probe_schema,
+            probe_keys_executor: Arc::new(probe_keys_executor),
+            build_puller,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    async fn build_hash_table(&self) -> Result<JoinHashTableRef> {
+        let build_future = self.build_puller.lock().take_build_data()?;
+
+        match self.ctx.try_spawn(build_future)?.await {
+            Ok(hash_table) => hash_table,
+            Err(error) => Err(ErrorCode::TokioError(format!(
+                "Cannot build the hash table of join. cause: {}",
+                error
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let hash_table = self.build_hash_table().await?;
+        let mut input_stream = self.input.execute().await?;
+
+        let join_type = self.join_type;
+        let schema = self.schema.clone();
+        let probe_schema = self.probe_schema.clone();
+        let executor = self.probe_keys_executor.clone();
+
+        let stream = stream! {
+            let mut build_matched = match join_type.keep_unmatched_right() {
+                true => vec![false; hash_table.num_rows()],
+                false => vec![],
+            };
+
+            let mut failed = false;
+            while let Some(block) = input_stream.next().await {
+                let joined = block.and_then(|block| {
+                    hash_table.probe(join_type, &schema, &executor, &block, &mut build_matched)
+                });
+
+                match joined {
+                    Ok(block) if block.is_empty() => continue,
+                    Ok(block) => yield Ok(block),
+                    Err(error) => {
+                        failed = true;
+                        yield Err(error);
+                        break;
+                    }
+                }
+            }
+
+            if !failed && join_type.keep_unmatched_right() {
+                match hash_table.unmatched_build_rows(&schema, &probe_schema, &build_matched) {
+                    Ok(block) if block.is_empty() => {}
+                    Ok(block) => yield Ok(block),
+                    Err(error) => yield Err(error),
+                }
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+}
+
+type BuildData = Result<JoinHashTableRef>;
+type SharedFuture<'a> = Shared<BoxFuture<'a, BuildData>>;
+
+/// Executes the build side of a join once and shares the hash table between the join transforms.
+pub struct JoinBuildPuller<'a> {
+    ctx: DatabendQueryContextRef,
+    build_plan: PlanNode,
+    build_keys: Vec<Expression>,
+    build_data: Option<SharedFuture<'a>>,
+}
+
+impl<'a> JoinBuildPuller<'a> {
+    pub fn create(
+        ctx: DatabendQueryContextRef,
+        build_plan: PlanNode,
+        build_keys: Vec<Expression>,
+    ) -> Arc<Mutex<JoinBuildPuller<'a>>> {
+        Arc::new(Mutex::new(JoinBuildPuller {
+            ctx,
+            build_plan,
+            build_keys,
+            build_data: None,
+        }))
+    }
+
+    pub fn take_build_data(&mut self) -> Result<impl Future<Output = BuildData> + 'a> {
+        if self.build_data.is_none() {
+            self.init()?;
+        }
+
+        match &self.build_data {
+            Some(build_data) => Ok(build_data.clone()),
+            None => Err(ErrorCode::LogicalError(
+                "Join build data must be initialized",
+            )),
+        }
+    }
+
+    fn init(&mut self) -> Result<()> {
+        // The build side has its own partitions, so it needs a new context like subqueries.
+        let build_ctx = DatabendQueryContext::new(self.ctx.clone());
+        let builder = PipelineBuilder::create(build_ctx);
+        let mut pipeline = builder.build(&self.build_plan)?;
+
+        let schema = self.build_plan.schema();
+        let keys_executor = JoinHashTable::keys_executor(&schema, &self.build_keys)?;
+        keys_executor.validate()?;
+
+        let build_future = async move {
+            let mut stream = pipeline.execute().await?;
+
+            let mut blocks = vec![];
+            while let Some(block) = stream.next().await {
+                blocks.push(block?);
+                MemoryTracker::check_current_memory_limit()?;
+            }
+
+            let hash_table = JoinHashTable::try_create(schema, blocks, &keys_executor)?;
+            MemoryTracker::check_current_memory_limit()?;
+            Ok(Arc::new(hash_table))
+        };
+
+        self.build_data = Some(build_future.boxed().shared());
+        Ok(())
+    }
+}
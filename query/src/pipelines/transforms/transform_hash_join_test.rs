@@ -0,0 +1,195 @@
+// Copyright 2020 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+
+use crate::pipelines::processors::*;
+
+fn join_plan_for_test(
+    join_type: JoinType,
+    probe_numbers: i64,
+    build_numbers: i64,
+) -> Result<PlanNode> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx);
+
+    let probe_plan =
+        PlanNode::ReadSource(test_source.number_read_source_plan_for_test(probe_numbers)?);
+    let build_plan = PlanBuilder::from(&PlanNode::ReadSource(
+        test_source.number_read_source_plan_for_test(build_numbers)?,
+    ))
+    .project(&[col("number").alias("b")])?
+    .build()?;
+
+    PlanBuilder::from(&probe_plan)
+        .join(join_type, &[col("number")], &[col("b")], &build_plan)?
+        .build()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_inner() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let plan = join_plan_for_test(JoinType::Inner, 5, 3)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+---+",
+        "| number | b |",
+        "+--------+---+",
+        "| 0      | 0 |",
+        "| 1      | 1 |",
+        "| 2      | 2 |",
+        "+--------+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_left() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let plan = join_plan_for_test(JoinType::Left, 5, 3)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+------+",
+        "| number | b    |",
+        "+--------+------+",
+        "| 0      | 0    |",
+        "| 1      | 1    |",
+        "| 2      | 2    |",
+        "| 3      | NULL |",
+        "| 4      | NULL |",
+        "+--------+------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_right() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let plan = join_plan_for_test(JoinType::Right, 3, 5)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+---+",
+        "| number | b |",
+        "+--------+---+",
+        "| 0      | 0 |",
+        "| 1      | 1 |",
+        "| 2      | 2 |",
+        "| NULL   | 3 |",
+        "| NULL   | 4 |",
+        "+--------+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_full() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // probe: 0..4, build: 2..6
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+    let probe_plan = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(4)?);
+    let build_plan = PlanBuilder::from(&PlanNode::ReadSource(
+        test_source.number_read_source_plan_for_test(4)?,
+    ))
+    .project(&[add(col("number"), lit(2u64)).alias("b")])?
+    .build()?;
+    let plan = PlanBuilder::from(&probe_plan)
+        .join(JoinType::Full, &[col("number")], &[col("b")], &build_plan)?
+        .build()?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+------+",
+        "| number | b    |",
+        "+--------+------+",
+        "| 0      | NULL |",
+        "| 1      | NULL |",
+        "| 2      | 2    |",
+        "| 3      | 3    |",
+        "| NULL   | 4    |",
+        "| NULL   | 5    |",
+        "+--------+------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_semi() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let plan = join_plan_for_test(JoinType::Semi, 5, 3)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+",
+        "| number |",
+        "+--------+",
+        "| 0      |",
+        "| 1      |",
+        "| 2      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_anti() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let plan = join_plan_for_test(JoinType::Anti, 5, 3)?;
+
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+",
+        "| number |",
+        "+--------+",
+        "| 3      |",
+        "| 4      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -16,7 +16,9 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_base::MemoryTracker;
 use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::Expression;
@@ -28,9 +30,13 @@ use futures::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::spill::SpillFile;
+use crate::pipelines::transforms::spill::SpillReader;
 use crate::pipelines::transforms::transform_sort_partial::get_sort_descriptions;
+use crate::sessions::DatabendQueryContextRef;
 
 pub struct SortMergeTransform {
+    ctx: DatabendQueryContextRef,
     schema: DataSchemaRef,
     exprs: Vec<Expression>,
     limit: Option<usize>,
@@ -39,17 +45,35 @@ pub struct SortMergeTransform {
 
 impl SortMergeTransform {
     pub fn try_create(
+        ctx: DatabendQueryContextRef,
         schema: DataSchemaRef,
         exprs: Vec<Expression>,
         limit: Option<usize>,
     ) -> Result<Self> {
         Ok(SortMergeTransform {
+            ctx,
             schema,
             exprs,
             limit,
             input: Arc::new(EmptyProcessor::create()),
         })
     }
+
+    // Merges the buffered blocks into a sorted run and spills it to the disk.
+    fn spill_run(
+        &self,
+        blocks: &[DataBlock],
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<SpillFile> {
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let run = DataBlock::merge_sort_blocks(blocks, sort_columns_descriptions, self.limit)?;
+        let file = SpillFile::try_create(&self.ctx, "sort")?;
+        file.write(
+            &self.schema,
+            DataBlock::split_block_by_size(&run, max_block_size)?,
+        )?;
+        Ok(file)
+    }
 }
 
 #[async_trait]
@@ -75,11 +99,47 @@ impl Processor for SortMergeTransform {
         tracing::debug!("execute...");
 
         let sort_columns_descriptions = get_sort_descriptions(&self.schema, &self.exprs)?;
+        let settings = self.ctx.get_settings();
+        let max_bytes_before_external_sort =
+            settings.get_max_bytes_before_external_sort()? as usize;
+
         let mut blocks = vec![];
+        let mut buffered_bytes = 0;
+        let mut runs = vec![];
         let mut stream = self.input.execute().await?;
 
         while let Some(block) = stream.next().await {
-            blocks.push(block?);
+            let block = block?;
+            buffered_bytes += block.memory_size();
+            blocks.push(block);
+
+            // The buffered bytes of this transform only, the tracked memory usage is shared by
+            // the other transforms of the query, which would spill a run for every block.
+            if max_bytes_before_external_sort != 0
+                && buffered_bytes > max_bytes_before_external_sort
+            {
+                runs.push(self.spill_run(&blocks, &sort_columns_descriptions)?);
+                blocks.clear();
+                buffered_bytes = 0;
+            }
+
+            MemoryTracker::check_current_memory_limit()?;
+        }
+
+        if !runs.is_empty() {
+            if !blocks.is_empty() {
+                runs.push(self.spill_run(&blocks, &sort_columns_descriptions)?);
+            }
+
+            let runs = runs
+                .into_iter()
+                .map(|run| run.read())
+                .collect::<Result<Vec<_>>>()?;
+            let merger = SortedRunsMerger::create(runs, sort_columns_descriptions, self.limit);
+            return Ok(Box::pin(CorrectWithSchemaStream::new(
+                Box::pin(futures::stream::iter(merger)),
+                self.schema.clone(),
+            )));
         }
 
         let results = match blocks.len() {
@@ -97,3 +157,86 @@ impl Processor for SortMergeTransform {
         )))
     }
 }
+
+/// The k-way streaming merge of the spilled sorted runs, only the head block of each run is
+/// kept in memory.
+struct SortedRunsMerger {
+    runs: Vec<SpillReader>,
+    heads: Vec<Option<DataBlock>>,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    limit: Option<usize>,
+    merged_rows: usize,
+}
+
+impl SortedRunsMerger {
+    fn create(
+        runs: Vec<SpillReader>,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        limit: Option<usize>,
+    ) -> SortedRunsMerger {
+        SortedRunsMerger {
+            heads: runs.iter().map(|_| None).collect(),
+            runs,
+            sort_columns_descriptions,
+            limit,
+            merged_rows: 0,
+        }
+    }
+
+    fn merge_next(&mut self) -> Result<Option<DataBlock>> {
+        if matches!(self.limit, Some(limit) if self.merged_rows >= limit) {
+            return Ok(None);
+        }
+
+        // Refill the heads which are consumed, the exhausted runs are dropped.
+        let mut index = 0;
+        while index < self.runs.len() {
+            while self.heads[index]
+                .as_ref()
+                .map_or(true, |head| head.num_rows() == 0)
+            {
+                match self.runs[index].next() {
+                    None => break,
+                    Some(block) => self.heads[index] = Some(block?),
+                }
+            }
+
+            match &self.heads[index] {
+                Some(head) if head.num_rows() > 0 => index += 1,
+                _ => {
+                    self.runs.remove(index);
+                    self.heads.remove(index);
+                }
+            }
+        }
+
+        if self.heads.is_empty() {
+            return Ok(None);
+        }
+
+        let heads = self
+            .heads
+            .iter_mut()
+            .flat_map(Option::take)
+            .collect::<Vec<_>>();
+        let (merged, rests) = DataBlock::merge_sort_heads(&heads, &self.sort_columns_descriptions)?;
+        self.heads = rests.into_iter().map(Some).collect();
+
+        let merged = match self.limit {
+            Some(limit) if self.merged_rows + merged.num_rows() > limit => {
+                DataBlock::slice_block(&merged, 0, limit - self.merged_rows)
+            }
+            _ => merged,
+        };
+        self.merged_rows += merged.num_rows();
+        Ok(Some(merged))
+    }
+}
+
+impl Iterator for SortedRunsMerger {
+    type Item = Result<DataBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merge_next().transpose()
+    }
+}
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_base::tokio;
+use common_base::TrySpawn;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::*;
 use common_planners::{self};
@@ -49,6 +51,7 @@ async fn test_transform_sort() -> Result<()> {
 
     pipeline.add_simple_transform(|| {
         Ok(Box::new(SortMergeTransform::try_create(
+            ctx.clone(),
             plan.schema(),
             sort_expression.to_vec(),
             None,
@@ -59,6 +62,7 @@ async fn test_transform_sort() -> Result<()> {
         pipeline.merge_processor()?;
         pipeline.add_simple_transform(|| {
             Ok(Box::new(SortMergeTransform::try_create(
+                ctx.clone(),
                 plan.schema(),
                 sort_expression.to_vec(),
                 None,
@@ -90,3 +94,115 @@ async fn test_transform_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_external_sort() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    // spill every block, and read the runs back in blocks of 2 rows
+    ctx.get_settings().set_max_bytes_before_external_sort(1)?;
+    ctx.get_settings().set_max_block_size(2)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // Pipeline.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    let sort_expression = &[sort("number", false, false)];
+    let plan = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .sort(sort_expression)?
+        .build()?;
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortPartialTransform::try_create(
+            plan.schema(),
+            sort_expression.to_vec(),
+            None,
+        )?))
+    })?;
+
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortMergeTransform::try_create(
+            ctx.clone(),
+            plan.schema(),
+            sort_expression.to_vec(),
+            Some(6),
+        )?))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------+",
+        "| number |",
+        "+--------+",
+        "| 7      |",
+        "| 6      |",
+        "| 5      |",
+        "| 4      |",
+        "| 3      |",
+        "| 2      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    // The spilled runs are removed once merged.
+    let query_id = ctx.get_id();
+    let spilled = std::fs::read_dir(std::env::temp_dir())?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.starts_with("sort-") && name.contains(&query_id)
+        })
+        .count();
+    assert_eq!(spilled, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_sort_memory_limit() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_settings().set_max_memory_usage(1)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // Pipeline.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    let sort_expression = &[sort("number", false, false)];
+    let plan = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .sort(sort_expression)?
+        .build()?;
+
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortMergeTransform::try_create(
+            ctx.clone(),
+            plan.schema(),
+            sort_expression.to_vec(),
+            None,
+        )?))
+    })?;
+
+    // The memory is tracked and limited in the query runtime only.
+    let result = ctx
+        .try_spawn(async move {
+            let stream = pipeline.execute().await?;
+            stream.try_collect::<Vec<_>>().await
+        })?
+        .await
+        .unwrap();
+
+    assert!(result.is_err());
+    assert_eq!(
+        ErrorCode::MemoryLimitExceeded("").code(),
+        result.unwrap_err().code()
+    );
+
+    Ok(())
+}
@@ -100,12 +100,19 @@ impl ClickHouseSession for InteractiveWorker {
     fn authenticate(&self, user: &str, password: &[u8], client_addr: &str) -> bool {
         let info = CertifiedInfo::create(user, password, client_addr);
 
+        let session = self.session.clone();
         let user_manager = self.session.get_user_manager();
         // TODO: push async up to clickhouse server lib
         futures::executor::block_on(async move {
             // TODO: use get_users and check client address
             let res = match user_manager.get_user(user, "%").await {
-                Ok(user_info) => user_manager.auth_user(user_info, info).await,
+                Ok(user_info) => {
+                    let authed = user_manager.auth_user(user_info.clone(), info).await;
+                    if let Ok(true) = authed {
+                        session.set_current_user(user_info);
+                    }
+                    authed
+                }
                 Err(err) => Err(err),
             };
             match res {
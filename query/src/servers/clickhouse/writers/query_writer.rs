@@ -24,6 +24,7 @@ use common_clickhouse_srv::errors::Result as CHResult;
 use common_clickhouse_srv::errors::ServerError;
 use common_clickhouse_srv::types::Block;
 use common_clickhouse_srv::types::DateTimeType;
+use common_clickhouse_srv::types::Decimal;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -203,10 +204,32 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
 
                     result.column(name, c)
                 }
+                DataType::DateTime64(precision, tz) => {
+                    let precision = *precision;
+                    let tz = parse_timezone(tz)?;
+
+                    let c: Vec<Option<DateTime<Tz>>> = column
+                        .i64()?
+                        .into_iter()
+                        .map(|x| x.map(|v| v.to_date_time64(precision, &tz)))
+                        .collect();
+
+                    result.column(name, c)
+                }
                 DataType::UInt64 => result.column(name, column.u64()?.collect_values()),
                 DataType::Float32 => result.column(name, column.f32()?.collect_values()),
                 DataType::Float64 => result.column(name, column.f64()?.collect_values()),
-                DataType::String => result.column(name, column.string()?.collect_values()),
+                DataType::String | DataType::Variant => {
+                    result.column(name, column.string()?.collect_values())
+                }
+                DataType::Decimal(_, scale) => {
+                    let c: Vec<Option<Decimal>> = column
+                        .i64()?
+                        .into_iter()
+                        .map(|x| x.map(|v| Decimal::new(*v, *scale as u8)))
+                        .collect();
+                    result.column(name, c)
+                }
                 DataType::Boolean => {
                     let v: Vec<Option<u8>> = column
                         .bool()?
@@ -279,6 +302,19 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                     result.column(name, c)
                 }
 
+                DataType::DateTime64(precision, tz) => {
+                    let precision = *precision;
+                    let tz = parse_timezone(tz)?;
+
+                    let c: Vec<DateTime<Tz>> = column
+                        .i64()?
+                        .into_no_null_iter()
+                        .map(|v| v.to_date_time64(precision, &tz))
+                        .collect();
+
+                    result.column(name, c)
+                }
+
                 DataType::UInt64 => {
                     result.column(name, column.u64()?.inner().values().as_slice().to_vec())
                 }
@@ -288,10 +324,18 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                 DataType::Float64 => {
                     result.column(name, column.f64()?.inner().values().as_slice().to_vec())
                 }
-                DataType::String => {
+                DataType::String | DataType::Variant => {
                     let vs: Vec<&[u8]> = column.string()?.into_no_null_iter().collect();
                     result.column(name, vs)
                 }
+                DataType::Decimal(_, scale) => {
+                    let vs: Vec<Decimal> = column
+                        .i64()?
+                        .into_no_null_iter()
+                        .map(|v| Decimal::new(*v, *scale as u8))
+                        .collect();
+                    result.column(name, vs)
+                }
                 DataType::Boolean => {
                     let vs: Vec<u8> = column
                         .bool()?
@@ -354,6 +398,17 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
                 Ok(DFStringArray::new_from_iter(col.iter::<&[u8]>()?).into_series())
             }
 
+            SqlType::Decimal(_, _) => Ok(DFInt64Array::new_from_iter(
+                col.iter::<Decimal>()?.map(|d| d.internal::<i64>()),
+            )
+            .into_series()),
+            SqlType::DateTime(DateTimeType::DateTime64(precision, _)) => Ok(
+                DFInt64Array::new_from_iter(col.iter::<DateTime<Tz>>()?.map(|v| {
+                    datetime64_from_parts(v.timestamp(), v.timestamp_subsec_nanos(), precision)
+                }))
+                .into_series(),
+            ),
+
             SqlType::Nullable(SqlType::UInt8) => Ok(DFUInt8Array::new_from_opt_iter(
                 col.iter::<Option<u8>>()?.map(|c| c.copied()),
             )
@@ -367,6 +422,14 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
                 DFUInt32Array::new_from_opt_iter(col.iter::<Option<u32>>()?.map(|c| c.copied()))
                     .into_series(),
             ),
+            SqlType::Nullable(SqlType::DateTime(DateTimeType::DateTime64(precision, _))) => Ok(
+                DFInt64Array::new_from_opt_iter(col.iter::<Option<DateTime<Tz>>>()?.map(|c| {
+                    c.map(|v| {
+                        datetime64_from_parts(v.timestamp(), v.timestamp_subsec_nanos(), *precision)
+                    })
+                }))
+                .into_series(),
+            ),
             SqlType::Nullable(SqlType::UInt64) => Ok(DFUInt64Array::new_from_opt_iter(
                 col.iter::<Option<u64>>()?.map(|c| c.copied()),
             )
@@ -401,6 +464,11 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
             SqlType::Nullable(SqlType::FixedString(_)) => {
                 Ok(DFStringArray::new_from_opt_iter(col.iter::<Option<&[u8]>>()?).into_series())
             }
+            SqlType::Nullable(SqlType::Decimal(_, _)) => Ok(DFInt64Array::new_from_opt_iter(
+                col.iter::<Option<Decimal>>()?
+                    .map(|d| d.map(|d| d.internal::<i64>())),
+            )
+            .into_series()),
 
             other => Err(CHError::Other(Cow::from(format!(
                 "Unsupported type: {:?}",
@@ -18,8 +18,12 @@ use common_datablocks::DataBlock;
 use common_datavalues::arrays::DFPrimitiveArray;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
+use common_datavalues::datetime64_to_string;
+use common_datavalues::decimal_to_string;
+use common_datavalues::parse_timezone;
 use common_datavalues::DFPrimitiveType;
 use common_datavalues::DataType;
+use common_datavalues::DateConverter;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Serialize;
@@ -92,6 +96,11 @@ where
         .collect()
 }
 
+/// The variant values are embedded in the result as they are instead of as strings.
+fn variant_to_json(value: &[u8]) -> JsonValue {
+    serde_json::from_slice(value).unwrap_or(JsonValue::Null)
+}
+
 fn bad_type(data_type: &DataType) -> ErrorCode {
     ErrorCode::BadDataValueType(format!("Unsupported column type:{:?}", data_type))
 }
@@ -124,11 +133,38 @@ pub(crate) fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                     .map(|o| o.as_ref().map(|v| String::from_utf8(v.clone()).unwrap()))
                     .map(to_json_value)
                     .collect(),
+                DataType::Variant => series
+                    .string()?
+                    .into_iter()
+                    .map(|o| o.map_or(JsonValue::Null, variant_to_json))
+                    .collect(),
+                DataType::Decimal(_, scale) => series
+                    .i64()?
+                    .into_iter()
+                    .map(|o| o.map(|v| decimal_to_string(*v, *scale)))
+                    .map(to_json_value)
+                    .collect(),
                 DataType::Boolean => series.bool()?.into_iter().map(to_json_value).collect(),
                 DataType::Date16 => date_array_to_string_array(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array(series.i32()?, DATE_FMT),
-                // TODO(youngsofun): add time zone?
-                DataType::DateTime32(_) => date_array_to_string_array(series.i32()?, TIME_FMT),
+                DataType::DateTime32(tz) => {
+                    let tz = parse_timezone(tz)?;
+                    series
+                        .u32()?
+                        .into_iter()
+                        .map(|o| o.map(|v| v.to_date_time(&tz).format(TIME_FMT).to_string()))
+                        .map(to_json_value)
+                        .collect()
+                }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = parse_timezone(tz)?;
+                    series
+                        .i64()?
+                        .into_iter()
+                        .map(|o| o.map(|v| datetime64_to_string(*v, *precision, &tz)))
+                        .map(to_json_value)
+                        .collect()
+                }
                 // TODO(youngsofun): support other DataType
                 _ => return Err(bad_type(data_type)),
             },
@@ -154,10 +190,36 @@ pub(crate) fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                     .map(|v| String::from_utf8(v.to_vec()).unwrap())
                     .map(to_json_value)
                     .collect(),
+                DataType::Variant => series
+                    .string()?
+                    .into_no_null_iter()
+                    .map(variant_to_json)
+                    .collect(),
+                DataType::Decimal(_, scale) => series
+                    .i64()?
+                    .into_no_null_iter()
+                    .map(|v| decimal_to_string(*v, *scale))
+                    .map(to_json_value)
+                    .collect(),
                 DataType::Date16 => date_array_to_string_array_not_null(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array_not_null(series.i32()?, DATE_FMT),
-                DataType::DateTime32(_) => {
-                    date_array_to_string_array_not_null(series.i32()?, TIME_FMT)
+                DataType::DateTime32(tz) => {
+                    let tz = parse_timezone(tz)?;
+                    series
+                        .u32()?
+                        .into_no_null_iter()
+                        .map(|v| v.to_date_time(&tz).format(TIME_FMT).to_string())
+                        .map(to_json_value)
+                        .collect()
+                }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = parse_timezone(tz)?;
+                    series
+                        .i64()?
+                        .into_no_null_iter()
+                        .map(|v| datetime64_to_string(*v, *precision, &tz))
+                        .map(to_json_value)
+                        .collect()
                 }
                 _ => return Err(bad_type(data_type)),
             },
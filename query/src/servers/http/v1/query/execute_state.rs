@@ -112,7 +112,9 @@ impl ExecuteState {
         block_tx: mpsc::Sender<DataBlock>,
     ) -> Result<(ExecuteStateRef, DataSchemaRef)> {
         let sql = &request.sql;
-        let session = session_manager.create_session("http-statement")?;
+        let session = session_manager
+            .create_default_user_session("http-statement")
+            .await?;
         let context = session.create_context().await?;
         context.attach_query_str(sql);
 
@@ -106,7 +106,9 @@ impl HttpQuery {
     }
 
     async fn start(&mut self, session_manager: SessionManagerRef) -> Result<HttpQueryState> {
-        let session = session_manager.create_session("http-statement")?;
+        let session = session_manager
+            .create_default_user_session("http-statement")
+            .await?;
         let ctx = session.create_context().await?;
         if self.db.is_some() && !self.db.clone().unwrap().is_empty() {
             ctx.set_current_database(self.db.clone().unwrap())?;
@@ -215,12 +215,17 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         let saved = &user_info.password;
         let encode_password = Self::encoding_password(auth_plugin, salt, input, saved)?;
 
-        user_manager
+        let authed = user_manager
             .auth_user(
-                user_info,
+                user_info.clone(),
                 CertifiedInfo::create(user_name, encode_password, address),
             )
-            .await
+            .await?;
+
+        if authed {
+            self.session.set_current_user(user_info);
+        }
+        Ok(authed)
     }
 
     fn encoding_password(
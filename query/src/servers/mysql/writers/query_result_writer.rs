@@ -14,6 +14,9 @@
 
 use chrono_tz::Tz;
 use common_datablocks::DataBlock;
+use common_datavalues::datetime64_to_string;
+use common_datavalues::decimal_to_string;
+use common_datavalues::parse_timezone;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
@@ -72,13 +75,17 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+                DataType::Decimal(_, _) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Struct(_) | DataType::List(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
@@ -151,6 +158,9 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                 (DataType::Float64, DataValue::Float64(Some(v))) => {
                                     row_writer.write_col(v)?
                                 }
+                                (DataType::Decimal(_, scale), DataValue::Int64(Some(v))) => {
+                                    row_writer.write_col(decimal_to_string(v, *scale))?
+                                }
                                 (DataType::Date16, DataValue::UInt16(Some(v))) => {
                                     row_writer.write_col(v.to_date(&utc).naive_local())?
                                 }
@@ -163,10 +173,20 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
-                                (DataType::String, DataValue::String(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Struct(_), DataValue::Struct(_)) => {
+                                (
+                                    DataType::DateTime64(precision, tz),
+                                    DataValue::Int64(Some(v)),
+                                ) => {
+                                    let tz = parse_timezone(tz)?;
+                                    row_writer
+                                        .write_col(datetime64_to_string(v, *precision, &tz))?
+                                }
+                                (
+                                    DataType::String | DataType::Variant,
+                                    DataValue::String(Some(v)),
+                                ) => row_writer.write_col(v)?,
+                                (DataType::Struct(_), DataValue::Struct(_))
+                                | (DataType::List(_), DataValue::List(_, _)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
@@ -44,6 +44,7 @@ use crate::clusters::ClusterRef;
 use crate::configs::Config;
 use crate::datasources::DataSourceContext;
 use crate::servers::http::v1::query::HttpQueryHandle;
+use crate::sessions::context_shared::CteData;
 use crate::sessions::context_shared::DatabendQueryContextShared;
 use crate::sessions::SessionManagerRef;
 use crate::sessions::Settings;
@@ -156,6 +157,16 @@ impl DatabendQueryContext {
         self.shared.get_table(database, table)
     }
 
+    /// Get the result of the common table expression, `create` is only called by the first scan
+    /// of the CTE in the query, so the CTE is evaluated once.
+    pub fn get_cte_data(
+        &self,
+        name: &str,
+        create: impl FnOnce() -> Result<CteData>,
+    ) -> Result<CteData> {
+        self.shared.get_cte_data(name, create)
+    }
+
     /// Build a table instance the plan wants to operate on.
     ///
     /// A plan just contains raw information about a table or table function.
@@ -19,13 +19,17 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common_base::BlockingWait;
+use common_base::MemoryTracker;
 use common_base::Progress;
 use common_base::Runtime;
+use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_infallible::RwLock;
 use common_planners::PlanNode;
 use futures::future::AbortHandle;
+use futures::future::BoxFuture;
+use futures::future::Shared;
 use uuid::Uuid;
 
 use crate::catalogs::impls::DatabaseCatalog;
@@ -39,6 +43,9 @@ use crate::sessions::Settings;
 
 type DatabaseAndTable = (String, String);
 
+/// The result of a common table expression, shared by all of its scans in a query.
+pub type CteData = Shared<BoxFuture<'static, Result<Arc<Vec<DataBlock>>>>>;
+
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
 ///     USE database_1;
@@ -62,6 +69,7 @@ pub struct DatabendQueryContextShared {
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    pub(in crate::sessions) cte_data: Arc<Mutex<HashMap<String, CteData>>>,
 }
 
 impl DatabendQueryContextShared {
@@ -84,6 +92,7 @@ impl DatabendQueryContextShared {
             http_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            cte_data: Arc::new(Mutex::new(HashMap::new())),
         })
     }
 
@@ -145,22 +154,62 @@ impl DatabendQueryContextShared {
         Ok(ent)
     }
 
+    pub fn get_cte_data(
+        &self,
+        name: &str,
+        create: impl FnOnce() -> Result<CteData>,
+    ) -> Result<CteData> {
+        let mut cte_data = self.cte_data.lock();
+
+        match cte_data.entry(name.to_string()) {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => Ok(entry.insert(create()?).clone()),
+        }
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
+        if let Some(query_runtime) = &*self.runtime.read() {
+            return Ok(query_runtime.clone());
+        }
+
+        // The memory tracker of the query runtime is a child of the one of the user,
+        // so the query is limited by both the max_memory_usage and the user quota.
+        let settings = self.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let max_memory_usage = settings.get_max_memory_usage()? as usize;
+        let parent_memory_tracker = match self.session.get_current_user() {
+            None => MemoryTracker::current(),
+            Some(user) => {
+                let sessions = self.session.get_sessions_manager();
+                Some(sessions.get_user_memory_tracker(&user))
+            }
+        };
+
         let mut query_runtime = self.runtime.write();
 
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
-                let settings = self.get_settings();
-                let max_threads = settings.get_max_threads()? as usize;
-                let runtime = Arc::new(Runtime::with_worker_threads(max_threads)?);
+                let runtime =
+                    Runtime::with_worker_threads_and_parent(max_threads, parent_memory_tracker)?;
+                let runtime = Arc::new(runtime);
+                let memory_tracker = runtime.get_tracker().get_memory_tracker();
+                memory_tracker.set_limit(max_memory_usage);
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
         }
     }
 
+    /// The memory tracker of the query runtime, None if the runtime is not created yet.
+    pub fn get_memory_tracker(&self) -> Option<Arc<MemoryTracker>> {
+        let query_runtime = self.runtime.read();
+        query_runtime
+            .as_ref()
+            .map(|runtime| runtime.get_tracker().get_memory_tracker())
+    }
+
     pub fn attach_http_query(&self, handle: HttpQueryHandle) {
         let mut http_query = self.http_query.write();
         *http_query = Some(handle);
@@ -29,6 +29,7 @@ mod settings;
 
 pub use context::DatabendQueryContext;
 pub use context::DatabendQueryContextRef;
+pub use context_shared::CteData;
 pub use context_shared::DatabendQueryContextShared;
 pub use session::Session;
 pub use session_info::ProcessInfo;
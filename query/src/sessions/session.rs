@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_macros::MallocSizeOf;
+use common_management::UserInfo;
 use common_mem_allocator::malloc_size;
 use futures::channel::oneshot::Sender;
 use futures::channel::*;
@@ -36,6 +37,8 @@ use crate::users::UserManagerRef;
 pub(in crate::sessions) struct MutableStatus {
     pub(in crate::sessions) abort: bool,
     pub(in crate::sessions) current_database: String,
+    #[ignore_malloc_size_of = "insignificant"]
+    pub(in crate::sessions) current_user: Option<UserInfo>,
     pub(in crate::sessions) session_settings: Arc<Settings>,
     #[ignore_malloc_size_of = "insignificant"]
     pub(in crate::sessions) client_host: Option<SocketAddr>,
@@ -73,6 +76,7 @@ impl Session {
             mutable_state: Arc::new(Mutex::new(MutableStatus {
                 abort: false,
                 current_database: String::from("default"),
+                current_user: None,
                 session_settings: Settings::try_create()?,
                 client_host: None,
                 io_shutdown_tx: None,
@@ -178,6 +182,16 @@ impl Session {
         inner.current_database.clone()
     }
 
+    pub fn set_current_user(self: &Arc<Self>, user: UserInfo) {
+        let mut inner = self.mutable_state.lock();
+        inner.current_user = Some(user);
+    }
+
+    pub fn get_current_user(self: &Arc<Self>) -> Option<UserInfo> {
+        let inner = self.mutable_state.lock();
+        inner.current_user.clone()
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.mutable_state.lock().session_settings.clone()
     }
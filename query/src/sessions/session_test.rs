@@ -39,3 +39,36 @@ async fn test_session_mem_usage() {
     assert!(session_size > 3000);
     assert_eq!(session_size, session.get_memory_usage());
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_memory_tracker() {
+    use std::sync::Arc;
+
+    use common_management::UserInfo;
+    use common_meta_types::AuthType;
+
+    use crate::configs::Config;
+    use crate::sessions::sessions::SessionManager;
+
+    let conf = Config::load_from_args();
+    let session_manager = SessionManager::from_conf(conf).await.unwrap();
+
+    let mut user = UserInfo::new("test".into(), "%".into(), vec![], AuthType::None);
+    user.quota.max_memory_in_bytes = 1024;
+
+    // The queries of the same user share the tracker, limited by the memory quota.
+    let tracker = session_manager.get_user_memory_tracker(&user);
+    let other = session_manager.get_user_memory_tracker(&user);
+    assert!(Arc::ptr_eq(&tracker, &other));
+    assert_eq!(tracker.get_limit(), 1024);
+
+    tracker.alloc_memory(2048);
+    assert!(other.check_memory_limit().is_err());
+
+    // A new tracker is created once the queries of the user are all finished.
+    drop(tracker);
+    drop(other);
+    let tracker = session_manager.get_user_memory_tracker(&user);
+    assert_eq!(tracker.get_memory_usage(), 0);
+    assert!(tracker.check_memory_limit().is_ok());
+}
@@ -17,13 +17,16 @@ use std::collections::hash_map::Entry::Vacant;
 use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
 use common_base::tokio;
+use common_base::MemoryTracker;
 use common_base::SignalStream;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_management::UserInfo;
 use common_metrics::label_counter;
 use futures::future::Either;
 use futures::StreamExt;
@@ -48,6 +51,9 @@ pub struct SessionManager {
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
+
+    pub(in crate::sessions) memory_tracker: Option<Arc<MemoryTracker>>,
+    pub(in crate::sessions) user_memory_trackers: RwLock<HashMap<String, Weak<MemoryTracker>>>,
 }
 
 pub type SessionManagerRef = Arc<SessionManager>;
@@ -73,6 +79,8 @@ impl SessionManager {
             http_query_manager,
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
+            memory_tracker: MemoryTracker::current(),
+            user_memory_trackers: RwLock::new(HashMap::new()),
         }))
     }
 
@@ -97,6 +105,27 @@ impl SessionManager {
         self.catalog.clone()
     }
 
+    /// The memory tracker shared by the running queries of the user, it's limited by the memory
+    /// quota of the user. It's dropped with the last query of the user, so the memory freed out of
+    /// the query runtimes is not accumulated across the queries.
+    pub fn get_user_memory_tracker(self: &Arc<Self>, user: &UserInfo) -> Arc<MemoryTracker> {
+        let key = format!("'{}'@'{}'", user.name, user.hostname);
+        let mut user_memory_trackers = self.user_memory_trackers.write();
+        user_memory_trackers.retain(|_, tracker| tracker.strong_count() > 0);
+
+        let tracker = match user_memory_trackers.get(&key).and_then(Weak::upgrade) {
+            Some(tracker) => tracker,
+            None => {
+                let tracker = MemoryTracker::create(self.memory_tracker.clone());
+                user_memory_trackers.insert(key, Arc::downgrade(&tracker));
+                tracker
+            }
+        };
+
+        tracker.set_limit(user.quota.max_memory_in_bytes as usize);
+        tracker
+    }
+
     pub fn create_session(self: &Arc<Self>, typ: impl Into<String>) -> Result<SessionRef> {
         let mut sessions = self.active_sessions.write();
         match sessions.len() == self.max_sessions {
@@ -123,6 +152,18 @@ impl SessionManager {
         }
     }
 
+    /// The handlers without authentication yet, e.g. the HTTP handler, run the queries as the
+    /// default user, so they are limited by the memory quota of it like the other handlers.
+    pub async fn create_default_user_session(
+        self: &Arc<Self>,
+        typ: impl Into<String>,
+    ) -> Result<SessionRef> {
+        let session = self.create_session(typ)?;
+        let user_info = self.get_user_manager().get_user("root", "%").await?;
+        session.set_current_user(user_info);
+        Ok(session)
+    }
+
     pub fn create_rpc_session(self: &Arc<Self>, id: String, aborted: bool) -> Result<SessionRef> {
         let mut sessions = self.active_sessions.write();
 
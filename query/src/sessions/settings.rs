@@ -31,7 +31,13 @@ impl Settings {
         ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
-        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.")
+        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
+        ("max_commit_retries", u64, 10, "Maximum number of times a fuse table commit is retried, when it conflicts with the concurrent commits. By default, it is 10"),
+        ("max_bytes_before_external_sort", u64, 0, "The sorted blocks are spilled to the disk when the bytes buffered by the sort exceed this value. By default, it is 0, the external sort is disabled"),
+        ("group_by_two_level_threshold", u64, 100000, "The number of groups from which the partial and the final group by are partitioned into 256 buckets by the key hash. By default, it is 100000"),
+        ("max_bytes_before_external_group_by", u64, 0, "The buckets of the group by are spilled to the disk when the memory allocated by the group by states exceeds this value. By default, it is 0, the external group by is disabled"),
+        ("max_memory_usage", u64, 0, "The maximum memory usage of a query, the query fails when the tracked memory usage exceeds this value. By default, it is 0, the memory usage is unlimited"),
+        ("timezone", String, "UTC", "The timezone of the session, it's used by the date and time functions when the value has no timezone. By default, it is UTC")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -228,7 +234,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_update_string(&self, key: &'static str, val: &str) -> Result<()> {
+    pub fn try_update_string(&self, key: &'static str, val: String) -> Result<()> {
         let mut settings = self.settings.write();
         let setting_val = settings
             .get(key)
@@ -236,7 +242,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             let v = DataValue::Struct(vec![
-                DataValue::String(Some(val.as_bytes().to_vec())),
+                DataValue::String(Some(val.into_bytes())),
                 values[1].clone(),
                 values[2].clone(),
             ]);
@@ -246,7 +252,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_get_string(&self, key: &str) -> Result<Vec<u8>> {
+    pub fn try_get_string(&self, key: &str) -> Result<String> {
         let settings = self.settings.read();
         let setting_val = settings
             .get(key)
@@ -254,7 +260,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             if let DataValue::String(Some(result)) = values[0].clone() {
-                return Ok(result);
+                return Ok(String::from_utf8(result)?);
             }
         }
 
@@ -12,29 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::Cell;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_datavalues::compare_coercion;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_meta_types::TableMeta;
+use common_planners::clone_with_replacement;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::expand_wildcard;
 use common_planners::expr_as_column_expr;
 use common_planners::extract_aliases;
 use common_planners::find_aggregate_exprs;
+use common_planners::find_column_exprs;
 use common_planners::find_columns_not_satisfy_exprs;
+use common_planners::lit;
 use common_planners::rebase_expr;
 use common_planners::rebase_expr_from_input;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::sort_to_inner_expr;
 use common_planners::unwrap_alias_exprs;
+use common_planners::AggregatorFinalPlan;
+use common_planners::AggregatorPartialPlan;
 use common_planners::AlterUserPlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::CreateUserPlan;
+use common_planners::CteScanPlan;
+use common_planners::DeletePlan;
 use common_planners::DescribeTablePlan;
 use common_planners::DropDatabasePlan;
 use common_planners::DropTablePlan;
@@ -43,19 +58,28 @@ use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::GrantPrivilegePlan;
 use common_planners::InsertIntoPlan;
+use common_planners::JoinPlan;
+use common_planners::JoinType;
 use common_planners::KillPlan;
+use common_planners::OptimizeTablePlan;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
+use common_planners::PlanRewriter;
 use common_planners::SelectPlan;
 use common_planners::SettingPlan;
 use common_planners::ShowCreateTablePlan;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_planners::UseDatabasePlan;
+use common_planners::VacuumTablePlan;
 use common_planners::VarValue;
 use common_tracing::tracing;
 use nom::FindSubstring;
+use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
+use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::Query;
@@ -64,6 +88,7 @@ use sqlparser::ast::TableFactor;
 use sqlparser::ast::UnaryOperator;
 
 use crate::catalogs::Catalog;
+use crate::catalogs::NavigationPoint;
 use crate::catalogs::ToReadDataSourcePlan;
 use crate::functions::ContextFunction;
 use crate::sessions::DatabendQueryContextRef;
@@ -79,21 +104,98 @@ use crate::sql::DfExplain;
 use crate::sql::DfGrantStatement;
 use crate::sql::DfHint;
 use crate::sql::DfKillStatement;
+use crate::sql::DfOptimizeTable;
 use crate::sql::DfParser;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
 use crate::sql::DfShowTables;
 use crate::sql::DfStatement;
 use crate::sql::DfTruncateTable;
+use crate::sql::DfUpdate;
+use crate::sql::DfVacuumTable;
 use crate::sql::SQLCommon;
 
 pub struct PlanParser {
     ctx: DatabendQueryContextRef,
+    // The scopes of CTEs visible to the query being planned, the innermost scope is the last one.
+    cte_scopes: RefCell<Vec<HashMap<String, CommonTableExpr>>>,
+    cte_index: Cell<usize>,
+    // The own schemas of the planned relations, by their SQL text, to resolve the qualified columns.
+    relation_schemas: RefCell<HashMap<String, DataSchemaRef>>,
+}
+
+/// A CTE is scanned as a relation by its references, which share one evaluation of its query
+/// by the `CteData` of the query context. It's not planned as a `SubQueriesSetPlan`, the sets
+/// created by `transform_create_sets` are the values of the subquery expressions, not the blocks
+/// of a relation that can be joined, filtered and aggregated by several scans.
+struct CommonTableExpr {
+    // The unique name of the CTE in the query, used by its scans to share the result.
+    scan_name: String,
+    plan: PlanNode,
+    references: usize,
+}
+
+/// Replace the scans of the CTEs referenced only once with the plans of the CTEs, they are
+/// inlined like derived tables as there is nothing to share.
+struct CteInliner<'a> {
+    scan_names: &'a HashSet<String>,
+}
+
+impl PlanRewriter for CteInliner<'_> {
+    fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.set_input(&self.rewrite_plan_node(plan.input.as_ref())?);
+        Ok(PlanNode::AggregatorPartial(new_plan))
+    }
+
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.set_input(&self.rewrite_plan_node(plan.input.as_ref())?);
+        Ok(PlanNode::AggregatorFinal(new_plan))
+    }
+
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        let query_plan = self.rewrite_plan_node(plan.query_plan.as_ref())?;
+        match self.scan_names.contains(&plan.name) {
+            true => Ok(query_plan),
+            false => Ok(PlanNode::CteScan(CteScanPlan {
+                name: plan.name.clone(),
+                query_plan: Arc::new(query_plan),
+            })),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SubqueryKind {
+    Exists,
+    In,
+    Scalar,
+}
+
+/// A subquery correlated to the outer query by equi-conditions, such as
+/// `EXISTS (SELECT * FROM t2 WHERE t2.a = t1.a AND t2.b > 1)`.
+struct CorrelatedSubquery {
+    /// The plan of the subquery without the correlated conditions.
+    plan: PlanNode,
+    /// The output column of the subquery value, None for `EXISTS`.
+    value: Option<String>,
+    /// The value of the scalar subquery when no row is correlated, NULL if None.
+    empty_value: Option<Expression>,
+    /// The output columns of the inner side of the correlated conditions.
+    keys: Vec<String>,
+    /// The outer side of the correlated conditions.
+    outer_keys: Vec<sqlparser::ast::Expr>,
 }
 
 impl PlanParser {
     pub fn create(ctx: DatabendQueryContextRef) -> Self {
-        Self { ctx }
+        Self {
+            ctx,
+            cte_scopes: RefCell::new(vec![]),
+            cte_index: Cell::new(0),
+            relation_schemas: RefCell::new(HashMap::new()),
+        }
     }
 
     pub fn build_from_sql(&self, query: &str) -> Result<PlanNode> {
@@ -134,6 +236,9 @@ impl PlanParser {
             DfStatement::DescribeTable(v) => self.sql_describe_table_to_plan(v),
             DfStatement::DropTable(v) => self.sql_drop_table_to_plan(v),
             DfStatement::TruncateTable(v) => self.sql_truncate_table_to_plan(v),
+            DfStatement::OptimizeTable(v) => self.sql_optimize_table_to_plan(v),
+            DfStatement::VacuumTable(v) => self.sql_vacuum_table_to_plan(v),
+            DfStatement::Update(v) => self.sql_update_to_plan(v),
             DfStatement::UseDatabase(v) => self.sql_use_database_to_plan(v),
             DfStatement::ShowCreateTable(v) => self.sql_show_create_table_to_plan(v),
             DfStatement::ShowTables(df) => {
@@ -202,6 +307,12 @@ impl PlanParser {
                 self.insert_to_plan(table_name, columns, source, &format_sql)
             }
 
+            Statement::Delete {
+                table_name,
+                selection,
+                ..
+            } => self.delete_to_plan(table_name, selection),
+
             _ => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported statement {:?}",
                 statement
@@ -462,6 +573,96 @@ impl PlanParser {
         Ok(PlanNode::TruncateTable(TruncateTablePlan { db, table }))
     }
 
+    // DfOptimizeTable to plan.
+    #[tracing::instrument(level = "info", skip(self, optimize), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_optimize_table_to_plan(&self, optimize: &DfOptimizeTable) -> Result<PlanNode> {
+        let mut db = self.ctx.get_current_database();
+        if optimize.name.0.is_empty() {
+            return Result::Err(ErrorCode::SyntaxException(
+                "OptimizeTable table name is empty",
+            ));
+        }
+        let mut table = optimize.name.0[0].value.clone();
+        if optimize.name.0.len() > 1 {
+            db = table;
+            table = optimize.name.0[1].value.clone();
+        }
+
+        Ok(PlanNode::OptimizeTable(OptimizeTablePlan { db, table }))
+    }
+
+    // DfVacuumTable to plan.
+    #[tracing::instrument(level = "info", skip(self, vacuum), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_vacuum_table_to_plan(&self, vacuum: &DfVacuumTable) -> Result<PlanNode> {
+        let mut db = self.ctx.get_current_database();
+        if vacuum.name.0.is_empty() {
+            return Result::Err(ErrorCode::SyntaxException(
+                "VacuumTable table name is empty",
+            ));
+        }
+        let mut table = vacuum.name.0[0].value.clone();
+        if vacuum.name.0.len() > 1 {
+            db = table;
+            table = vacuum.name.0[1].value.clone();
+        }
+
+        Ok(PlanNode::VacuumTable(VacuumTablePlan {
+            db,
+            table,
+            retain_hours: vacuum.retain_hours,
+        }))
+    }
+
+    // DfUpdate to plan.
+    #[tracing::instrument(level = "info", skip(self, update), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_update_to_plan(&self, update: &DfUpdate) -> Result<PlanNode> {
+        let mut db_name = self.ctx.get_current_database();
+        if update.name.0.is_empty() {
+            return Result::Err(ErrorCode::SyntaxException("Update table name is empty"));
+        }
+        let mut tbl_name = update.name.0[0].value.clone();
+        if update.name.0.len() > 1 {
+            db_name = tbl_name;
+            tbl_name = update.name.0[1].value.clone();
+        }
+
+        let table = self.ctx.get_table(&db_name, &tbl_name)?;
+        let schema = table.schema();
+
+        let mut assignments: Vec<(String, Expression)> =
+            Vec::with_capacity(update.assignments.len());
+        for (column, value) in &update.assignments {
+            schema.field_with_name(&column.value)?;
+            if assignments.iter().any(|(c, _)| c == &column.value) {
+                return Result::Err(ErrorCode::SyntaxException(format!(
+                    "Column {} is updated more than once",
+                    column.value
+                )));
+            }
+
+            let value = self.sql_to_rex(value, &schema, None)?;
+            value.to_data_field(&schema)?;
+            assignments.push((column.value.clone(), value));
+        }
+
+        let selection = match &update.selection {
+            None => None,
+            Some(expr) => {
+                let predicate = self.sql_to_rex(expr, &schema, None)?;
+                predicate.to_data_field(&schema)?;
+                Some(predicate)
+            }
+        };
+
+        Ok(PlanNode::Update(UpdatePlan {
+            db_name,
+            tbl_name,
+            tbl_id: table.get_id(),
+            assignments,
+            selection,
+        }))
+    }
+
     #[tracing::instrument(level = "info", skip(self, table_name, columns, source), fields(ctx.id = self.ctx.get_id().as_str()))]
     fn insert_to_plan(
         &self,
@@ -515,23 +716,340 @@ impl PlanParser {
         Ok(PlanNode::InsertInto(plan_node))
     }
 
+    #[tracing::instrument(level = "info", skip(self, table_name, selection), fields(ctx.id = self.ctx.get_id().as_str()))]
+    fn delete_to_plan(
+        &self,
+        table_name: &ObjectName,
+        selection: &Option<sqlparser::ast::Expr>,
+    ) -> Result<PlanNode> {
+        let mut db_name = self.ctx.get_current_database();
+        let mut tbl_name = table_name.0[0].value.clone();
+
+        if table_name.0.len() > 1 {
+            db_name = tbl_name;
+            tbl_name = table_name.0[1].value.clone();
+        }
+
+        let table = self.ctx.get_table(&db_name, &tbl_name)?;
+        let schema = table.schema();
+        let selection = match selection {
+            None => None,
+            Some(expr) => {
+                let predicate = self.sql_to_rex(expr, &schema, None)?;
+                // make sure the predicate can be evaluated against the rows of the table
+                predicate.to_data_field(&schema)?;
+                Some(predicate)
+            }
+        };
+
+        Ok(PlanNode::Delete(DeletePlan {
+            db_name,
+            tbl_name,
+            tbl_id: table.get_id(),
+            selection,
+        }))
+    }
+
     /// Generate a logic plan from an SQL query
     pub fn query_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
-        if query.with.is_some() {
-            return Result::Err(ErrorCode::UnImplement("CTE is not yet implement"));
+        match &query.with {
+            None => self.query_body_to_plan(query),
+            Some(with) => {
+                if with.recursive {
+                    return Result::Err(ErrorCode::UnImplement(
+                        "Recursive CTE is not yet implemented",
+                    ));
+                }
+
+                // The CTEs referenced more than once are evaluated once and shared by the scans,
+                // the others are inlined once the references are all planned.
+                let (plan, references) = self.with_query_to_plan(with, query)?;
+                let scan_names = references
+                    .into_iter()
+                    .filter(|(_, references)| *references <= 1)
+                    .map(|(scan_name, _)| scan_name)
+                    .collect::<HashSet<_>>();
+
+                match scan_names.is_empty() {
+                    true => Ok(plan),
+                    false => CteInliner {
+                        scan_names: &scan_names,
+                    }
+                    .rewrite_plan_node(&plan),
+                }
+            }
+        }
+    }
+
+    /// Plan the query with its CTEs in scope.
+    /// Returns the plan and the number of references to each CTE by its scan name.
+    fn with_query_to_plan(
+        &self,
+        with: &sqlparser::ast::With,
+        query: &sqlparser::ast::Query,
+    ) -> Result<(PlanNode, HashMap<String, usize>)> {
+        self.cte_scopes.borrow_mut().push(HashMap::new());
+        let plan = with
+            .cte_tables
+            .iter()
+            .try_for_each(|cte| self.cte_to_plan(cte))
+            .and_then(|_| self.query_body_to_plan(query));
+        let scope = self.cte_scopes.borrow_mut().pop().unwrap_or_default();
+
+        let references = scope
+            .into_iter()
+            .map(|(_, cte)| (cte.scan_name, cte.references))
+            .collect();
+        Ok((plan?, references))
+    }
+
+    fn cte_to_plan(&self, cte: &sqlparser::ast::Cte) -> Result<()> {
+        let name = cte.alias.name.value.clone();
+        let mut plan = self.query_to_plan(&cte.query)?;
+
+        // WITH t(a, b) AS (...)
+        let columns = &cte.alias.columns;
+        if !columns.is_empty() {
+            let schema = plan.schema();
+            if schema.fields().len() != columns.len() {
+                return Result::Err(ErrorCode::SyntaxException(format!(
+                    "CTE '{}' has {} columns but {} column names are specified",
+                    name,
+                    schema.fields().len(),
+                    columns.len()
+                )));
+            }
+
+            let exprs = schema
+                .fields()
+                .iter()
+                .zip(columns.iter())
+                .map(|(field, column)| {
+                    Expression::Alias(
+                        column.value.clone(),
+                        Box::new(Expression::Column(field.name().clone())),
+                    )
+                })
+                .collect::<Vec<_>>();
+            plan = PlanBuilder::from(&plan).project(&exprs)?.build()?;
+        }
+
+        let index = self.cte_index.get();
+        self.cte_index.set(index + 1);
+
+        let mut cte_scopes = self.cte_scopes.borrow_mut();
+        let scope = cte_scopes
+            .last_mut()
+            .ok_or_else(|| ErrorCode::LogicalError("Missing scope of CTE"))?;
+        if scope.contains_key(&name) {
+            return Result::Err(ErrorCode::SyntaxException(format!(
+                "Duplicate CTE name '{}'",
+                name
+            )));
         }
 
+        scope.insert(name.clone(), CommonTableExpr {
+            scan_name: format!("{}_{}", name, index),
+            plan,
+            references: 0,
+        });
+        Ok(())
+    }
+
+    /// The schema of the CTE in scope, without referencing it.
+    fn cte_schema(&self, name: &str) -> Option<DataSchemaRef> {
+        let cte_scopes = self.cte_scopes.borrow();
+        let cte = cte_scopes.iter().rev().find_map(|scope| scope.get(name))?;
+        Some(cte.plan.schema())
+    }
+
+    /// Plan the reference to a CTE in scope, the innermost CTE with the name wins.
+    fn cte_scan_to_plan(&self, name: &str) -> Option<PlanNode> {
+        let mut cte_scopes = self.cte_scopes.borrow_mut();
+        let cte = cte_scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))?;
+
+        cte.references += 1;
+        Some(PlanNode::CteScan(CteScanPlan {
+            name: cte.scan_name.clone(),
+            query_plan: Arc::new(cte.plan.clone()),
+        }))
+    }
+
+    fn query_body_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
         match &query.body {
             sqlparser::ast::SetExpr::Select(s) => {
                 self.select_to_plan(s.as_ref(), &query.limit, &query.offset, &query.order_by)
             }
+            body => {
+                let plan = self.set_expr_to_plan(body)?;
+
+                // The ORDER BY of a set operation is against the output columns.
+                let output_exprs = plan
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| Expression::Column(field.name().clone()))
+                    .collect::<Vec<_>>();
+                let order_by_exprs = query
+                    .order_by
+                    .iter()
+                    .map(|e| -> Result<Expression> {
+                        let expr = self.sql_to_rex(&e.expr, &plan.schema(), None)?;
+                        Ok(Expression::Sort {
+                            expr: Box::new(expr.clone()),
+                            asc: e.asc.unwrap_or(true),
+                            nulls_first: e.nulls_first.unwrap_or(true),
+                            origin_expr: Box::new(expr),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let plan = match order_by_exprs.is_empty() {
+                    true => plan,
+                    false => {
+                        let mut expression_exprs = output_exprs.clone();
+                        expression_exprs.extend_from_slice(&order_by_exprs);
+                        let plan = self.expression(&plan, &expression_exprs, "Before OrderBy")?;
+                        let plan = self.sort(&plan, &order_by_exprs)?;
+                        self.project(&plan, &output_exprs)?
+                    }
+                };
+
+                let plan = self.limit(&plan, &query.limit, &query.offset, None)?;
+                Ok(PlanNode::Select(SelectPlan {
+                    input: Arc::new(plan),
+                }))
+            }
+        }
+    }
+
+    /// Generate a logic plan from the body of a query, the set operations are
+    /// built on the union and group by:
+    /// UNION ALL: union of the inputs
+    /// UNION: group by all the columns of UNION ALL
+    /// INTERSECT/EXCEPT: tag the rows with the side of input and group by all the columns
+    /// of UNION ALL, then keep the groups which come from both sides or the left side only.
+    fn set_expr_to_plan(&self, set_expr: &sqlparser::ast::SetExpr) -> Result<PlanNode> {
+        match set_expr {
+            sqlparser::ast::SetExpr::Select(s) => {
+                self.select_to_plan(s.as_ref(), &None, &None, &[])
+            }
+            sqlparser::ast::SetExpr::Query(q) => self.query_to_plan(q.as_ref()),
+            sqlparser::ast::SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => {
+                let left_plan = self.set_expr_to_plan(left.as_ref())?;
+                let right_plan = self.set_expr_to_plan(right.as_ref())?;
+
+                match (op, all) {
+                    (sqlparser::ast::SetOperator::Union, true) => {
+                        Self::union_all(&left_plan, &right_plan)
+                    }
+                    (sqlparser::ast::SetOperator::Union, false) => {
+                        let plan = Self::union_all(&left_plan, &right_plan)?;
+                        self.distinct(&plan)
+                    }
+                    (sqlparser::ast::SetOperator::Intersect, false) => {
+                        self.intersect_or_except(&left_plan, &right_plan, true)
+                    }
+                    (sqlparser::ast::SetOperator::Except, false) => {
+                        self.intersect_or_except(&left_plan, &right_plan, false)
+                    }
+                    (op, _) => Result::Err(ErrorCode::UnImplement(format!(
+                        "{} ALL is not yet implemented",
+                        op
+                    ))),
+                }
+            }
             _ => Result::Err(ErrorCode::UnImplement(format!(
                 "Query {} is not yet implemented",
-                query.body
+                set_expr
             ))),
         }
     }
 
+    fn union_all(left: &PlanNode, right: &PlanNode) -> Result<PlanNode> {
+        // a UNION ALL b UNION ALL c is flattened into one union.
+        let mut inputs = match left {
+            PlanNode::Union(plan) => plan.inputs.iter().map(|v| v.as_ref().clone()).collect(),
+            _ => vec![left.clone()],
+        };
+        inputs.push(right.clone());
+
+        PlanBuilder::from(&inputs[0])
+            .union(&inputs[1..])
+            .and_then(|builder| builder.build())
+    }
+
+    /// Remove the duplicate rows by grouping all the columns.
+    fn distinct(&self, plan: &PlanNode) -> Result<PlanNode> {
+        let columns = plan
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| Expression::Column(field.name().clone()))
+            .collect::<Vec<_>>();
+
+        let plan = self.aggregate(plan, &[], &columns)?;
+        self.project(&plan, &columns)
+    }
+
+    fn intersect_or_except(
+        &self,
+        left: &PlanNode,
+        right: &PlanNode,
+        is_intersect: bool,
+    ) -> Result<PlanNode> {
+        let side_column = "_set_operation_side";
+        let tag_side = |plan: &PlanNode, side: u8| -> Result<PlanNode> {
+            let mut exprs = plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| Expression::Column(field.name().clone()))
+                .collect::<Vec<_>>();
+            exprs.push(lit(side).alias(side_column));
+            self.project(plan, &exprs)
+        };
+
+        let plan = Self::union_all(&tag_side(left, 1)?, &tag_side(right, 2)?)?;
+        let columns = plan
+            .schema()
+            .fields()
+            .iter()
+            .filter(|field| field.name() != side_column)
+            .map(|field| Expression::Column(field.name().clone()))
+            .collect::<Vec<_>>();
+
+        let side_aggregate = |op: &str| Expression::AggregateFunction {
+            op: op.to_string(),
+            distinct: false,
+            params: vec![],
+            args: vec![Expression::Column(side_column.to_string())],
+        };
+        let min_side = side_aggregate("min");
+        let max_side = side_aggregate("max");
+        let plan = self.aggregate(&plan, &[min_side.clone(), max_side.clone()], &columns)?;
+
+        // INTERSECT: the group comes from both sides.
+        // EXCEPT: the group comes from the left side only.
+        let min_side = Expression::Column(min_side.column_name());
+        let max_side = Expression::Column(max_side.column_name());
+        let predicate = match is_intersect {
+            true => min_side.eq(lit(1u8)).and(max_side.eq(lit(2u8))),
+            false => max_side.eq(lit(1u8)),
+        };
+
+        let plan = PlanBuilder::from(&plan).filter(predicate)?.build()?;
+        self.project(&plan, &columns)
+    }
+
     /// Generate a logic plan from an SQL select
     /// For example:
     /// "select sum(number+1)+2, number%3 as id from numbers(10) where number>1 group by id having id>1 order by id desc limit 3"
@@ -543,11 +1061,89 @@ impl PlanParser {
         offset: &Option<sqlparser::ast::Offset>,
         order_by: &[OrderByExpr],
     ) -> Result<PlanNode> {
+        let input = self.plan_tables_with_joins(&select.from, Some(select))?;
+        let (input, select) = self.prewhere_to_plan(input, select)?;
+        self.select_input_to_plan(&input, &select, limit, offset, order_by)
+    }
+
+    /// Pick the `prewhere(...)` conjuncts of the selection, which are rewritten from the PREWHERE
+    /// clause by the parser, as the prewhere of the table read. They are still kept in the
+    /// selection, the rows are filtered as usual after the late materialization.
+    fn prewhere_to_plan(
+        &self,
+        input: PlanNode,
+        select: &sqlparser::ast::Select,
+    ) -> Result<(PlanNode, sqlparser::ast::Select)> {
+        let selection = match &select.selection {
+            None => return Ok((input, select.clone())),
+            Some(selection) => selection.clone(),
+        };
+
+        let mut conjunctions = vec![];
+        Self::split_sql_conjunctions(selection, &mut conjunctions);
+
+        let mut prewhere = vec![];
+        let mut where_conjunctions = Vec::with_capacity(conjunctions.len());
+        for conjunction in conjunctions {
+            match conjunction {
+                sqlparser::ast::Expr::Function(sqlparser::ast::Function { name, args, .. })
+                    if name.to_string().to_lowercase() == "prewhere" =>
+                {
+                    match args.as_slice() {
+                        [FunctionArg::Unnamed(arg)] => {
+                            prewhere.push(arg.clone());
+                            where_conjunctions.push(arg.clone());
+                        }
+                        _ => {
+                            return Result::Err(ErrorCode::SyntaxException(
+                                "PREWHERE must have exactly one expression",
+                            ))
+                        }
+                    }
+                }
+                other => where_conjunctions.push(other),
+            }
+        }
+
+        if prewhere.is_empty() {
+            return Ok((input, select.clone()));
+        }
+
+        let mut select = select.clone();
+        select.selection = Self::conjoin_sql_exprs(where_conjunctions);
+        match input {
+            PlanNode::ReadSource(mut plan) => {
+                let schema = plan.schema();
+                let mut extras = plan.push_downs.take().unwrap_or_else(Extras::default);
+                extras.prewhere = prewhere
+                    .iter()
+                    .map(|expr| self.sql_to_rex(expr, &schema, None))
+                    .collect::<Result<Vec<_>>>()?;
+                plan.push_downs = Some(extras);
+                Ok((PlanNode::ReadSource(plan), select))
+            }
+            _ => Result::Err(ErrorCode::SyntaxException(
+                "PREWHERE is only supported when selecting from a single table",
+            )),
+        }
+    }
+
+    /// Plan the select on the plan of its FROM clause.
+    fn select_input_to_plan(
+        &self,
+        input: &PlanNode,
+        select: &sqlparser::ast::Select,
+        limit: &Option<sqlparser::ast::Expr>,
+        offset: &Option<sqlparser::ast::Offset>,
+        order_by: &[OrderByExpr],
+    ) -> Result<PlanNode> {
+        // Correlated subqueries are replaced by the columns joined to the input
+        let (plan, select) = self.decorrelate_subqueries(input, select)?;
+        let select = &select;
+
         // Filter expression
         // In example: Filter=(number > 1)
-        let plan = self
-            .plan_tables_with_joins(&select.from)
-            .and_then(|input| self.filter(&input, &select.selection, Some(select)))?;
+        let plan = self.filter(&plan, &select.selection, Some(select))?;
 
         // Projection expression
         // In example: Projection=[(sum((number + 1)) + 2), (number % 3) as id]
@@ -557,7 +1153,7 @@ impl PlanParser {
             .map(|e| self.sql_select_to_rex(e, &plan.schema(), Some(select)))
             .collect::<Result<Vec<Expression>>>()?
             .iter()
-            .flat_map(|expr| expand_wildcard(expr, &plan.schema()))
+            .flat_map(|expr| expand_wildcard(expr, &input.schema()))
             .collect::<Vec<Expression>>();
 
         // Aliases replacement for group by, having, sorting
@@ -725,10 +1321,16 @@ impl PlanParser {
         }
     }
 
-    fn plan_tables_with_joins(&self, from: &[sqlparser::ast::TableWithJoins]) -> Result<PlanNode> {
+    fn plan_tables_with_joins(
+        &self,
+        from: &[sqlparser::ast::TableWithJoins],
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<PlanNode> {
         match from.len() {
             0 => self.plan_with_dummy_source(),
-            1 => self.plan_table_with_joins(&from[0]),
+            1 => self
+                .plan_table_with_joins(&from[0], select)
+                .map(|(plan, _)| plan),
             // Such as SELECT * FROM t1, t2;
             // It's not `JOIN` clause.
             _ => Result::Err(ErrorCode::SyntaxException("Cannot SELECT multiple tables")),
@@ -746,73 +1348,1015 @@ impl PlanParser {
         Ok(dummy_read_plan)
     }
 
-    fn plan_table_with_joins(&self, t: &sqlparser::ast::TableWithJoins) -> Result<PlanNode> {
-        self.create_relation(&t.relation)
+    /// Plan the relation and its joins as a left-deep tree.
+    /// Returns the plan together with the table qualifier of each output column.
+    fn plan_table_with_joins(
+        &self,
+        t: &sqlparser::ast::TableWithJoins,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<(PlanNode, Vec<Option<String>>)> {
+        let mut left = self.plan_relation(&t.relation, select)?;
+        for join in &t.joins {
+            let (join_type, constraint) = match &join.join_operator {
+                JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+                JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+                JoinOperator::RightOuter(constraint) => (JoinType::Right, constraint),
+                JoinOperator::FullOuter(constraint) => (JoinType::Full, constraint),
+                other => {
+                    return Result::Err(ErrorCode::UnImplement(format!(
+                        "Join operator {:?} is not yet implemented",
+                        other
+                    )))
+                }
+            };
+
+            let right = self.plan_relation(&join.relation, select)?;
+            left = self.join(join_type, constraint, left, right, select)?;
+        }
+        Ok(left)
     }
 
-    fn create_relation(&self, relation: &sqlparser::ast::TableFactor) -> Result<PlanNode> {
+    fn plan_relation(
+        &self,
+        relation: &sqlparser::ast::TableFactor,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<(PlanNode, Vec<Option<String>>)> {
         match relation {
-            TableFactor::Table { name, args, .. } => {
-                let mut db_name = self.ctx.get_current_database();
-                let mut table_name = name.to_string();
-                if name.0.len() == 2 {
-                    db_name = name.0[0].to_string();
-                    table_name = name.0[1].to_string();
+            TableFactor::NestedJoin(table_with_joins) => {
+                self.plan_table_with_joins(table_with_joins, select)
+            }
+            _ => {
+                let plan = self.create_relation(relation)?;
+                self.relation_schemas
+                    .borrow_mut()
+                    .insert(relation.to_string(), plan.schema());
+                let qualifiers =
+                    vec![Self::relation_qualifier(relation); plan.schema().fields().len()];
+                Ok((plan, qualifiers))
+            }
+        }
+    }
+
+    /// The name used to qualify the columns of a relation: its alias, or else its table name.
+    fn relation_qualifier(relation: &sqlparser::ast::TableFactor) -> Option<String> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => match alias {
+                Some(alias) => Some(alias.name.value.clone()),
+                None => name.0.last().map(|ident| ident.value.clone()),
+            },
+            TableFactor::Derived { alias, .. } | TableFactor::TableFunction { alias, .. } => {
+                alias.as_ref().map(|alias| alias.name.value.clone())
+            }
+            TableFactor::NestedJoin(_) => None,
+        }
+    }
+
+    /// Find the relation whose columns can be qualified with the name.
+    fn find_qualified_relation<'a>(
+        t: &'a sqlparser::ast::TableWithJoins,
+        qualifier: &str,
+    ) -> Option<&'a TableFactor> {
+        let relations = std::iter::once(&t.relation).chain(t.joins.iter().map(|j| &j.relation));
+        for relation in relations {
+            let found = match relation {
+                TableFactor::NestedJoin(table_with_joins) => {
+                    Self::find_qualified_relation(table_with_joins, qualifier)
                 }
-                let table;
+                _ => match Self::relation_names(relation)
+                    .iter()
+                    .any(|n| n == qualifier)
+                {
+                    true => Some(relation),
+                    false => None,
+                },
+            };
 
-                // only table functions has table args
-                if !args.is_empty() {
-                    if name.0.len() >= 2 {
-                        return Result::Err(ErrorCode::BadArguments(
-                            "Currently table can't have arguments",
-                        ));
-                    }
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
 
-                    let empty_schema = Arc::new(DataSchema::empty());
-                    let table_args = args.iter().try_fold(
-                        Vec::with_capacity(args.len()),
-                        |mut acc, f_arg| {
-                            let item = match f_arg {
-                                FunctionArg::Named { arg, .. } => {
-                                    self.sql_to_rex(arg, empty_schema.as_ref(), None)?
-                                }
-                                FunctionArg::Unnamed(arg) => {
-                                    self.sql_to_rex(arg, empty_schema.as_ref(), None)?
-                                }
-                            };
-                            acc.push(item);
-                            Ok::<_, ErrorCode>(acc)
-                        },
-                    )?;
+    /// The own schema of the relation, before it's joined.
+    fn relation_schema(&self, relation: &TableFactor) -> Result<DataSchemaRef> {
+        if let Some(schema) = self.relation_schemas.borrow().get(&relation.to_string()) {
+            return Ok(schema.clone());
+        }
+        Ok(self.create_relation(relation)?.schema())
+    }
 
-                    let table_func = self
-                        .ctx
-                        .get_catalog()
-                        .get_table_function(&table_name, Some(table_args))?;
-                    table = table_func.as_table();
-                } else {
-                    table = self.ctx.get_table(&db_name, &table_name)?;
+    /// Collect all the names a column of the relation can be qualified with.
+    fn collect_qualifiers(t: &sqlparser::ast::TableWithJoins, qualifiers: &mut Vec<String>) {
+        let relations = std::iter::once(&t.relation).chain(t.joins.iter().map(|j| &j.relation));
+        for relation in relations {
+            match relation {
+                TableFactor::NestedJoin(table_with_joins) => {
+                    Self::collect_qualifiers(table_with_joins, qualifiers)
                 }
+                _ => qualifiers.extend(Self::relation_names(relation)),
+            }
+        }
+    }
 
-                // TODO: Move ReadSourcePlan to SelectInterpreter
-                let source_plan = table.read_plan(self.ctx.clone(), None)?;
+    /// Collect the column names of the relations and their joins without planning them, except
+    /// for the derived tables and the table functions whose columns are only known once planned.
+    fn collect_relation_columns(
+        &self,
+        t: &sqlparser::ast::TableWithJoins,
+        columns: &mut HashSet<String>,
+    ) -> Result<()> {
+        let relations = std::iter::once(&t.relation).chain(t.joins.iter().map(|j| &j.relation));
+        for relation in relations {
+            let schema = match relation {
+                TableFactor::NestedJoin(table_with_joins) => {
+                    self.collect_relation_columns(table_with_joins, columns)?;
+                    continue;
+                }
+                TableFactor::Table { name, args, .. }
+                    if args.is_empty() || self.navigation_point(args)?.is_some() =>
+                {
+                    let cte_schema = match (args.is_empty(), name.0.as_slice()) {
+                        (true, [ident]) => self.cte_schema(&ident.value),
+                        _ => None,
+                    };
+                    match cte_schema {
+                        Some(schema) => schema,
+                        None => {
+                            let (db_name, table_name) = match name.0.as_slice() {
+                                [db_name, table_name] => {
+                                    (db_name.to_string(), table_name.to_string())
+                                }
+                                _ => (self.ctx.get_current_database(), name.to_string()),
+                            };
+                            self.ctx.get_table(&db_name, &table_name)?.schema()
+                        }
+                    }
+                }
+                _ => self.relation_schema(relation)?,
+            };
+            columns.extend(schema.fields().iter().map(|field| field.name().clone()));
+        }
+        Ok(())
+    }
+
+    /// The names a column of the relation, which is not a nested join, can be qualified with.
+    fn relation_names(relation: &sqlparser::ast::TableFactor) -> Vec<String> {
+        let mut names = vec![];
+        if let TableFactor::Table { name, .. } = relation {
+            if let Some(ident) = name.0.last() {
+                names.push(ident.value.clone());
+            }
+        }
+        names.extend(Self::relation_qualifier(relation));
+        names
+    }
+
+    /// Join the right relation to the left one.
+    /// The columns existing on both sides are renamed to `qualifier.column`, except for the
+    /// USING columns which are merged into one output column.
+    fn join(
+        &self,
+        join_type: JoinType,
+        constraint: &JoinConstraint,
+        left: (PlanNode, Vec<Option<String>>),
+        right: (PlanNode, Vec<Option<String>>),
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<(PlanNode, Vec<Option<String>>)> {
+        let (left, left_qualifiers) = left;
+        let (right, right_qualifiers) = right;
+
+        if let Some(Some(qualifier)) = right_qualifiers
+            .iter()
+            .find(|q| q.is_some() && left_qualifiers.contains(q))
+        {
+            return Result::Err(ErrorCode::SyntaxException(format!(
+                "Not unique table/alias: '{}'",
+                qualifier
+            )));
+        }
+
+        let using = match constraint {
+            JoinConstraint::On(_) => vec![],
+            JoinConstraint::Using(idents) => idents
+                .iter()
+                .map(|ident| ident.value.clone())
+                .collect::<Vec<_>>(),
+            _ => {
+                return Result::Err(ErrorCode::UnImplement(
+                    "Join without ON or USING clause is not yet implemented",
+                ))
+            }
+        };
+
+        if join_type == JoinType::Full && !using.is_empty() {
+            return Result::Err(ErrorCode::UnImplement(
+                "FULL JOIN with USING clause is not yet implemented",
+            ));
+        }
+
+        for name in &using {
+            if left.schema().field_with_name(name).is_err()
+                || right.schema().field_with_name(name).is_err()
+            {
+                return Result::Err(ErrorCode::SyntaxException(format!(
+                    "Unknown column '{}' in USING clause",
+                    name
+                )));
+            }
+        }
+
+        let original_left_schema = left.schema();
+        let original_right_schema = right.schema();
+        let (left, left_names) = Self::disambiguate_columns(
+            &left,
+            &left_qualifiers,
+            &original_right_schema,
+            &using,
+            true,
+        )?;
+        let (right, right_names) = Self::disambiguate_columns(
+            &right,
+            &right_qualifiers,
+            &original_left_schema,
+            &using,
+            false,
+        )?;
+
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        let uses_columns_of = |expr: &Expression, schema: &DataSchemaRef| {
+            find_column_exprs(&[expr.clone()]).iter().any(|column| {
+                matches!(column, Expression::Column(name) if schema.field_with_name(name).is_ok())
+            })
+        };
+
+        let mut left_keys = vec![];
+        let mut right_keys = vec![];
+        let mut left_filters = vec![];
+        let mut right_filters = vec![];
+        let mut residual_filters = vec![];
+        match constraint {
+            JoinConstraint::On(on) => {
+                let joined_schema = JoinPlan::join_schema(join_type, &left_schema, &right_schema);
+                let on = self.sql_to_rex(on, &joined_schema, select)?;
+
+                let mut conjunctions = vec![];
+                Self::split_conjunctions(on, &mut conjunctions);
+                for conjunction in conjunctions {
+                    if let Expression::BinaryExpression { op, left, right } = &conjunction {
+                        if op == "=" {
+                            let left_uses = (
+                                uses_columns_of(left, &left_schema),
+                                uses_columns_of(left, &right_schema),
+                            );
+                            let right_uses = (
+                                uses_columns_of(right, &left_schema),
+                                uses_columns_of(right, &right_schema),
+                            );
+                            match (left_uses, right_uses) {
+                                ((true, false), (false, true)) => {
+                                    left_keys.push(left.as_ref().clone());
+                                    right_keys.push(right.as_ref().clone());
+                                    continue;
+                                }
+                                ((false, true), (true, false)) => {
+                                    left_keys.push(right.as_ref().clone());
+                                    right_keys.push(left.as_ref().clone());
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+
+                    let uses_left = uses_columns_of(&conjunction, &left_schema);
+                    let uses_right = uses_columns_of(&conjunction, &right_schema);
+                    match join_type {
+                        JoinType::Inner => residual_filters.push(conjunction),
+                        JoinType::Left if !uses_left => right_filters.push(conjunction),
+                        JoinType::Right if !uses_right => left_filters.push(conjunction),
+                        _ => {
+                            return Result::Err(ErrorCode::UnImplement(format!(
+                                "Condition {:?} of {} JOIN is not yet implemented",
+                                conjunction, join_type
+                            )))
+                        }
+                    }
+                }
+            }
+            _ => {
+                for name in &using {
+                    let index = original_right_schema.index_of(name)?;
+                    left_keys.push(Expression::Column(name.clone()));
+                    right_keys.push(Expression::Column(right_names[index].clone()));
+                }
+            }
+        }
+
+        if left_keys.is_empty() {
+            return Result::Err(ErrorCode::UnImplement(
+                "Join without equi-join condition is not yet implemented",
+            ));
+        }
+
+        Self::coerce_join_keys(&mut left_keys, &mut right_keys, &left_schema, &right_schema)?;
+
+        let left = Self::filter_by_conjunctions(&left, left_filters)?;
+        let right = Self::filter_by_conjunctions(&right, right_filters)?;
+        let plan = PlanBuilder::from(&left)
+            .join(join_type, &left_keys, &right_keys, &right)?
+            .build()?;
+        let plan = Self::filter_by_conjunctions(&plan, residual_filters)?;
+
+        let mut qualifiers = left_qualifiers;
+        qualifiers.extend(right_qualifiers);
+        if using.is_empty() {
+            return Ok((plan, qualifiers));
+        }
+
+        // Merge the USING columns of both sides into one, taking the value from the side
+        // that is never NULL-extended.
+        let mut exprs = vec![];
+        let mut output_qualifiers = vec![];
+        for (index, name) in left_names.iter().chain(right_names.iter()).enumerate() {
+            let (is_left, original_name) = match index < left_names.len() {
+                true => (true, original_left_schema.field(index).name()),
+                false => (
+                    false,
+                    original_right_schema.field(index - left_names.len()).name(),
+                ),
+            };
+            let is_using = using.contains(original_name);
+            let expr = match (is_left, is_using) {
+                (true, true) if join_type == JoinType::Right => {
+                    let right_index = original_right_schema.index_of(name)?;
+                    Expression::Alias(
+                        name.clone(),
+                        Box::new(Expression::Column(right_names[right_index].clone())),
+                    )
+                }
+                (false, true) => continue,
+                _ => Expression::Column(name.clone()),
+            };
+            exprs.push(expr);
+            output_qualifiers.push(qualifiers[index].clone());
+        }
+
+        let plan = PlanBuilder::from(&plan).project(&exprs)?.build()?;
+        Ok((plan, output_qualifiers))
+    }
+
+    /// Coerce the key pairs to the same type.
+    fn coerce_join_keys(
+        left_keys: &mut [Expression],
+        right_keys: &mut [Expression],
+        left_schema: &DataSchemaRef,
+        right_schema: &DataSchemaRef,
+    ) -> Result<()> {
+        for (left_key, right_key) in left_keys.iter_mut().zip(right_keys.iter_mut()) {
+            let left_type = left_key.to_data_type(left_schema)?;
+            let right_type = right_key.to_data_type(right_schema)?;
+            if left_type != right_type {
+                let data_type = compare_coercion(&left_type, &right_type)?;
+                if left_type != data_type {
+                    *left_key = Expression::Cast {
+                        expr: Box::new(left_key.clone()),
+                        data_type: data_type.clone(),
+                    };
+                }
+                if right_type != data_type {
+                    *right_key = Expression::Cast {
+                        expr: Box::new(right_key.clone()),
+                        data_type,
+                    };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rename the columns which also exist in the other schema to `qualifier.column`.
+    /// The USING columns keep their names on the left side.
+    /// Returns the plan and its output column names.
+    fn disambiguate_columns(
+        plan: &PlanNode,
+        qualifiers: &[Option<String>],
+        other: &DataSchemaRef,
+        using: &[String],
+        is_left: bool,
+    ) -> Result<(PlanNode, Vec<String>)> {
+        let schema = plan.schema();
+        let mut renamed = false;
+        let mut names = Vec::with_capacity(schema.fields().len());
+        let mut exprs = Vec::with_capacity(schema.fields().len());
+        for (field, qualifier) in schema.fields().iter().zip(qualifiers.iter()) {
+            let name = field.name();
+            if other.field_with_name(name).is_err() || (is_left && using.contains(name)) {
+                names.push(name.clone());
+                exprs.push(Expression::Column(name.clone()));
+                continue;
+            }
+
+            let qualified_name = match qualifier {
+                Some(qualifier) => format!("{}.{}", qualifier, name),
+                None if using.contains(name) => format!("using.{}", name),
+                None => {
+                    return Result::Err(ErrorCode::SyntaxException(format!(
+                        "Column '{}' in join is ambiguous",
+                        name
+                    )))
+                }
+            };
+
+            renamed = true;
+            names.push(qualified_name.clone());
+            exprs.push(Expression::Alias(
+                qualified_name,
+                Box::new(Expression::Column(name.clone())),
+            ));
+        }
+
+        match renamed {
+            true => Ok((PlanBuilder::from(plan).project(&exprs)?.build()?, names)),
+            false => Ok((plan.clone(), names)),
+        }
+    }
+
+    fn split_conjunctions(expr: Expression, conjunctions: &mut Vec<Expression>) {
+        match expr {
+            Expression::BinaryExpression { op, left, right } if op.to_lowercase() == "and" => {
+                Self::split_conjunctions(*left, conjunctions);
+                Self::split_conjunctions(*right, conjunctions);
+            }
+            other => conjunctions.push(other),
+        }
+    }
+
+    fn filter_by_conjunctions(plan: &PlanNode, conjunctions: Vec<Expression>) -> Result<PlanNode> {
+        match conjunctions
+            .into_iter()
+            .reduce(|left, right| left.and(right))
+        {
+            None => Ok(plan.clone()),
+            Some(predicate) => PlanBuilder::from(plan).filter(predicate)?.build(),
+        }
+    }
+
+    /// Decorrelate the correlated subqueries of the select into joins with the input:
+    /// `EXISTS` and `IN` of the WHERE conjunctions become SEMI joins, `NOT EXISTS` becomes
+    /// ANTI join, and the scalar subqueries become LEFT joins with the subqueries grouped by
+    /// the correlated columns.
+    /// Returns the joined plan and the select with the scalar subqueries replaced by columns.
+    fn decorrelate_subqueries(
+        &self,
+        input: &PlanNode,
+        select: &sqlparser::ast::Select,
+    ) -> Result<(PlanNode, sqlparser::ast::Select)> {
+        let outer_schema = input.schema();
+        let mut rewritten = select.clone();
+        let mut conjunctions = vec![];
+        if let Some(selection) = rewritten.selection.take() {
+            Self::split_sql_conjunctions(selection, &mut conjunctions);
+        }
+
+        let mut filters = vec![];
+        let mut post_join_filters = vec![];
+        let mut existence_joins = vec![];
+        let mut scalar_joins = vec![];
+        for mut conjunction in conjunctions {
+            if let Some((in_expr, subquery, negated)) = Self::existence_subquery(&conjunction) {
+                let kind = match in_expr {
+                    Some(_) => SubqueryKind::In,
+                    None => SubqueryKind::Exists,
+                };
+
+                if let Some(subquery) = self.correlate(subquery, kind, &outer_schema, select)? {
+                    let join_type = match (negated, in_expr) {
+                        (false, _) => JoinType::Semi,
+                        (true, None) => JoinType::Anti,
+                        (true, Some(_)) => {
+                            return Result::Err(ErrorCode::UnImplement(
+                                "Correlated NOT IN subquery is not yet implemented",
+                            ))
+                        }
+                    };
+                    existence_joins.push((join_type, in_expr.cloned(), subquery));
+                    continue;
+                }
+            }
+
+            match self.rewrite_scalar_subqueries(
+                &mut conjunction,
+                &outer_schema,
+                select,
+                &mut scalar_joins,
+            )? {
+                true => post_join_filters.push(conjunction),
+                false => filters.push(conjunction),
+            }
+        }
+
+        for item in rewritten.projection.iter_mut() {
+            if let sqlparser::ast::SelectItem::UnnamedExpr(expr)
+            | sqlparser::ast::SelectItem::ExprWithAlias { expr, .. } = item
+            {
+                self.rewrite_scalar_subqueries(expr, &outer_schema, select, &mut scalar_joins)?;
+            }
+        }
+
+        if existence_joins.is_empty() && scalar_joins.is_empty() {
+            return Ok((input.clone(), select.clone()));
+        }
+
+        // Filter the input before joining as much as possible.
+        let filters = Self::conjoin_sql_exprs(filters);
+        let mut plan = self.filter(input, &filters, Some(select))?;
+        for (join_type, in_expr, subquery) in existence_joins {
+            plan = self.join_subquery(&plan, join_type, in_expr.as_ref(), subquery, select)?;
+        }
+        for subquery in scalar_joins {
+            plan = self.join_subquery(&plan, JoinType::Left, None, subquery, select)?;
+        }
+
+        rewritten.selection = Self::conjoin_sql_exprs(post_join_filters);
+        Ok((plan, rewritten))
+    }
+
+    /// Match `[NOT] EXISTS (subquery)` and `expr [NOT] IN (subquery)`.
+    /// Returns the IN expression, the subquery and whether it's negated.
+    fn existence_subquery(
+        expr: &sqlparser::ast::Expr,
+    ) -> Option<(Option<&sqlparser::ast::Expr>, &Query, bool)> {
+        match expr {
+            sqlparser::ast::Expr::Nested(expr) => Self::existence_subquery(expr),
+            sqlparser::ast::Expr::Exists(subquery) => Some((None, subquery.as_ref(), false)),
+            sqlparser::ast::Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => Some((Some(expr.as_ref()), subquery.as_ref(), *negated)),
+            sqlparser::ast::Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } => Self::existence_subquery(expr)
+                .map(|(in_expr, subquery, negated)| (in_expr, subquery, !negated)),
+            _ => None,
+        }
+    }
+
+    /// Replace the correlated scalar subqueries in the expression by the columns of their
+    /// values. Returns whether any subquery is replaced.
+    fn rewrite_scalar_subqueries(
+        &self,
+        expr: &mut sqlparser::ast::Expr,
+        outer_schema: &DataSchemaRef,
+        outer: &sqlparser::ast::Select,
+        joins: &mut Vec<CorrelatedSubquery>,
+    ) -> Result<bool> {
+        if let sqlparser::ast::Expr::Subquery(subquery) = expr {
+            let correlated = self.correlate(subquery, SubqueryKind::Scalar, outer_schema, outer)?;
+            return match correlated {
+                None => Ok(false),
+                Some(subquery) => {
+                    let value = subquery.value.clone().unwrap_or_default();
+                    *expr = sqlparser::ast::Expr::Identifier(Ident::new(value));
+                    joins.push(subquery);
+                    Ok(true)
+                }
+            };
+        }
+
+        let mut rewritten = false;
+        for child in sql_expr_children_mut(expr) {
+            rewritten |= self.rewrite_scalar_subqueries(child, outer_schema, outer, joins)?;
+        }
+        Ok(rewritten)
+    }
+
+    /// Split the WHERE conditions of the subquery into the conditions on the subquery itself,
+    /// and the equi-conditions correlated to the outer query, such as `inner.a = outer.a`.
+    /// The subquery without the correlated conditions is planned to output its value(if any)
+    /// and the inner side of the conditions, grouped by the latter if it's aggregated.
+    /// Returns None if the subquery is not correlated.
+    fn correlate(
+        &self,
+        subquery: &Query,
+        kind: SubqueryKind,
+        outer_schema: &DataSchemaRef,
+        outer: &sqlparser::ast::Select,
+    ) -> Result<Option<CorrelatedSubquery>> {
+        let (inner, selection) = match &subquery.body {
+            sqlparser::ast::SetExpr::Select(inner) if subquery.with.is_none() => {
+                match (inner.from.as_slice(), &inner.selection) {
+                    ([_], Some(selection)) => (inner.as_ref(), selection),
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        let mut outer_qualifiers = vec![];
+        if let [from] = outer.from.as_slice() {
+            Self::collect_qualifiers(from, &mut outer_qualifiers);
+        }
+        let mut inner_qualifiers = vec![];
+        Self::collect_qualifiers(&inner.from[0], &mut inner_qualifiers);
+        let is_outer_qualified = |ids: &[Ident]| {
+            ids.len() == 2
+                && !inner_qualifiers.contains(&ids[0].value)
+                && outer_qualifiers.contains(&ids[0].value)
+        };
+
+        // Look for the outer columns before planning the relations of the subquery.
+        let mut identifiers = vec![];
+        collect_sql_identifiers(selection, &mut identifiers);
+        let maybe_correlated = identifiers.iter().any(|identifier| match identifier {
+            sqlparser::ast::Expr::Identifier(ident) => {
+                outer_schema.field_with_name(&ident.value).is_ok()
+            }
+            sqlparser::ast::Expr::CompoundIdentifier(ids) => is_outer_qualified(ids),
+            _ => false,
+        });
+        if !maybe_correlated {
+            return Ok(None);
+        }
+
+        // The unqualified columns are resolved in the subquery first. The subquery is planned
+        // only once it's known to be correlated, the uncorrelated one is planned as a value.
+        let mut inner_columns = HashSet::new();
+        self.collect_relation_columns(&inner.from[0], &mut inner_columns)?;
+        let uses_outer = |expr: &sqlparser::ast::Expr| {
+            let mut identifiers = vec![];
+            collect_sql_identifiers(expr, &mut identifiers);
+            let outer_identifiers = identifiers
+                .iter()
+                .filter(|identifier| match identifier {
+                    sqlparser::ast::Expr::Identifier(ident) => {
+                        !inner_columns.contains(&ident.value)
+                            && outer_schema.field_with_name(&ident.value).is_ok()
+                    }
+                    sqlparser::ast::Expr::CompoundIdentifier(ids) => is_outer_qualified(ids),
+                    _ => false,
+                })
+                .count();
+            // (uses any outer column, uses only outer columns)
+            (
+                outer_identifiers > 0,
+                outer_identifiers == identifiers.len(),
+            )
+        };
+
+        let mut conjunctions = vec![];
+        Self::split_sql_conjunctions(selection.clone(), &mut conjunctions);
+        let mut inner_conjunctions = vec![];
+        let mut outer_keys = vec![];
+        let mut inner_keys = vec![];
+        for conjunction in conjunctions {
+            if !uses_outer(&conjunction).0 {
+                inner_conjunctions.push(conjunction);
+                continue;
+            }
+
+            if let sqlparser::ast::Expr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } = &conjunction
+            {
+                match (uses_outer(left.as_ref()), uses_outer(right.as_ref())) {
+                    ((true, true), (false, _)) => {
+                        outer_keys.push(left.as_ref().clone());
+                        inner_keys.push(right.as_ref().clone());
+                        continue;
+                    }
+                    ((false, _), (true, true)) => {
+                        outer_keys.push(right.as_ref().clone());
+                        inner_keys.push(left.as_ref().clone());
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            return Result::Err(ErrorCode::UnImplement(format!(
+                "Correlated condition {} of subquery is not yet implemented",
+                conjunction
+            )));
+        }
+
+        if outer_keys.is_empty() {
+            return Ok(None);
+        }
+
+        if subquery.limit.is_some() || subquery.offset.is_some() {
+            return Result::Err(ErrorCode::UnImplement(
+                "LIMIT of correlated subquery is not yet implemented",
+            ));
+        }
+
+        let (inner_plan, _) = self.plan_table_with_joins(&inner.from[0], Some(inner))?;
+        let inner_schema = inner_plan.schema();
+        let name = self.ctx.get_subquery_name(&inner_plan);
+        let keys = (0..inner_keys.len())
+            .map(|index| format!("{}_key_{}", name, index))
+            .collect::<Vec<_>>();
+
+        let mut select = inner.clone();
+        select.selection = Self::conjoin_sql_exprs(inner_conjunctions);
+        select.projection = vec![];
+
+        let mut value = None;
+        let mut empty_value = None;
+        let mut aggregated = !select.group_by.is_empty() || select.having.is_some();
+        if kind != SubqueryKind::Exists {
+            let value_expr = match inner.projection.as_slice() {
+                [sqlparser::ast::SelectItem::UnnamedExpr(expr)]
+                | [sqlparser::ast::SelectItem::ExprWithAlias { expr, .. }] => expr,
+                _ => {
+                    return Result::Err(ErrorCode::SyntaxException(
+                        "Subquery must return only one column",
+                    ))
+                }
+            };
+
+            let value_rex = self.sql_to_rex(value_expr, &inner_schema, Some(inner))?;
+            aggregated |= !find_aggregate_exprs(&[value_rex.clone()]).is_empty();
+            if kind == SubqueryKind::Scalar {
+                if !aggregated {
+                    return Result::Err(ErrorCode::UnImplement(
+                        "Correlated scalar subquery without aggregation is not yet implemented",
+                    ));
+                }
+
+                // The subquery without GROUP BY has a value even if no row is correlated, which
+                // is the value over the empty group, e.g. `count(*) + 1` is 1 rather than NULL.
+                if inner.group_by.is_empty() && inner.having.is_none() {
+                    empty_value = Self::empty_group_value(&value_rex)?;
+                }
+            }
+
+            select
+                .projection
+                .push(sqlparser::ast::SelectItem::ExprWithAlias {
+                    expr: value_expr.clone(),
+                    alias: Ident::new(name.clone()),
+                });
+            value = Some(name);
+        }
+
+        for (key, alias) in inner_keys.iter().zip(keys.iter()) {
+            select
+                .projection
+                .push(sqlparser::ast::SelectItem::ExprWithAlias {
+                    expr: key.clone(),
+                    alias: Ident::new(alias.clone()),
+                });
+        }
+
+        if aggregated {
+            select.group_by.extend(inner_keys);
+        }
+
+        let plan = self.select_input_to_plan(&inner_plan, &select, &None, &None, &[])?;
+        Ok(Some(CorrelatedSubquery {
+            plan,
+            value,
+            empty_value,
+            keys,
+            outer_keys,
+        }))
+    }
+
+    /// The value of the aggregated expression over the empty group, where COUNT is 0 and the
+    /// other aggregate functions are NULL. Returns None if it's NULL.
+    fn empty_group_value(expr: &Expression) -> Result<Option<Expression>> {
+        let value = clone_with_replacement(expr, &|expr| match expr {
+            Expression::AggregateFunction { op, .. } => match op.eq_ignore_ascii_case("count") {
+                true => Ok(Some(lit(0u64))),
+                false => Ok(Some(Expression::create_literal(DataValue::Null))),
+            },
+            Expression::Column(column) => Err(ErrorCode::UnImplement(format!(
+                "Column {} out of aggregate functions in correlated subquery is unsupported",
+                column
+            ))),
+            _ => Ok(None),
+        })?;
+
+        match value {
+            Expression::Literal {
+                value: DataValue::Null,
+                ..
+            } => Ok(None),
+            value => Ok(Some(value)),
+        }
+    }
+
+    /// Join the decorrelated subquery to the plan on the correlated columns,
+    /// and on the value of the subquery for `IN`.
+    fn join_subquery(
+        &self,
+        plan: &PlanNode,
+        join_type: JoinType,
+        in_expr: Option<&sqlparser::ast::Expr>,
+        subquery: CorrelatedSubquery,
+        outer: &sqlparser::ast::Select,
+    ) -> Result<PlanNode> {
+        let schema = plan.schema();
+        let mut left_keys = vec![];
+        let mut right_keys = vec![];
+        if let (Some(in_expr), Some(value)) = (in_expr, &subquery.value) {
+            left_keys.push(self.sql_to_rex(in_expr, &schema, Some(outer))?);
+            right_keys.push(Expression::Column(value.clone()));
+        }
+        for (outer_key, key) in subquery.outer_keys.iter().zip(subquery.keys.iter()) {
+            left_keys.push(self.sql_to_rex(outer_key, &schema, Some(outer))?);
+            right_keys.push(Expression::Column(key.clone()));
+        }
+
+        let subquery_schema = subquery.plan.schema();
+        Self::coerce_join_keys(&mut left_keys, &mut right_keys, &schema, &subquery_schema)?;
+        let plan = PlanBuilder::from(plan)
+            .join(join_type, &left_keys, &right_keys, &subquery.plan)?
+            .build()?;
+
+        // The keys of the subquery are NULL only for the outer rows without correlated rows.
+        match (subquery.value, subquery.empty_value) {
+            (Some(value), Some(empty_value)) => {
+                let unmatched =
+                    Expression::create_scalar_function("isnull", vec![Expression::Column(
+                        subquery.keys[0].clone(),
+                    )]);
+                let exprs = plan
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| match field.name() == &value {
+                        true => Expression::create_scalar_function("if", vec![
+                            unmatched.clone(),
+                            empty_value.clone(),
+                            Expression::Column(value.clone()),
+                        ])
+                        .alias(&value),
+                        false => Expression::Column(field.name().clone()),
+                    })
+                    .collect::<Vec<_>>();
+                PlanBuilder::from(&plan).project(&exprs)?.build()
+            }
+            _ => Ok(plan),
+        }
+    }
+
+    fn split_sql_conjunctions(
+        expr: sqlparser::ast::Expr,
+        conjunctions: &mut Vec<sqlparser::ast::Expr>,
+    ) {
+        match expr {
+            sqlparser::ast::Expr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => {
+                Self::split_sql_conjunctions(*left, conjunctions);
+                Self::split_sql_conjunctions(*right, conjunctions);
+            }
+            other => conjunctions.push(other),
+        }
+    }
+
+    fn conjoin_sql_exprs(exprs: Vec<sqlparser::ast::Expr>) -> Option<sqlparser::ast::Expr> {
+        exprs
+            .into_iter()
+            .reduce(|left, right| sqlparser::ast::Expr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            })
+    }
+
+    fn create_relation(&self, relation: &sqlparser::ast::TableFactor) -> Result<PlanNode> {
+        match relation {
+            TableFactor::Table { name, args, .. } => {
+                if let (true, [ident]) = (args.is_empty(), name.0.as_slice()) {
+                    if let Some(plan) = self.cte_scan_to_plan(&ident.value) {
+                        return Ok(plan);
+                    }
+                }
+
+                let mut db_name = self.ctx.get_current_database();
+                let mut table_name = name.to_string();
+                if name.0.len() == 2 {
+                    db_name = name.0[0].to_string();
+                    table_name = name.0[1].to_string();
+                }
+                let table;
+
+                // time travel, e.g. `SELECT * FROM t AT (SNAPSHOT => '...')`
+                if let Some(point) = self.navigation_point(args)? {
+                    table = self
+                        .ctx
+                        .get_table(&db_name, &table_name)?
+                        .navigate_to(self.ctx.clone(), &point)?;
+                    let source_plan = table.read_plan(self.ctx.clone(), None)?;
+                    return Ok(PlanNode::ReadSource(source_plan));
+                }
+
+                // only table functions has table args
+                if !args.is_empty() {
+                    if name.0.len() >= 2 {
+                        return Result::Err(ErrorCode::BadArguments(
+                            "Currently table can't have arguments",
+                        ));
+                    }
+
+                    let empty_schema = Arc::new(DataSchema::empty());
+                    let table_args = args.iter().try_fold(
+                        Vec::with_capacity(args.len()),
+                        |mut acc, f_arg| {
+                            let item = match f_arg {
+                                FunctionArg::Named { arg, .. } => {
+                                    self.sql_to_rex(arg, empty_schema.as_ref(), None)?
+                                }
+                                FunctionArg::Unnamed(arg) => {
+                                    self.sql_to_rex(arg, empty_schema.as_ref(), None)?
+                                }
+                            };
+                            acc.push(item);
+                            Ok::<_, ErrorCode>(acc)
+                        },
+                    )?;
+
+                    let table_func = self
+                        .ctx
+                        .get_catalog()
+                        .get_table_function(&table_name, Some(table_args))?;
+                    table = table_func.as_table();
+                } else {
+                    table = self.ctx.get_table(&db_name, &table_name)?;
+                }
+
+                // TODO: Move ReadSourcePlan to SelectInterpreter
+                let source_plan = table.read_plan(self.ctx.clone(), None)?;
 
                 let dummy_read_plan = PlanNode::ReadSource(source_plan);
                 Ok(dummy_read_plan)
             }
             TableFactor::Derived { subquery, .. } => self.query_to_plan(subquery),
-            TableFactor::NestedJoin(table_with_joins) => {
-                self.plan_table_with_joins(table_with_joins)
-            }
+            TableFactor::NestedJoin(table_with_joins) => self
+                .plan_table_with_joins(table_with_joins, None)
+                .map(|(plan, _)| plan),
             TableFactor::TableFunction { .. } => {
                 Result::Err(ErrorCode::UnImplement("Unsupported table function"))
             }
         }
     }
+
+    fn navigation_point(&self, args: &[FunctionArg]) -> Result<Option<NavigationPoint>> {
+        let (name, value) = match args {
+            [FunctionArg::Named {
+                name,
+                arg: sqlparser::ast::Expr::Value(sqlparser::ast::Value::SingleQuotedString(value)),
+            }] => (name.value.to_uppercase(), value),
+            _ => return Ok(None),
+        };
+
+        match name.as_str() {
+            "SNAPSHOT" => Ok(Some(NavigationPoint::SnapshotID(value.clone()))),
+            "TIMESTAMP" => {
+                let time_point = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+                    .or_else(|_| {
+                        NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|d| d.and_hms(0, 0, 0))
+                    })
+                    .map_err(|e| {
+                        ErrorCode::BadArguments(format!(
+                            "Cannot parse '{}' as the timestamp to travel to: {}",
+                            value, e
+                        ))
+                    })?;
+
+                // The timestamp has no timezone, it's in the timezone of the session
+                let timezone = parse_timezone(&Some(self.ctx.get_settings().get_timezone()?))?;
+                let time_point = timezone
+                    .from_local_datetime(&time_point)
+                    .earliest()
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "The timestamp '{}' to travel to does not exist in timezone {}",
+                            value, timezone
+                        ))
+                    })?;
+                Ok(Some(NavigationPoint::TimePoint(
+                    time_point.with_timezone(&Utc),
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn process_compound_ident(
         &self,
         ids: &[Ident],
+        schema: &DataSchema,
         select: Option<&sqlparser::ast::Select>,
     ) -> Result<Expression> {
         let mut var_names = vec![];
@@ -826,64 +2370,45 @@ impl PlanParser {
             )));
         }
 
+        // The column was renamed to `table.column` as it exists on both sides of a join.
         let table_name = &var_names[0];
-        let from = &select.unwrap().from;
-        let obj_table_name = ObjectName(vec![Ident::new(table_name)]);
+        let qualified_name = format!("{}.{}", table_name, var_names[1]);
+        if schema.field_with_name(&qualified_name).is_ok() {
+            return Ok(Expression::Column(qualified_name));
+        }
 
+        let from = &select.unwrap().from;
         match from.len() {
             0 => Err(ErrorCode::SyntaxException(
                 "Missing table in the select clause",
             )),
-            1 => match &from[0].relation {
-                TableFactor::Table {
-                    name,
-                    alias,
-                    args: _,
-                    with_hints: _,
-                } => {
-                    if *name == obj_table_name {
-                        return Ok(Expression::Column(var_names.pop().unwrap()));
-                    }
-                    match alias {
-                        Some(a) => {
-                            if a.name == ids[0] {
-                                Ok(Expression::Column(var_names.pop().unwrap()))
-                            } else {
-                                Err(ErrorCode::UnknownTable(format!(
-                                    "Unknown Table '{:?}'",
-                                    &table_name,
-                                )))
-                            }
+            1 => match Self::find_qualified_relation(&from[0], table_name) {
+                None => Err(ErrorCode::UnknownTable(format!(
+                    "Unknown Table '{:?}'",
+                    &table_name,
+                ))),
+                Some(relation) => {
+                    // The column of the relation is renamed to the alias qualified one.
+                    let column_name = &var_names[1];
+                    if let Some(qualifier) = Self::relation_qualifier(relation) {
+                        let qualified_name = format!("{}.{}", qualifier, column_name);
+                        if schema.field_with_name(&qualified_name).is_ok() {
+                            return Ok(Expression::Column(qualified_name));
                         }
-                        None => Err(ErrorCode::UnknownTable(format!(
-                            "Unknown Table '{:?}'",
-                            &table_name,
+                    }
+
+                    // The column may only be resolved to the relation it's qualified with,
+                    // not to a column of the same name of another relation in the join.
+                    match self.relation_schema(relation)?.field_with_name(column_name) {
+                        Ok(_) => Ok(Expression::Column(var_names.pop().unwrap())),
+                        Err(_) => Err(ErrorCode::UnknownColumn(format!(
+                            "Unknown column '{}.{}'",
+                            table_name, column_name
                         ))),
                     }
                 }
-                TableFactor::Derived {
-                    lateral: _,
-                    subquery: _,
-                    alias,
-                } => match alias {
-                    Some(a) => {
-                        if a.name == ids[0] {
-                            Ok(Expression::Column(var_names.pop().unwrap()))
-                        } else {
-                            Err(ErrorCode::UnknownTable(format!(
-                                "Unknown Table '{:?}'",
-                                &table_name,
-                            )))
-                        }
-                    }
-                    None => Err(ErrorCode::UnknownTable(format!(
-                        "Unknown Table '{:?}'",
-                        &table_name,
-                    ))),
-                },
-                _ => Err(ErrorCode::SyntaxException("Cannot support Nested Join now")),
             },
-            _ => Err(ErrorCode::SyntaxException("Cannot support JOIN clause")),
+            _ => Err(ErrorCode::SyntaxException("Cannot SELECT multiple tables")),
         }
     }
 
@@ -1025,9 +2550,14 @@ impl PlanParser {
                 })
             }
             sqlparser::ast::Expr::CompoundIdentifier(ids) => {
-                self.process_compound_ident(ids.as_slice(), select)
+                self.process_compound_ident(ids.as_slice(), schema, select)
             }
             sqlparser::ast::Expr::Function(e) => {
+                // toDateTime64, toTimeZone and convert_tz are the casts to the datetime types
+                if let Some(expr) = self.datetime_function_to_rex(e, schema, select)? {
+                    return Ok(expr);
+                }
+
                 let mut args = Vec::with_capacity(e.args.len());
 
                 // 1. Get the args from context by function name. such as SELECT database()
@@ -1052,6 +2582,15 @@ impl PlanParser {
                     }
                 }
 
+                // 3. Get the session timezone for the timezone aware functions. such as SELECT toHour(now())
+                let tz_args = ContextFunction::build_timezone_args(
+                    e.name.to_string().as_str(),
+                    &args,
+                    schema,
+                    self.ctx.clone(),
+                )?;
+                args.extend(tz_args);
+
                 let op = e.name.to_string();
                 if AggregateFunctionFactory::instance().check(&op) {
                     let args = match op.to_lowercase().as_str() {
@@ -1147,6 +2686,67 @@ impl PlanParser {
                         .or(expression.gt(high_expression))),
                 }
             }
+            sqlparser::ast::Expr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let mut args = Vec::with_capacity(list.len() + 1);
+                args.push(self.sql_to_rex(expr, schema, select)?);
+                for item in list {
+                    args.push(self.sql_to_rex(item, schema, select)?);
+                }
+
+                let op = match negated {
+                    true => "not in",
+                    false => "in",
+                };
+                Ok(Expression::create_scalar_function(op, args))
+            }
+            sqlparser::ast::Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => {
+                let args = vec![
+                    self.sql_to_rex(expr, schema, select)?,
+                    self.subquery_to_rex(subquery)?,
+                ];
+
+                let op = match negated {
+                    true => "not in",
+                    false => "in",
+                };
+                Ok(Expression::create_scalar_function(op, args))
+            }
+            sqlparser::ast::Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                let operand = match operand {
+                    Some(operand) => Some(self.sql_to_rex(operand, schema, select)?),
+                    None => None,
+                };
+
+                // CASE x WHEN v THEN .. is CASE WHEN x = v THEN ..
+                let mut args = Vec::with_capacity(conditions.len() * 2 + 1);
+                for (condition, result) in conditions.iter().zip(results.iter()) {
+                    let condition = self.sql_to_rex(condition, schema, select)?;
+                    args.push(match &operand {
+                        Some(operand) => operand.eq(condition),
+                        None => condition,
+                    });
+                    args.push(self.sql_to_rex(result, schema, select)?);
+                }
+
+                args.push(match else_result {
+                    Some(else_result) => self.sql_to_rex(else_result, schema, select)?,
+                    None => Expression::create_literal(DataValue::Null),
+                });
+                Ok(Expression::create_scalar_function("case", args))
+            }
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -1172,6 +2772,111 @@ impl PlanParser {
         })
     }
 
+    /// The datetime functions whose result type depends on their literal arguments are the casts:
+    /// * `toDateTime64(value[, precision[, tz]])` casts the value to DateTime64, the precision is 3 by default.
+    /// * `toTimeZone(datetime, tz)` and `convert_tz(datetime, [from_tz,] to_tz)` change the timezone
+    ///   of the datetime type and keep the instant, `convert_tz` with `from_tz` reads the local time
+    ///   of the datetime again in `from_tz` like MySQL.
+    fn datetime_function_to_rex(
+        &self,
+        e: &sqlparser::ast::Function,
+        schema: &DataSchema,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<Option<Expression>> {
+        let name = e.name.to_string();
+        let (min_args, max_args) = match name.to_lowercase().as_str() {
+            "todatetime64" => (1, 3),
+            "totimezone" => (2, 2),
+            "convert_tz" => (2, 3),
+            _ => return Ok(None),
+        };
+        if e.args.len() < min_args || e.args.len() > max_args {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expect to have [{}, {}] arguments, but got {}",
+                name,
+                min_args,
+                max_args,
+                e.args.len()
+            )));
+        }
+
+        let mut args = Vec::with_capacity(e.args.len());
+        for arg in &e.args {
+            match &arg {
+                FunctionArg::Named { arg, .. } => args.push(self.sql_to_rex(arg, schema, select)?),
+                FunctionArg::Unnamed(arg) => args.push(self.sql_to_rex(arg, schema, select)?),
+            }
+        }
+
+        let literal_of = |arg: &Expression| -> Result<DataValue> {
+            match arg {
+                Expression::Literal { value, .. } => Ok(value.clone()),
+                other => Err(ErrorCode::BadArguments(format!(
+                    "The argument {:?} of function {} must be a literal",
+                    other, name
+                ))),
+            }
+        };
+        let timezone_of = |arg: &Expression| -> Result<String> {
+            match literal_of(arg)? {
+                DataValue::String(Some(tz)) => {
+                    let tz = String::from_utf8(tz)?;
+                    parse_timezone(&Some(tz.clone()))?;
+                    Ok(tz)
+                }
+                other => Err(ErrorCode::BadArguments(format!(
+                    "The timezone of function {} must be a string, but got {:?}",
+                    name, other
+                ))),
+            }
+        };
+
+        if name.eq_ignore_ascii_case("todatetime64") {
+            let precision = match args.get(1) {
+                Some(arg) => literal_of(arg)?.as_u64()? as u32,
+                None => 3,
+            };
+            let tz = args.get(2).map(|arg| timezone_of(arg)).transpose()?;
+            check_datetime64_type(precision, &tz)?;
+            return Ok(Some(Expression::Cast {
+                expr: Box::new(args[0].clone()),
+                data_type: DataType::DateTime64(precision, tz),
+            }));
+        }
+
+        let data_type = args[0].to_data_type(&DataSchemaRefExt::create(schema.fields().clone()))?;
+        let with_timezone = |tz_arg: &Expression| -> Result<DataType> {
+            let tz = timezone_of(tz_arg)?;
+            match &data_type {
+                DataType::DateTime32(_) => Ok(DataType::DateTime32(Some(tz))),
+                DataType::DateTime64(precision, _) => {
+                    Ok(DataType::DateTime64(*precision, Some(tz)))
+                }
+                other => Err(ErrorCode::IllegalDataType(format!(
+                    "Function {} must have a DateTime type as argument, but got {}",
+                    name, other
+                ))),
+            }
+        };
+
+        let mut expr = args[0].clone();
+        if args.len() == 3 {
+            // Read the local time of the datetime again in from_tz
+            let from_type = with_timezone(&args[1])?;
+            expr = Expression::Cast {
+                expr: Box::new(Expression::Cast {
+                    expr: Box::new(expr),
+                    data_type: DataType::String,
+                }),
+                data_type: from_type,
+            };
+        }
+        Ok(Some(Expression::Cast {
+            expr: Box::new(expr),
+            data_type: with_timezone(&args[args.len() - 1])?,
+        }))
+    }
+
     pub fn set_variable_to_plan(
         &self,
         variable: &sqlparser::ast::Ident,
@@ -1182,6 +2887,10 @@ impl PlanParser {
             let variable = variable.value.clone();
             let value = match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
+                // The quoted string value is set without the quotes, such as `SET timezone = 'Asia/Shanghai'`
+                sqlparser::ast::SetVariableValue::Literal(
+                    sqlparser::ast::Value::SingleQuotedString(v),
+                ) => v.clone(),
                 sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
             };
             vars.push(VarValue { variable, value });
@@ -1354,3 +3063,100 @@ impl PlanParser {
             .and_then(|builder| builder.build())
     }
 }
+
+fn sql_expr_children(expr: &sqlparser::ast::Expr) -> Vec<&sqlparser::ast::Expr> {
+    match expr {
+        sqlparser::ast::Expr::BinaryOp { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+        sqlparser::ast::Expr::UnaryOp { expr, .. }
+        | sqlparser::ast::Expr::Nested(expr)
+        | sqlparser::ast::Expr::IsNull(expr)
+        | sqlparser::ast::Expr::IsNotNull(expr)
+        | sqlparser::ast::Expr::Cast { expr, .. }
+        | sqlparser::ast::Expr::InSubquery { expr, .. } => vec![expr.as_ref()],
+        sqlparser::ast::Expr::Between {
+            expr, low, high, ..
+        } => vec![expr.as_ref(), low.as_ref(), high.as_ref()],
+        sqlparser::ast::Expr::InList { expr, list, .. } => {
+            std::iter::once(expr.as_ref()).chain(list.iter()).collect()
+        }
+        sqlparser::ast::Expr::Tuple(exprs) => exprs.iter().collect(),
+        sqlparser::ast::Expr::Function(function) => function
+            .args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArg::Named { arg, .. } => arg,
+                FunctionArg::Unnamed(arg) => arg,
+            })
+            .collect(),
+        sqlparser::ast::Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter()
+            .map(|expr| expr.as_ref())
+            .chain(conditions.iter())
+            .chain(results.iter())
+            .chain(else_result.iter().map(|expr| expr.as_ref()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn sql_expr_children_mut(expr: &mut sqlparser::ast::Expr) -> Vec<&mut sqlparser::ast::Expr> {
+    match expr {
+        sqlparser::ast::Expr::BinaryOp { left, right, .. } => vec![left.as_mut(), right.as_mut()],
+        sqlparser::ast::Expr::UnaryOp { expr, .. }
+        | sqlparser::ast::Expr::Nested(expr)
+        | sqlparser::ast::Expr::IsNull(expr)
+        | sqlparser::ast::Expr::IsNotNull(expr)
+        | sqlparser::ast::Expr::Cast { expr, .. }
+        | sqlparser::ast::Expr::InSubquery { expr, .. } => vec![expr.as_mut()],
+        sqlparser::ast::Expr::Between {
+            expr, low, high, ..
+        } => vec![expr.as_mut(), low.as_mut(), high.as_mut()],
+        sqlparser::ast::Expr::InList { expr, list, .. } => std::iter::once(expr.as_mut())
+            .chain(list.iter_mut())
+            .collect(),
+        sqlparser::ast::Expr::Tuple(exprs) => exprs.iter_mut().collect(),
+        sqlparser::ast::Expr::Function(function) => function
+            .args
+            .iter_mut()
+            .map(|arg| match arg {
+                FunctionArg::Named { arg, .. } => arg,
+                FunctionArg::Unnamed(arg) => arg,
+            })
+            .collect(),
+        sqlparser::ast::Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter_mut()
+            .map(|expr| expr.as_mut())
+            .chain(conditions.iter_mut())
+            .chain(results.iter_mut())
+            .chain(else_result.iter_mut().map(|expr| expr.as_mut()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Collect the column identifiers of the expression, except the ones in the subqueries.
+fn collect_sql_identifiers<'a>(
+    expr: &'a sqlparser::ast::Expr,
+    identifiers: &mut Vec<&'a sqlparser::ast::Expr>,
+) {
+    match expr {
+        sqlparser::ast::Expr::Identifier(_) | sqlparser::ast::Expr::CompoundIdentifier(_) => {
+            identifiers.push(expr)
+        }
+        _ => {
+            for child in sql_expr_children(expr) {
+                collect_sql_identifiers(child, identifiers);
+            }
+        }
+    }
+}
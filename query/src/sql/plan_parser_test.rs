@@ -177,10 +177,92 @@ fn test_plan_parser() -> Result<()> {
         },
 
         Test {
-            name: "unimplemented-cte",
-            sql: "with t as ( select sum(number) n from numbers_mt(1000) )select * from t",
+            name: "select-join",
+            sql: "select a.number, b.number from numbers(10) a join numbers(10) b on a.number = b.number",
+            expect: "\
+            Projection: a.number:UInt64, b.number:UInt64\
+            \n  Join: type=INNER, left_keys=[a.number], right_keys=[b.number]\
+            \n    Projection: number as a.number:UInt64\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n    Projection: number as b.number:UInt64\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-join-not-unique-alias",
+            sql: "select * from numbers(10) a join numbers(10) a on a.number = a.number",
+            expect: "",
+            error: "Code: 5, displayText = Not unique table/alias: 'a'.",
+        },
+        Test {
+            name: "select-join-qualified-column-of-other-relation",
+            sql: "select a.x from numbers(10) a join (select number as x from numbers(10)) b on a.number = b.x",
+            expect: "",
+            error: "Code: 60, displayText = Unknown column 'a.x'.",
+        },
+        Test {
+            name: "cte-inline",
+            sql: "with t as (select number from numbers(10)) select * from t",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "cte-scan",
+            sql: "with t as (select number from numbers(10)) select a.number from t a join t b on a.number = b.number",
+            expect: "\
+            Projection: a.number:UInt64\
+            \n  Join: type=INNER, left_keys=[a.number], right_keys=[b.number]\
+            \n    Projection: number as a.number:UInt64\
+            \n      CteScan: name=t_0\
+            \n        Projection: number:UInt64\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n    Projection: number as b.number:UInt64\
+            \n      CteScan: name=t_0\
+            \n        Projection: number:UInt64\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "cte-nested-inline",
+            sql: "with a as (with b as (select number from numbers(10)) select number from b) select * from a",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Projection: number:UInt64\
+            \n    Projection: number:UInt64\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "cte-duplicate-name",
+            sql: "with t as (select 1), t as (select 2) select * from t",
+            expect: "",
+            error: "Code: 5, displayText = Duplicate CTE name 't'.",
+        },
+        Test {
+            name: "select-union-all",
+            sql: "select number from numbers(10) union all select number from numbers(10)",
+            expect: "\
+            Union\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-union-mismatched-columns",
+            sql: "select number from numbers(10) union select number, number + 1 from numbers(10)",
             expect: "",
-            error: "Code: 2, displayText = CTE is not yet implement.",
+            error: "Code: 6, displayText = Union inputs must have the same number of columns, but got 1 and 2.",
+        },
+        Test {
+            name: "select-intersect-all",
+            sql: "select number from numbers(10) intersect all select number from numbers(10)",
+            expect: "",
+            error: "Code: 2, displayText = INTERSECT ALL is not yet implemented.",
         },
         Test {
             name: "kleene-logic-null",
@@ -200,6 +282,87 @@ fn test_plan_parser() -> Result<()> {
             \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
             error: "",
         },
+        Test {
+            name: "select-prewhere",
+            sql: "select * from numbers(10) prewhere number > 1 where number < 5",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: ((number > 1) AND (number < 5))\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [prewhere: [(number > 1)]]",
+            error: "",
+        },
+        Test {
+            name: "select-prewhere-on-join",
+            sql: "select * from numbers(10) a join numbers(10) b on a.number = b.number prewhere a.number > 1",
+            expect: "",
+            error: "Code: 5, displayText = PREWHERE is only supported when selecting from a single table.",
+        },
+        Test {
+            name: "select-where-in-list",
+            sql: "select * from numbers(10) where number in (1, 2)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: in(number, 1, 2)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-where-not-in-list",
+            sql: "select * from numbers(10) where number not in (1, 2)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: not in(number, 1, 2)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-where-case-when",
+            sql: "select * from numbers(10) where case when number > 1 then true else false end",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: case((number > 1), true, false)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-where-case-operand-without-else",
+            sql: "select * from numbers(10) where case number when 1 then true end",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: case((number = 1), true, NULL)\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "delete-with-selection",
+            sql: "delete from system.one where dummy > 1",
+            expect: "Delete from system.one, selection: (dummy > 1)",
+            error: "",
+        },
+        Test {
+            name: "delete-all",
+            sql: "delete from system.one",
+            expect: "Delete from system.one, selection: all",
+            error: "",
+        },
+        Test {
+            name: "delete-unknown-column",
+            sql: "delete from system.one where c > 1",
+            expect: "",
+            error: "Code: 6, displayText = Unable to get field named \"c\". Valid fields: [\"dummy\"].",
+        },
+        Test {
+            name: "update-with-selection",
+            sql: "update system.one set dummy = dummy + 1 where dummy > 1",
+            expect: "Update system.one set dummy = (dummy + 1), selection: (dummy > 1)",
+            error: "",
+        },
+        Test {
+            name: "update-column-twice",
+            sql: "update system.one set dummy = 1, dummy = 2",
+            expect: "",
+            error: "Code: 5, displayText = Column dummy is updated more than once.",
+        },
         Test {
             name: "show-metrics",
             sql: "show metrics",
@@ -207,6 +370,53 @@ fn test_plan_parser() -> Result<()> {
             Projection: metric:String, kind:String, labels:String, value:String\
             \n  ReadDataSource: scan partitions: [1], scan schema: [metric:String, kind:String, labels:String, value:String], statistics: [read_rows: 0, read_bytes: 0]",
             error: "",
+        },
+        Test {
+            name: "select-in-subquery",
+            sql: "select number from numbers(10) where number in (select number from numbers(10))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Filter: in(number, subquery(_subquery_1))\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-correlated-exists",
+            sql: "select number from numbers(10) a where exists (select * from numbers(10) b where b.number = a.number and b.number > 5)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Join: type=SEMI, left_keys=[number], right_keys=[_subquery_2_key_0]\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n    Projection: number as _subquery_2_key_0:UInt64\
+            \n      Filter: (number > 5)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-correlated-not-exists",
+            sql: "select number from numbers(10) a where not exists (select * from numbers(10) b where a.number = b.number)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Join: type=ANTI, left_keys=[number], right_keys=[_subquery_3_key_0]\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n    Projection: number as _subquery_3_key_0:UInt64\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "select-correlated-non-equi-condition",
+            sql: "select number from numbers(10) a where exists (select * from numbers(10) b where b.number > a.number)",
+            expect: "",
+            error: "Code: 2, displayText = Correlated condition b.number > a.number of subquery is not yet implemented.",
+        },
+        Test {
+            name: "select-correlated-not-in",
+            sql: "select number from numbers(10) a where number not in (select number from numbers(10) b where b.number = a.number)",
+            expect: "",
+            error: "Code: 2, displayText = Correlated NOT IN subquery is not yet implemented.",
         }
     ];
 
@@ -31,7 +31,12 @@ impl SQLCommon {
             SQLDataType::Varchar(_) => Ok(DataType::String),
             SQLDataType::String => Ok(DataType::String),
             SQLDataType::Text => Ok(DataType::String),
-            SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+            SQLDataType::Decimal(precision, scale) => {
+                let precision = precision.unwrap_or(10) as usize;
+                let scale = scale.unwrap_or(0) as usize;
+                check_decimal_type(precision, scale)?;
+                Ok(DataType::Decimal(precision, scale))
+            }
             SQLDataType::Float(_) => Ok(DataType::Float32),
             SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
             SQLDataType::Boolean => Ok(DataType::Boolean),
@@ -58,8 +63,11 @@ impl SQLCommon {
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    // The milliseconds precision by default, others are casted by toDateTime64
+                    "DATETIME64" => Ok(DataType::DateTime64(3, None)),
                     "SIGNED" => Ok(DataType::Int64),
                     "UNSIGNED" => Ok(DataType::UInt64),
+                    "VARIANT" | "JSON" => Ok(DataType::Variant),
 
                     _ => Result::Err(ErrorCode::IllegalDataType(format!(
                         "The SQL data type {:?} is not implemented",
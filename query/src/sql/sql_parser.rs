@@ -51,6 +51,7 @@ use crate::sql::DfExplain;
 use crate::sql::DfGrantStatement;
 use crate::sql::DfHint;
 use crate::sql::DfKillStatement;
+use crate::sql::DfOptimizeTable;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
 use crate::sql::DfShowMetrics;
@@ -60,7 +61,9 @@ use crate::sql::DfShowTables;
 use crate::sql::DfShowUsers;
 use crate::sql::DfStatement;
 use crate::sql::DfTruncateTable;
+use crate::sql::DfUpdate;
 use crate::sql::DfUseDatabase;
+use crate::sql::DfVacuumTable;
 
 // Use `Parser::expected` instead, if possible
 macro_rules! parser_err {
@@ -84,7 +87,10 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = rewrite_time_travel_clause(tokenizer.tokenize()?);
+        let tokens = rewrite_regexp_operators(tokens);
+        let tokens = rewrite_variant_paths(tokens);
+        let tokens = rewrite_prewhere_clause(tokens);
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
@@ -223,10 +229,16 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_grant()
                     }
+                    Keyword::UPDATE => {
+                        self.parser.next_token();
+                        self.parse_update()
+                    }
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
+                        "OPTIMIZE" => self.parse_optimize(),
+                        "VACUUM" => self.parse_vacuum(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => {
@@ -666,6 +678,66 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // Parse 'OPTIMIZE TABLE t [COMPACT]'.
+    fn parse_optimize(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("OPTIMIZE") {
+            return self.expected("Must OPTIMIZE", self.parser.peek_token());
+        }
+
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+        // Compaction is the only optimization for now, so COMPACT is optional.
+        self.consume_token("COMPACT");
+        Ok(DfStatement::OptimizeTable(DfOptimizeTable { name }))
+    }
+
+    // Parse 'VACUUM TABLE t [RETAIN n HOURS]'.
+    fn parse_vacuum(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("VACUUM") {
+            return self.expected("Must VACUUM", self.parser.peek_token());
+        }
+
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+        let retain_hours = match self.consume_token("RETAIN") {
+            true => {
+                let hours = self.parser.parse_literal_uint()?;
+                if !self.consume_token("HOURS") {
+                    return self.expected("HOURS", self.parser.peek_token());
+                }
+                Some(hours)
+            }
+            false => None,
+        };
+
+        Ok(DfStatement::VacuumTable(DfVacuumTable {
+            name,
+            retain_hours,
+        }))
+    }
+
+    // Parse 'UPDATE t SET c1 = expr1 [, c2 = expr2 ...] [WHERE expr]'.
+    fn parse_update(&mut self) -> Result<DfStatement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::SET)?;
+        let assignments = self.parser.parse_comma_separated(|parser| {
+            let column = parser.parse_identifier()?;
+            parser.expect_token(&Token::Eq)?;
+            let value = parser.parse_expr()?;
+            Ok((column, value))
+        })?;
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Update(DfUpdate {
+            name,
+            assignments,
+            selection,
+        }))
+    }
+
     fn parse_privileges(&mut self) -> Result<UserPrivilege, ParserError> {
         let mut privileges = UserPrivilege::empty();
         loop {
@@ -758,3 +830,300 @@ impl<'a> DfParser<'a> {
         }
     }
 }
+
+/// Rewrite `t AT (SNAPSHOT => '...')` and `t AT (TIMESTAMP => '...')` into `t (SNAPSHOT => '...')`,
+/// the time travel point then arrives at the planner as a named table argument.
+fn rewrite_time_travel_clause(tokens: Vec<Token>) -> Vec<Token> {
+    let is_travel_point = |rest: &[Token]| {
+        let mut rest = rest
+            .iter()
+            .filter(|token| !matches!(token, Token::Whitespace(_)));
+        matches!(
+            (rest.next(), rest.next(), rest.next()),
+            (Some(Token::LParen), Some(Token::Word(w)), Some(Token::RArrow))
+                if matches!(w.value.to_uppercase().as_str(), "SNAPSHOT" | "TIMESTAMP")
+        )
+    };
+
+    let mut rewritten = Vec::with_capacity(tokens.len());
+    for (idx, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Word(w)
+                if w.value.to_uppercase() == "AT" && is_travel_point(&tokens[idx + 1..]) => {}
+            _ => rewritten.push(token.clone()),
+        }
+    }
+    rewritten
+}
+
+/// Rewrite the MySQL operators `x REGEXP p` and `x RLIKE p` into `x ~ p`, and their negations
+/// `x NOT REGEXP p` and `x NOT RLIKE p` into `x !~ p`. The words are operators only between two
+/// expressions, so a `regexp(...)` call, a column or an alias named `rlike` is left untouched.
+fn rewrite_regexp_operators(tokens: Vec<Token>) -> Vec<Token> {
+    let ends_expression = |token: &Token| match token {
+        Token::Word(w) if w.quote_style.is_some() => true,
+        Token::Word(w) => !matches!(
+            w.value.to_uppercase().as_str(),
+            "SELECT"
+                | "DISTINCT"
+                | "FROM"
+                | "WHERE"
+                | "PREWHERE"
+                | "HAVING"
+                | "ON"
+                | "BY"
+                | "AS"
+                | "AND"
+                | "OR"
+                | "NOT"
+                | "CASE"
+                | "WHEN"
+                | "THEN"
+                | "ELSE"
+                | "SET"
+                | "IN"
+                | "IS"
+                | "LIKE"
+                | "BETWEEN"
+                | "REGEXP"
+                | "RLIKE"
+        ),
+        Token::Number(..) | Token::SingleQuotedString(_) | Token::RParen | Token::RBracket => true,
+        _ => false,
+    };
+
+    // The word followed by what can't start a pattern is an alias, e.g. `SELECT a rlike FROM t`.
+    let starts_pattern = |tokens: &[Token]| {
+        let next = tokens
+            .iter()
+            .find(|token| !matches!(token, Token::Whitespace(_)));
+        match next {
+            None | Some(Token::EOF | Token::SemiColon | Token::Comma | Token::RParen) => false,
+            Some(Token::Word(w)) if w.quote_style.is_none() => !matches!(
+                w.value.to_uppercase().as_str(),
+                "FROM"
+                    | "WHERE"
+                    | "PREWHERE"
+                    | "GROUP"
+                    | "HAVING"
+                    | "ORDER"
+                    | "LIMIT"
+                    | "OFFSET"
+                    | "UNION"
+                    | "JOIN"
+                    | "INNER"
+                    | "LEFT"
+                    | "RIGHT"
+                    | "FULL"
+                    | "CROSS"
+                    | "ON"
+                    | "USING"
+                    | "FORMAT"
+                    | "SETTINGS"
+            ),
+            _ => true,
+        }
+    };
+
+    let is_not_keyword =
+        |token: &Token| matches!(token, Token::Word(w) if w.keyword == Keyword::NOT);
+
+    let mut rewritten: Vec<Token> = Vec::with_capacity(tokens.len());
+    for (idx, token) in tokens.iter().enumerate() {
+        let is_regexp_word = matches!(
+            token,
+            Token::Word(w) if w.quote_style.is_none()
+                && matches!(w.value.to_uppercase().as_str(), "REGEXP" | "RLIKE")
+        );
+        if !is_regexp_word || !starts_pattern(&tokens[idx + 1..]) {
+            rewritten.push(token.clone());
+            continue;
+        }
+
+        // The operand before the operator, skipping the NOT of the negation.
+        let prev = rewritten
+            .iter()
+            .rposition(|token| !matches!(token, Token::Whitespace(_)));
+        let negated = matches!(prev, Some(prev) if is_not_keyword(&rewritten[prev]));
+        let operand = match (prev, negated) {
+            (Some(prev), true) => rewritten[..prev]
+                .iter()
+                .rposition(|token| !matches!(token, Token::Whitespace(_))),
+            _ => prev,
+        };
+
+        match (prev, operand) {
+            (Some(prev), Some(operand)) if ends_expression(&rewritten[operand]) => {
+                if negated {
+                    // Drop the NOT, along with the whitespaces after it.
+                    rewritten.truncate(prev);
+                    rewritten.push(Token::ExclamationMarkTilde);
+                } else {
+                    rewritten.push(Token::Tilde);
+                }
+            }
+            _ => rewritten.push(token.clone()),
+        }
+    }
+    rewritten
+}
+
+/// Rewrite the variant path access `v:a.b[0]` into `get(v, 'a.b[0]')`, the left side must be a
+/// column name (optionally qualified), and no whitespace is allowed around the colon.
+fn rewrite_variant_paths(tokens: Vec<Token>) -> Vec<Token> {
+    let mut rewritten: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let column_start = match &tokens[idx] {
+            Token::Colon => column_start(&rewritten),
+            _ => None,
+        };
+        let path = match column_start {
+            Some(_) => variant_path(&tokens[idx + 1..]),
+            None => None,
+        };
+
+        match (column_start, path) {
+            (Some(start), Some((path, consumed))) => {
+                let column = rewritten.split_off(start);
+                rewritten.push(Token::make_word("get", None));
+                rewritten.push(Token::LParen);
+                rewritten.extend(column);
+                rewritten.push(Token::Comma);
+                rewritten.push(Token::Whitespace(Whitespace::Space));
+                rewritten.push(Token::SingleQuotedString(path));
+                rewritten.push(Token::RParen);
+                idx += consumed + 1;
+            }
+            _ => {
+                rewritten.push(tokens[idx].clone());
+                idx += 1;
+            }
+        }
+    }
+    rewritten
+}
+
+/// Rewrite `PREWHERE p [WHERE w]` into `WHERE prewhere(p) [AND (w)]`, the planner then picks the
+/// `prewhere(...)` conjunct as the prewhere of the table read.
+fn rewrite_prewhere_clause(tokens: Vec<Token>) -> Vec<Token> {
+    let is_word = |token: &Token, word: &str| matches!(token, Token::Word(w) if w.quote_style.is_none() && w.value.to_uppercase() == word);
+
+    let mut rewritten: Vec<Token> = Vec::with_capacity(tokens.len() + 8);
+    let mut idx = 0;
+    while idx < tokens.len() {
+        if !is_word(&tokens[idx], "PREWHERE") {
+            rewritten.push(tokens[idx].clone());
+            idx += 1;
+            continue;
+        }
+
+        let prewhere_end = idx + 1 + clause_expr_len(&tokens[idx + 1..]);
+        rewritten.push(Token::make_keyword("WHERE"));
+        rewritten.push(Token::Whitespace(Whitespace::Space));
+        rewritten.push(Token::make_word("prewhere", None));
+        rewritten.push(Token::LParen);
+        rewritten.extend_from_slice(&tokens[idx + 1..prewhere_end]);
+        rewritten.push(Token::RParen);
+        idx = prewhere_end;
+
+        match tokens.get(idx) {
+            Some(token) if is_word(token, "WHERE") => {
+                let where_end = idx + 1 + clause_expr_len(&tokens[idx + 1..]);
+                rewritten.push(Token::Whitespace(Whitespace::Space));
+                rewritten.push(Token::make_keyword("AND"));
+                rewritten.push(Token::Whitespace(Whitespace::Space));
+                rewritten.push(Token::LParen);
+                rewritten.extend_from_slice(&tokens[idx + 1..where_end]);
+                rewritten.push(Token::RParen);
+                rewritten.push(Token::Whitespace(Whitespace::Space));
+                idx = where_end;
+            }
+            _ => rewritten.push(Token::Whitespace(Whitespace::Space)),
+        }
+    }
+    rewritten
+}
+
+/// Returns the number of tokens of the clause expression at the beginning of the tokens, the
+/// expression ends at the next clause keyword or at the end of the enclosing query.
+fn clause_expr_len(tokens: &[Token]) -> usize {
+    let mut depth = 0;
+    for (idx, token) in tokens.iter().enumerate() {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 0 => return idx,
+            Token::RParen => depth -= 1,
+            Token::SemiColon | Token::EOF if depth == 0 => return idx,
+            Token::Word(w) if depth == 0 && w.quote_style.is_none() => {
+                if matches!(
+                    w.value.to_uppercase().as_str(),
+                    "WHERE"
+                        | "GROUP"
+                        | "HAVING"
+                        | "ORDER"
+                        | "LIMIT"
+                        | "OFFSET"
+                        | "UNION"
+                        | "EXCEPT"
+                        | "INTERSECT"
+                        | "FORMAT"
+                        | "SETTINGS"
+                        | "WINDOW"
+                ) {
+                    return idx;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+/// Returns the start of the column name `a` or `t.a` at the end of the tokens.
+fn column_start(tokens: &[Token]) -> Option<usize> {
+    let mut start = None;
+    let mut idx = tokens.len();
+    while idx > 0 && matches!(tokens[idx - 1], Token::Word(_)) {
+        start = Some(idx - 1);
+        match idx >= 2 && tokens[idx - 2] == Token::Period {
+            true => idx -= 2,
+            false => break,
+        }
+    }
+    start
+}
+
+/// Collects the path like `a.b[0]` or `a['b c']` at the beginning of the tokens,
+/// returns the path text along with the number of tokens consumed.
+fn variant_path(tokens: &[Token]) -> Option<(String, usize)> {
+    let mut path = String::new();
+    let mut idx = 0;
+    loop {
+        match (tokens.get(idx), tokens.get(idx + 1), tokens.get(idx + 2)) {
+            (Some(Token::Word(w)), _, _) if idx == 0 => {
+                path.push_str(&w.value);
+                idx += 1;
+            }
+            (Some(Token::Period), Some(Token::Word(w)), _) if idx > 0 => {
+                path.push('.');
+                path.push_str(&w.value);
+                idx += 2;
+            }
+            (Some(Token::LBracket), Some(Token::Number(n, _)), Some(Token::RBracket)) => {
+                path.push_str(&format!("[{}]", n));
+                idx += 3;
+            }
+            (Some(Token::LBracket), Some(Token::SingleQuotedString(s)), Some(Token::RBracket)) => {
+                path.push_str(&format!("[\"{}\"]", s));
+                idx += 3;
+            }
+            _ => break,
+        }
+    }
+
+    match idx {
+        0 => None,
+        _ => Some((path, idx)),
+    }
+}
@@ -281,6 +281,241 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn optimize_table() -> Result<()> {
+    {
+        let sql = "OPTIMIZE TABLE t1";
+        let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "OPTIMIZE TABLE db1.t1 COMPACT";
+        let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn update() -> Result<()> {
+    {
+        let sql = "UPDATE t1 SET a = b, b = 'x'";
+        let expected = DfStatement::Update(DfUpdate {
+            name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![
+                (Ident::new("a"), Expr::Identifier(Ident::new("b"))),
+                (
+                    Ident::new("b"),
+                    Expr::Value(Value::SingleQuotedString("x".to_string())),
+                ),
+            ],
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE db1.t1 SET a = b WHERE a = 'x'";
+        let expected = DfStatement::Update(DfUpdate {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![(Ident::new("a"), Expr::Identifier(Ident::new("b")))],
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("x".to_string()))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE t1 WHERE a = 'x'";
+        let expected = DfParser::parse_sql(sql).is_err();
+        assert!(expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vacuum_table() -> Result<()> {
+    {
+        let sql = "VACUUM TABLE t1";
+        let expected = DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            retain_hours: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "VACUUM TABLE db1.t1 RETAIN 24 HOURS";
+        let expected = DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            retain_hours: Some(24),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "VACUUM TABLE t1 RETAIN 24";
+        let expected = DfParser::parse_sql(sql).is_err();
+        assert!(expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn time_travel() -> Result<()> {
+    {
+        let sql = "SELECT * FROM db1.t1 AT (SNAPSHOT => 'a6e6b0f8') AS t";
+        let (expected, _) =
+            DfParser::parse_sql("SELECT * FROM db1.t1 (SNAPSHOT => 'a6e6b0f8') AS t")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM t1 at(timestamp => '2021-10-01 00:00:00')";
+        let (expected, _) =
+            DfParser::parse_sql("SELECT * FROM t1 (timestamp => '2021-10-01 00:00:00')")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    // `AT` not followed by a time travel point is left untouched
+    {
+        let sql = "SELECT at FROM t1";
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        match &statements[0] {
+            DfStatement::Statement(statement) => assert_eq!(statement.to_string(), sql),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn regexp_operators() -> Result<()> {
+    {
+        let sql = "SELECT * FROM t1 WHERE a REGEXP '^x' AND b rlike 'y$'";
+        let (expected, _) = DfParser::parse_sql("SELECT * FROM t1 WHERE a ~ '^x' AND b ~ 'y$'")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM t1 WHERE a NOT REGEXP '^x' OR b NOT RLIKE 'y$'";
+        let (expected, _) = DfParser::parse_sql("SELECT * FROM t1 WHERE a !~ '^x' OR b !~ 'y$'")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM t1 WHERE a REGEXP ('^x') AND (b) NOT RLIKE ('y$')";
+        let (expected, _) =
+            DfParser::parse_sql("SELECT * FROM t1 WHERE a ~ ('^x') AND (b) !~ ('y$')")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    // The function call form and the columns named rlike are left untouched
+    {
+        let sql = "SELECT rlike FROM t1 WHERE rlike REGEXP '^x'";
+        let (expected, _) = DfParser::parse_sql("SELECT rlike FROM t1 WHERE rlike ~ '^x'")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    // The aliases named rlike or regexp are left untouched
+    {
+        let sql = "SELECT a rlike, b regexp FROM t1 rlike WHERE rlike.a REGEXP '^x'";
+        let (expected, _) = DfParser::parse_sql(
+            "SELECT a AS rlike, b AS regexp FROM t1 AS rlike WHERE rlike.a ~ '^x'",
+        )?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM (SELECT a regexp FROM t1) regexp";
+        let (expected, _) =
+            DfParser::parse_sql("SELECT * FROM (SELECT a AS regexp FROM t1) AS regexp")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT a FROM t1 rlike JOIN t2 regexp ON rlike.a = regexp.a ORDER BY a";
+        let (expected, _) = DfParser::parse_sql(
+            "SELECT a FROM t1 AS rlike JOIN t2 AS regexp ON rlike.a = regexp.a ORDER BY a",
+        )?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    // The function call form is left untouched
+    {
+        let sql = "SELECT regexp(a, '^x') FROM t1";
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        match &statements[0] {
+            DfStatement::Statement(statement) => assert_eq!(statement.to_string(), sql),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn variant_paths() -> Result<()> {
+    {
+        let sql = "SELECT v:a.b[0], t.v:c['d e'] FROM t WHERE v:[1] = 2";
+        let (expected, _) = DfParser::parse_sql(
+            "SELECT get(v, 'a.b[0]'), get(t.v, 'c[\"d e\"]') FROM t WHERE get(v, '[1]') = 2",
+        )?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    // The colon with whitespaces around is left untouched
+    {
+        let sql = "SELECT v : a FROM t";
+        expect_parse_err(
+            sql,
+            "sql parser error: Expected end of statement, found: :".to_string(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn prewhere() -> Result<()> {
+    {
+        let sql = "SELECT * FROM t1 PREWHERE a > 1 ORDER BY b";
+        let (expected, _) =
+            DfParser::parse_sql("SELECT * FROM t1 WHERE prewhere(a > 1) ORDER BY b")?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM t1 prewhere (a > 1 or a < 0) WHERE b = 1 or c = 2 LIMIT 1";
+        let (expected, _) = DfParser::parse_sql(
+            "SELECT * FROM t1 WHERE prewhere((a > 1 or a < 0)) AND (b = 1 or c = 2) LIMIT 1",
+        )?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    {
+        let sql = "SELECT * FROM (SELECT a FROM t1 PREWHERE a > 1) WHERE a < 3";
+        let (expected, _) = DfParser::parse_sql(
+            "SELECT * FROM (SELECT a FROM t1 WHERE prewhere(a > 1)) WHERE a < 3",
+        )?;
+        expect_parse_ok(sql, expected[0].clone())?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -87,6 +87,25 @@ pub struct DfTruncateTable {
     pub name: ObjectName,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfOptimizeTable {
+    pub name: ObjectName,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfVacuumTable {
+    pub name: ObjectName,
+    pub retain_hours: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUpdate {
+    pub name: ObjectName,
+    /// The updated columns and their new values
+    pub assignments: Vec<(Ident, Expr)>,
+    pub selection: Option<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateDatabase {
     pub if_not_exists: bool,
@@ -160,6 +179,9 @@ pub enum DfStatement {
     DescribeTable(DfDescribeTable),
     DropTable(DfDropTable),
     TruncateTable(DfTruncateTable),
+    OptimizeTable(DfOptimizeTable),
+    VacuumTable(DfVacuumTable),
+    Update(DfUpdate),
 
     // Settings.
     ShowSettings(DfShowSettings),
@@ -0,0 +1,9 @@
+select case when number < 2 then 'small' when number < 4 then 'medium' else 'large' end from numbers(5) order by number;
+select case number when 0 then 'zero' when 1 then 'one' end from numbers(3) order by number;
+select toTypeName(case when number > 1 then toUInt32(1) else toInt64(3) end) from numbers(10) limit 1;
+select coalesce(null, null, 3), ifNull(null, 2), nullIf(1, 1), nullIf(1, 2);
+select coalesce(if(number > 1, null, number), 10) from numbers(3) order by number;
+select count(*) from numbers(100) where number in (1, 3, 5, 200);
+select count(*) from numbers(100) where number not in (1, 3, 5);
+select count(*) from numbers(100) where number in (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+select number from numbers(5) where not (number in (0, 1)) order by number;
@@ -0,0 +1,8 @@
+select length('databend'), lower('DataBend'), upper('DataBend'), reverse('abc');
+select concat('data', 'bend', 1), concat('data', null), concat_ws(',', 'a', null, 'b');
+select replace('www.mysql.com', 'w', 'Ww'), repeat('ab', 3), lpad('hi', 4, '??'), rpad('hi', 5, '?');
+select locate('bar', 'foobarbar'), locate('bar', 'foobarbar', 5), split_part('a,b,c', ',', 2);
+select startsWith('databend', 'data'), endsWith('databend', 'data');
+select format('{} and {1}', 'a', 'b'), hex('abc'), hex(255), unhex('616263'), unhex('GG');
+select base64Encode('databend'), base64Decode('ZGF0YWJlbmQ=');
+select concat_ws('-', toString(number), 'x') from numbers(3) order by number;
@@ -0,0 +1,3 @@
+select 'databend' regexp '^data', 'databend' rlike 'end$', 'databend' not regexp 'x', match('ab12', '[0-9]+$');
+select regexp_replace('a1b22', '([0-9]+)', '<$1>'), regexp_extract('id=12,id=34', 'id=([0-9]+)'), regexp_substr('ab ac ad', 'a.', 1, 3);
+select count(*) from numbers(100) where toString(number) regexp '^[1-3]$';
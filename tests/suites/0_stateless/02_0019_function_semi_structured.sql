@@ -0,0 +1,10 @@
+select parse_json('{ "a": {"b": [1, "x"]} }'), json_typeof(parse_json('[]'));
+select get(parse_json('{"a": {"b": [1, "x"]}}'), 'a.b[1]'), json_extract_path_text('{"a": {"b": [1, "x"]}}', 'a.b[1]');
+select toInt64(parse_json('"42"')), toString(parse_json('"x"'));
+
+DROP TABLE IF EXISTS t_variant;
+CREATE TABLE t_variant(id Int32, v Variant) Engine = Fuse;
+INSERT INTO t_variant SELECT 1, parse_json('{"user": {"name": "alice", "tags": ["a", "b"]}}');
+INSERT INTO t_variant SELECT 2, parse_json('{"user": {"name": "bob"}}');
+SELECT id, v:user.name, v:user.tags[0] FROM t_variant ORDER BY id;
+DROP TABLE t_variant;
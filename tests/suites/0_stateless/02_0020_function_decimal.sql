@@ -0,0 +1,11 @@
+select CAST('12.345' AS DECIMAL(6, 2)), CAST(-1.5 AS DECIMAL(4, 1)), CAST(CAST('7.25' AS DECIMAL(4, 2)) AS Float64);
+select CAST(1.10 AS DECIMAL(5, 2)) + CAST(2.205 AS DECIMAL(6, 3)), CAST(10 AS DECIMAL(4, 2)) / 3, CAST(1.5 AS DECIMAL(3, 1)) * 2;
+select toTypeName(CAST(1.10 AS DECIMAL(5, 2)) + CAST(2.205 AS DECIMAL(6, 3)));
+select CAST(2.5 AS DECIMAL(3, 1)) > 2, CAST(2.50 AS DECIMAL(4, 2)) = CAST(2.5 AS DECIMAL(3, 1));
+
+DROP TABLE IF EXISTS t_decimal;
+CREATE TABLE t_decimal(id Int32, price Decimal(10, 2)) Engine = Fuse;
+INSERT INTO t_decimal VALUES (1, 10.25), (2, 0.10), (3, 5.333);
+SELECT id, price FROM t_decimal WHERE price > 1 ORDER BY id;
+SELECT sum(price), avg(price) FROM t_decimal;
+DROP TABLE t_decimal;
@@ -0,0 +1,17 @@
+select toDateTime64('2021-09-09 01:01:01.123456', 6), toTypeName(toDateTime64('2021-09-09 01:01:01', 3));
+select toHour(toDateTime('2021-09-09 01:01:01')), toHour(toDateTime('2021-09-09 01:01:01'), 'Asia/Shanghai');
+select toTimeZone(toDateTime('2021-09-09 01:01:01'), 'Asia/Shanghai'), toYYYYMMDD(toTimeZone(toDateTime('2021-09-08 20:00:00'), 'Asia/Shanghai'));
+select convert_tz(toDateTime('2021-09-09 09:01:01'), 'Asia/Shanghai', 'America/New_York');
+select toStartOfDay(toDateTime64('2021-09-09 01:01:01.5', 3, 'Asia/Shanghai'));
+select toDateTime64('2021-09-09 01:01:01.5', 3) - toDateTime64('2021-09-09 01:01:01', 3);
+
+SET timezone='Asia/Shanghai';
+select toHour(toDateTime('2021-09-09 01:01:01')), toHour(toDateTime('2021-09-09 01:01:01'), 'UTC');
+SET timezone='UTC';
+
+DROP TABLE IF EXISTS t_datetime64;
+CREATE TABLE t_datetime64(id Int32, t DateTime64) Engine = Fuse;
+INSERT INTO t_datetime64 VALUES (1, '2021-09-09 01:01:01.123'), (2, '2021-09-09 23:59:59.999');
+SELECT id, t, toSecond(t), toYYYYMMDD(t) FROM t_datetime64 ORDER BY id;
+SELECT id FROM t_datetime64 WHERE t > toDateTime64('2021-09-09 12:00:00', 3);
+DROP TABLE t_datetime64;
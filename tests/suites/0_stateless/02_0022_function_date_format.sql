@@ -0,0 +1,12 @@
+select formatDateTime(toDateTime('2021-09-05 03:26:06'), '%Y/%m/%d %H:%M:%S'), DATE_FORMAT(toDateTime('2021-09-05 03:26:06'), '%W %M %D %Y %h:%i:%s %p');
+select formatDateTime(toDateTime('2021-09-05 03:26:06'), '%F %T', 'Asia/Shanghai'), DATE_FORMAT(toDate('2021-09-05'), '%d.%m.%y');
+select STR_TO_DATE('05/09/2021 11:26', '%d/%m/%Y %H:%i'), parseDateTime('2021-09-05', '%Y-%m-%d'), STR_TO_DATE('abc', '%Y-%m-%d');
+
+select date_trunc('week', toDateTime('2021-09-05 03:26:06')), date_trunc('hour', toDateTime('2021-09-05 03:26:06')), date_trunc('month', toDate('2021-09-05'));
+select date_diff('month', toDate('2021-01-31'), toDate('2021-02-01')), date_diff('day', toDate('2021-02-01'), toDate('2021-01-31')), date_diff('second', toDateTime('2021-09-05 03:26:06'), toDateTime('2021-09-05 03:27:00'));
+select date_add('month', 1, toDate('2021-01-31')), date_sub('hour', 2, toDateTime('2021-09-05 03:26:06'));
+select DATE_ADD(toDateTime('2021-09-05 03:26:06'), INTERVAL 1 DAY), DATE_SUB(toDate('2021-03-31'), INTERVAL 1 MONTH);
+
+SET timezone='Asia/Shanghai';
+select formatDateTime(toDateTime('2021-09-05 03:26:06'), '%F %T'), date_trunc('day', toDateTime('2021-09-05 20:00:00'));
+SET timezone='UTC';
@@ -0,0 +1,10 @@
+SELECT median(number), quantile(0.25)(number), quantileExact(0.9)(number) FROM numbers_mt(10000);
+SELECT quantiles(0.25, 0.5, 0.75)(number) FROM numbers_mt(10000);
+SELECT quantilesExact(0, 1)(number) FROM numbers_mt(10000);
+SELECT medianExact(number) FROM numbers(4);
+SELECT medianTDigest(number) BETWEEN 4950 AND 5050 FROM numbers_mt(10000);
+SELECT quantileTDigest(0.99)(number) BETWEEN 98000 AND 100000 FROM numbers_mt(100000);
+SELECT number % 2 AS k, median(number), quantilesExact(0.2, 0.8)(number) FROM numbers(10) GROUP BY k ORDER BY k;
+SELECT median(number) FROM numbers(10) WHERE number > 100;
+SELECT quantile(2)(number) FROM numbers(10); -- {ErrorCode 6}
+SELECT median(0.5)(number) FROM numbers(10); -- {ErrorCode 28}
@@ -0,0 +1,8 @@
+SELECT uniqCombined(number), uniqHLL12(number), approx_count_distinct(number) FROM numbers(10);
+SELECT approx_count_distinct(number) BETWEEN 98000 AND 102000 FROM numbers_mt(100000);
+SELECT uniqHLL12(number % 1000) BETWEEN 970 AND 1030 FROM numbers_mt(100000);
+SELECT uniqCombined(number % 100, number % 7) BETWEEN 670 AND 730 FROM numbers_mt(100000);
+SELECT uniqCombined(number % 3) FROM numbers_mt(100000);
+SELECT number % 3 AS k, uniqCombined(number) FROM numbers(30) GROUP BY k ORDER BY k;
+SELECT uniqHLL12(number) FROM numbers(10) WHERE number > 100;
+SELECT uniqCombined(toString(number)), uniqCombined(number = 1) FROM numbers(10);
@@ -11,3 +11,8 @@ SELECT count(1), name FROM system.credits WHERE name='ahash' GROUP BY name;
 
 SELECT 'NOT in GROUP BY function check';
 -- SELECT number%3 as c1, number as c2 FROM numbers_mt(10) where number > 2 group by c1 order by c1;
+set group_by_two_level_threshold = 10;
+set max_bytes_before_external_group_by = 1;
+SELECT count(*), sum(c), max(k) FROM (SELECT number % 1000 AS k, count(*) AS c FROM numbers_mt(100000) GROUP BY k);
+set group_by_two_level_threshold = 100000;
+set max_bytes_before_external_group_by = 0;
@@ -3,3 +3,8 @@ SELECT number, number + 3 FROM numbers_mt (1000) where number > 5 order by numbe
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1 desc, c2 asc;
 EXPLAIN SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
+set max_bytes_before_external_sort = 1;
+set max_block_size = 100;
+SELECT number FROM numbers_mt (1000) where number % 7 = 0 order by number desc limit 3;
+set max_bytes_before_external_sort = 0;
+set max_block_size = 10000;
@@ -0,0 +1,6 @@
+SELECT a.number, b.number FROM numbers(5) a INNER JOIN numbers(3) b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(5) a LEFT JOIN numbers(3) b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(3) a RIGHT JOIN numbers(5) b ON a.number = b.number ORDER BY b.number;
+SELECT count(*), sum(a.number), sum(b.n) FROM numbers(4) a FULL JOIN (SELECT number + 2 AS n FROM numbers(4)) b ON a.number = b.n;
+SELECT number FROM numbers(5) a JOIN numbers(3) b USING(number) ORDER BY number;
+SELECT a.number, b.number FROM numbers(5) a JOIN numbers(5) b ON a.number = b.number AND b.number > 2 ORDER BY a.number;
@@ -0,0 +1,5 @@
+WITH t AS (SELECT number FROM numbers(5)) SELECT number FROM t WHERE number > 2 ORDER BY number;
+WITH a AS (SELECT number FROM numbers(5)), b AS (SELECT number * 10 AS n FROM a WHERE number < 3) SELECT n FROM b ORDER BY n;
+WITH t(x) AS (SELECT number FROM numbers(3)) SELECT x FROM t ORDER BY x;
+WITH t AS (SELECT number FROM numbers(4)) SELECT a.number, b.number FROM t a JOIN t b ON a.number = b.number ORDER BY a.number;
+WITH t AS (SELECT 1 AS s) SELECT s FROM (WITH t AS (SELECT 100 AS s) SELECT s FROM t) AS inner_t;
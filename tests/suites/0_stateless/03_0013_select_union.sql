@@ -0,0 +1,6 @@
+SELECT number FROM numbers(3) UNION ALL SELECT number FROM numbers(2) ORDER BY number;
+SELECT number FROM numbers(3) UNION SELECT number FROM numbers(5) ORDER BY number;
+SELECT number FROM numbers(5) INTERSECT SELECT number + 3 FROM numbers(5) ORDER BY number;
+SELECT number FROM numbers(5) EXCEPT SELECT number + 3 FROM numbers(5) ORDER BY number;
+SELECT toUInt8(number) AS a FROM numbers(2) UNION ALL SELECT number + 1000 FROM numbers(1) ORDER BY a;
+SELECT number FROM numbers(10) UNION ALL SELECT number FROM numbers(10) ORDER BY number DESC LIMIT 3;
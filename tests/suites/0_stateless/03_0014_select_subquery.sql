@@ -0,0 +1,10 @@
+SELECT number FROM numbers(10) WHERE number IN (SELECT number * 2 FROM numbers(5)) ORDER BY number;
+SELECT count(*) FROM numbers(10) WHERE number NOT IN (SELECT number * 2 FROM numbers(5));
+SELECT number FROM numbers(5) a WHERE EXISTS (SELECT * FROM numbers(3) b WHERE b.number = a.number) ORDER BY number;
+SELECT number FROM numbers(5) a WHERE NOT EXISTS (SELECT * FROM numbers(3) b WHERE b.number = a.number) ORDER BY number;
+SELECT number FROM numbers(5) a WHERE a.number IN (SELECT b.number % 3 FROM numbers(10) b WHERE b.number = a.number + 3) ORDER BY number;
+SELECT number, (SELECT count(*) FROM numbers(5) b WHERE b.number < 3 AND b.number = a.number) AS c FROM numbers(5) a ORDER BY number;
+SELECT number FROM numbers(5) a WHERE number = (SELECT max(b.number) FROM numbers(5) b WHERE b.number % 2 = a.number % 2) ORDER BY number;
+SELECT number, (SELECT count(*) + 1 FROM numbers(5) b WHERE b.number < 3 AND b.number = a.number) AS c FROM numbers(5) a ORDER BY number;
+SELECT number, (SELECT coalesce(sum(b.number), 10) FROM numbers(5) b WHERE b.number < 3 AND b.number = a.number) AS c FROM numbers(5) a ORDER BY number;
+WITH t AS (SELECT number FROM numbers(5)) SELECT number FROM numbers(10) WHERE number IN (SELECT number FROM t WHERE number > 2) ORDER BY number;
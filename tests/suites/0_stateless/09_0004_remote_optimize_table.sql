@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64) Engine = fuse;
+OPTIMIZE TABLE t;
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+OPTIMIZE TABLE t;
+SELECT * FROM t ORDER BY a;
+INSERT INTO t VALUES(4);
+OPTIMIZE TABLE t COMPACT;
+SELECT count(*), sum(a) FROM t;
+
+DROP TABLE t;
+OPTIMIZE TABLE t; -- {ErrorCode 25}
+
+DROP DATABASE db1;
@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64) Engine = fuse;
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+SELECT count(*), sum(row_count), max(segment_count) FROM fuse_snapshot('db1', 't');
+SELECT count(*) FROM fuse_snapshot('db1', 't') WHERE prev_snapshot_id IS NULL;
+SELECT * FROM t AT (TIMESTAMP => '2000-01-01 00:00:00'); -- {ErrorCode 58}
+SELECT * FROM t AT (SNAPSHOT => 'not a uuid'); -- {ErrorCode 6}
+SET timezone='America/New_York';
+SELECT * FROM t AT (TIMESTAMP => '2021-03-14 02:30:00'); -- {ErrorCode 6}
+SET timezone='UTC';
+SELECT count(*) FROM fuse_snapshot('db1'); -- {ErrorCode 6}
+
+DROP TABLE t;
+DROP DATABASE db1;
@@ -0,0 +1,20 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64) Engine = fuse;
+VACUUM TABLE t;
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+OPTIMIZE TABLE t;
+VACUUM TABLE t;
+SELECT count(*) FROM fuse_snapshot('db1', 't');
+VACUUM TABLE t RETAIN 0 HOURS;
+SELECT count(*) FROM fuse_snapshot('db1', 't');
+SELECT * FROM t ORDER BY a;
+
+DROP TABLE t;
+VACUUM TABLE t; -- {ErrorCode 25}
+
+DROP DATABASE db1;
@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64, b String) Engine = fuse;
+DELETE FROM t WHERE a = 1;
+INSERT INTO t VALUES(1, 'a'), (2, 'b'), (3, 'c');
+INSERT INTO t VALUES(4, 'd'), (5, 'e');
+DELETE FROM t WHERE a = 100;
+SELECT count(*) FROM t;
+DELETE FROM t WHERE a = 2 OR b = 'e';
+SELECT * FROM t ORDER BY a;
+DELETE FROM t;
+SELECT count(*) FROM t;
+
+CREATE TABLE IF NOT EXISTS m(a UInt64) Engine = Memory;
+DELETE FROM m WHERE a = 1; -- {ErrorCode 2}
+
+DROP DATABASE db1;
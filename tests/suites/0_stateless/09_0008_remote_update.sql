@@ -0,0 +1,24 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64, b String) Engine = fuse;
+UPDATE t SET b = 'x' WHERE a = 1;
+INSERT INTO t VALUES(1, 'a'), (2, 'b'), (3, 'c');
+INSERT INTO t VALUES(4, 'd'), (5, 'e');
+UPDATE t SET b = 'x' WHERE a = 100;
+SELECT * FROM t ORDER BY a;
+UPDATE t SET a = a + 10, b = 'y' WHERE a = 2 OR b = 'e';
+SELECT * FROM t ORDER BY a;
+UPDATE t SET b = 'z';
+SELECT count(*) FROM t WHERE b = 'z';
+UPDATE t SET c = 1; -- {ErrorCode 6}
+
+CREATE TABLE IF NOT EXISTS m(a UInt64) Engine = Memory;
+INSERT INTO m VALUES(1), (2);
+UPDATE m SET a = a * 2 WHERE a = 2;
+SELECT * FROM m ORDER BY a;
+UPDATE m SET a = a * 10 WHERE a = 1;
+SELECT * FROM m;
+
+DROP DATABASE db1;
@@ -0,0 +1,14 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a UInt64, b String, c Int32) Engine = fuse;
+INSERT INTO t VALUES(1, 'a', 10), (2, 'b', 20), (3, 'c', 30);
+INSERT INTO t VALUES(4, 'd', 40), (5, 'e', 50);
+SELECT * FROM t PREWHERE a > 3 ORDER BY a;
+SELECT b FROM t PREWHERE a > 1 WHERE c < 40 ORDER BY b;
+SELECT count(*) FROM t PREWHERE a > 100;
+SELECT b, c FROM t WHERE a > 1 AND a < 5 ORDER BY c;
+SELECT * FROM t a JOIN t b ON a.a = b.a PREWHERE a.a > 1; -- {ErrorCode 5}
+
+DROP DATABASE db1;